@@ -18,12 +18,9 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
 // IN THE SOFTWARE.
 
-#![feature(ascii_char)]
-#![feature(ascii_char_variants)]
-
-use std::{ascii::Char::*, io::Error, sync::Arc, thread::{self, JoinHandle}, time::Duration};
+use std::{io::Error, sync::Arc, thread::{self, JoinHandle}, time::Duration};
 use semi_e5::{Item, Message, items::*, messages::*};
-use semi_e37::generic::{ConnectionMode, Client, MessageID, ParameterSettings};
+use semi_e37::generic::{ConnectionMode, Client, Inbound, MessageID, ParameterSettings};
 
 fn main() {
   test_data();
@@ -35,7 +32,7 @@ fn main() {
 
 fn test_data() {
   println!("{:?}", Item::try_from(vec![1, 1, 177, 4, 0, 0, 7, 237]));
-  let a: semi_e5::items::ErrorText = semi_e5::items::ErrorText::new(vec![CapitalA]).unwrap();
+  let a: semi_e5::items::ErrorText = semi_e5::items::ErrorText::try_from("A").unwrap();
   println!("{:?}", a);
   println!("{:?}", a.read()[0])
 }
@@ -57,15 +54,15 @@ fn test_equipment() {
       // SPAWN RX THREAD
       let equipment_rx: Arc<Client> = equipment_client.clone();
       let _rx_thread: JoinHandle<()> = thread::spawn(move || {
-        for (id, request) in rx_message {
+        for Inbound {id, message: request, ..} in rx_message {
           println!("equipment_rx request                : {:?}", request);
           let response: Message = match (request.w, request.stream, request.function) {
             (true, 1, 1) => {
               match s1::AreYouThere::try_from(request) {
                 Ok(_) => {
                   s1::OnLineDataEquipment((
-                    ModelName::new(b"SEMI-RS".as_ascii().unwrap().to_vec()).unwrap(),
-                    SoftwareRevision::new(b"010".as_ascii().unwrap().to_vec()).unwrap(),
+                    ModelName::try_from("SEMI-RS").unwrap(),
+                    SoftwareRevision::try_from("010").unwrap(),
                   )).into()
                 },
                 Err(_) => s1::Abort.into(),
@@ -100,8 +97,8 @@ fn test_equipment() {
                 Ok(_s1f13) => {
                   s1::EquipmentCRA((
                     CommAck::Accepted, (
-                      ModelName::new(b"SEMI-RS".as_ascii().unwrap().to_vec()).unwrap(),
-                      SoftwareRevision::new(b"010".as_ascii().unwrap().to_vec()).unwrap(),
+                      ModelName::try_from("SEMI-RS").unwrap(),
+                      SoftwareRevision::try_from("010").unwrap(),
                     )
                   )).into()
                 },
@@ -198,7 +195,7 @@ fn test_equipment() {
             _ => {break}
           };
           println!("equipment_rx response               : {:?}", response.clone());
-          println!("equipment_rx.data                   : {:?}", equipment_rx.data(id, response).join().unwrap());
+          println!("equipment_rx.data                   : {:?}", equipment_rx.data(id, response));
         }
       });
     }
@@ -206,7 +203,7 @@ fn test_equipment() {
     if system == 0x1020 {break}
     thread::sleep(Duration::from_secs(1));
   }
-  println!("equipment_client.separate           : {:?}", equipment_client.separate(MessageID {system, session: 0xFFFF}).join().unwrap());
+  println!("equipment_client.separate           : {:?}", equipment_client.separate(MessageID {system, session: 0xFFFF.into()}).join().unwrap());
   println!("equipment_client.disconnect         : {:?}", equipment_client.disconnect());
 }
 
@@ -223,13 +220,13 @@ fn test_host() {
   thread::sleep(Duration::from_millis(2000));
   let mut system: u32 = 0;
   // SELECT
-  println!("host_client.select                  : {:?}", host_client.select(MessageID{session: 0, system}).join().unwrap());
+  println!("host_client.select                  : {:?}", host_client.select(MessageID{session: 0.into(), system}).join().unwrap());
   system += 1;
   // DATA LOOP
   loop {
     let data_result: Result<Option<Message>, Error> = host_client.data(
       MessageID {
-        session: 0,
+        session: 0.into(),
         system,
       },
       Message {
@@ -238,7 +235,7 @@ fn test_host() {
         w: true,
         text: None,
       }
-    ).join().unwrap();
+    );
     println!("host_client.data                    : {:?}", data_result);
     if data_result.is_err() {break}
     system += 1;
@@ -0,0 +1,14 @@
+//! # DECODE FRAME
+//! 
+//! Feeds arbitrary bytes to the same decode path the Client uses on
+//! received data, which must never panic.
+//! 
+//! Run from this directory with `cargo +nightly fuzz run decode_frame`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let _ = semi_e37::generic::decode_frame(data);
+});
@@ -0,0 +1,71 @@
+//! # PRIMITIVE SERVICES
+//! 
+//! Defines the most agnostic form in which data can be exchanged persuant to
+//! the [HSMS] protocol and any subsidary protocols. This involves managing the
+//! creation and breaking of the TCP/IP connection, and the sending of messages
+//! with properly formatted headers. This is not necessarily outlined by the
+//! standard, but is an important piece of establishing and maintaining proper
+//! communications.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! To use the [Primitive Services]:
+//! 
+//! - Build [Message]s which use [Message Header]s.
+//! - Create a [Client] with the [New Client] function.
+//! - Manage the [Connection State] with the [Connect Procedure] and
+//!   [Disconnect Procedure].
+//! - Receive [Message]s with the hook provided by the [Connect Procedure].
+//! - Transmit [Message]s with the [Transmit Procedure].
+
+//! # ASYNCHRONOUS CLIENT EXAMPLE
+//! 
+//! Connects an equipment and a host over the loopback interface using the
+//! asynchronous client, and exchanges an S1F1/S1F2 transaction.
+//! 
+//! Run with `cargo run --example async_client --features tokio`.
+
+use std::time::Duration;
+use semi_e5::messages::s1;
+use semi_e37::{
+  asynchronous::Client,
+  generic::{
+    MessageID,
+    ParameterSettingsBuilder,
+    SessionID,
+  },
+};
+
+#[tokio::main]
+async fn main() {
+  // EQUIPMENT
+  let equipment = Client::new(ParameterSettingsBuilder::passive().build().unwrap());
+  let equipment_task = tokio::spawn({
+    let equipment = equipment.clone();
+    async move {
+      let (socket, mut rx_message) = equipment.connect("127.0.0.1:5000").await.unwrap();
+      println!("equipment.connect    : {:?}", socket);
+      while let Some((id, request)) = rx_message.recv().await {
+        println!("equipment request    : {:?}", request);
+        let response: semi_e5::Message = match s1::AreYouThere::try_from(request) {
+          Ok(_) => s1::OnLineDataEquipment((
+            semi_e5::items::ModelName::new(vec![]).unwrap(),
+            semi_e5::items::SoftwareRevision::new(vec![]).unwrap(),
+          )).into(),
+          Err(_) => s1::Abort.into(),
+        };
+        println!("equipment response   : {:?}", equipment.data(id, response).await);
+      }
+    }
+  });
+  tokio::time::sleep(Duration::from_millis(100)).await;
+  // HOST
+  let host = Client::new(ParameterSettingsBuilder::active().build().unwrap());
+  println!("host.connect         : {:?}", host.connect("127.0.0.1:5000").await.map(|(socket, _)| socket));
+  println!("host.select          : {:?}", host.select(MessageID {session: SessionID(0), system: 1}).await);
+  println!("host.data            : {:?}", host.data(MessageID {session: SessionID(0), system: 2}, s1::AreYouThere).await);
+  println!("host.linktest        : {:?}", host.linktest(3).await);
+  println!("host.separate        : {:?}", host.separate(MessageID {session: SessionID(0), system: 4}).await);
+  println!("host.disconnect      : {:?}", host.disconnect().await);
+  let _ = equipment_task.await;
+}
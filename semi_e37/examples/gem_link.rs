@@ -0,0 +1,68 @@
+//! # GEM LINK EXAMPLE
+//! 
+//! Connects an equipment and a host over the loopback interface using the
+//! role wrappers, so that the host connects, selects, and establishes
+//! communications with S1F13, while the equipment listens, accepts the
+//! selection, and answers with S1F14 before serving S1F1.
+//! 
+//! Run with `cargo run --example gem_link`.
+
+use std::{
+  thread,
+  time::Duration,
+};
+use semi_e5::{
+  items::{
+    CommAck,
+    ModelName,
+    SoftwareRevision,
+  },
+  messages::s1,
+};
+use semi_e37::{
+  generic::{
+    MessageID,
+    MessageRouter,
+    ParameterSettings,
+    SessionID,
+    Unhandled,
+  },
+  role::{
+    EquipmentClient,
+    HostClient,
+  },
+};
+
+fn main() {
+  // EQUIPMENT
+  let equipment = EquipmentClient::new(
+    ParameterSettings::default(),
+    ModelName::try_from("SEMI-RS").unwrap(),
+    SoftwareRevision::try_from("0.1").unwrap(),
+  );
+  equipment.on_establish(|| {
+    println!("equipment establish  : accepted");
+    CommAck::Accepted
+  });
+  let equipment_thread = thread::spawn(move || {
+    let (socket, rx_message) = equipment.connect("127.0.0.1:5000").unwrap();
+    println!("equipment.connect    : {:?}", socket);
+    let router = MessageRouter::new()
+      .route(1, 1, |_request| {
+        Some(s1::OnLineDataEquipment((
+          ModelName::try_from("SEMI-RS").unwrap(),
+          SoftwareRevision::try_from("0.1").unwrap(),
+        )).into())
+      })
+      .unhandled(Unhandled::Abort);
+    equipment.serve(rx_message, router);
+  });
+  thread::sleep(Duration::from_millis(100));
+  // HOST
+  let host = HostClient::new(ParameterSettings::default(), SessionID(0));
+  println!("host.connect         : {:?}", host.connect("127.0.0.1:5000").map(|(socket, _)| socket));
+  println!("host.establish       : {:?}", host.establish_communications());
+  println!("host.transact        : {:?}", host.client().transact(MessageID {session: SessionID(0), system: host.client().next_system()}, s1::AreYouThere));
+  println!("host.disconnect      : {:?}", host.client().disconnect());
+  let _ = equipment_thread.join();
+}
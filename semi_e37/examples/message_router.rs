@@ -0,0 +1,58 @@
+//! # MESSAGE ROUTER EXAMPLE
+//! 
+//! Connects an equipment and a host over the loopback interface, and answers
+//! the host's S1F1 with an S1F2 using a message router on the equipment.
+//! Any other primary message is aborted.
+//! 
+//! Run with `cargo run --example message_router`.
+
+use std::{
+  thread,
+  time::Duration,
+};
+use semi_e5::{
+  items::{
+    ModelName,
+    SoftwareRevision,
+  },
+  messages::s1,
+};
+use semi_e37::generic::{
+  Client,
+  MessageID,
+  MessageRouter,
+  ParameterSettingsBuilder,
+  SessionID,
+  Unhandled,
+};
+
+fn main() {
+  // EQUIPMENT
+  let equipment = Client::new(ParameterSettingsBuilder::passive().build().unwrap());
+  let equipment_thread = thread::spawn({
+    let equipment = equipment.clone();
+    move || {
+      let (socket, rx_message) = equipment.connect("127.0.0.1:5000").unwrap();
+      println!("equipment.connect    : {:?}", socket);
+      let router = MessageRouter::new()
+        .route(1, 1, |request| {
+          println!("equipment request    : {:?}", request);
+          Some(s1::OnLineDataEquipment((
+            ModelName::try_from("SEMI-RS").unwrap(),
+            SoftwareRevision::try_from("0.1").unwrap(),
+          )).into())
+        })
+        .unhandled(Unhandled::Abort);
+      equipment.serve(rx_message, &router);
+    }
+  });
+  thread::sleep(Duration::from_millis(100));
+  // HOST
+  let host = Client::new(ParameterSettingsBuilder::active().build().unwrap());
+  println!("host.connect         : {:?}", host.connect("127.0.0.1:5000").map(|(socket, _)| socket));
+  println!("host.select          : {:?}", host.select(MessageID {session: SessionID(0), system: 1}).join().unwrap());
+  println!("host.transact        : {:?}", host.transact(MessageID {session: SessionID(0), system: 2}, s1::AreYouThere));
+  println!("host.transact        : {:?}", host.transact(MessageID {session: SessionID(0), system: 3}, s1::RequestOffLine));
+  println!("host.disconnect      : {:?}", host.disconnect());
+  let _ = equipment_thread.join();
+}
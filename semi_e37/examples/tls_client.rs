@@ -0,0 +1,68 @@
+//! # TLS CLIENT EXAMPLE
+//! 
+//! Connects an equipment and a host over the loopback interface using TLS,
+//! and exchanges an S1F1/S1F2 transaction.
+//! 
+//! The equipment presents the self-signed certificate in `tls_cert.pem`, which
+//! the host trusts as its only root certificate. This certificate and its key
+//! are for demonstration only and must not be used in production.
+//! 
+//! Run with `cargo run --example tls_client --features tls`.
+
+use std::{
+  thread,
+  time::Duration,
+};
+use rustls::pki_types::{
+  pem::PemObject,
+  CertificateDer,
+  PrivateKeyDer,
+};
+use semi_e5::messages::s1;
+use semi_e37::generic::{
+  Client,
+  MessageID,
+  ParameterSettingsBuilder,
+  SessionID,
+  TlsSettings,
+};
+
+const CERTIFICATE: &[u8] = include_bytes!("tls_cert.pem");
+const PRIVATE_KEY: &[u8] = include_bytes!("tls_key.pem");
+
+fn main() {
+  let certificate = CertificateDer::from_pem_slice(CERTIFICATE).unwrap();
+  let private_key = PrivateKeyDer::from_pem_slice(PRIVATE_KEY).unwrap();
+  // EQUIPMENT
+  let equipment = Client::new(ParameterSettingsBuilder::passive().build().unwrap());
+  equipment.set_tls(Some(TlsSettings::passive(vec![certificate.clone()], private_key).unwrap()));
+  let equipment_thread = thread::spawn({
+    let equipment = equipment.clone();
+    move || {
+      let (socket, rx_message) = equipment.connect("127.0.0.1:5000").unwrap();
+      println!("equipment.connect    : {:?}", socket);
+      for inbound in rx_message {
+        println!("equipment request    : {:?}", inbound.message);
+        let response: semi_e5::Message = match s1::AreYouThere::try_from(inbound.message.clone()) {
+          Ok(_) => s1::OnLineDataEquipment((
+            semi_e5::items::ModelName::new(vec![]).unwrap(),
+            semi_e5::items::SoftwareRevision::new(vec![]).unwrap(),
+          )).into(),
+          Err(_) => s1::Abort.into(),
+        };
+        println!("equipment response   : {:?}", inbound.reply(response));
+      }
+    }
+  });
+  thread::sleep(Duration::from_millis(100));
+  // HOST
+  let host = Client::new(ParameterSettingsBuilder::active().build().unwrap());
+  host.set_tls(Some(TlsSettings::active(vec![certificate], "localhost").unwrap()));
+  println!("host.connect         : {:?}", host.connect("127.0.0.1:5000").map(|(socket, _)| socket));
+  println!("host.select          : {:?}", host.select(MessageID {session: SessionID(0), system: 1}).join().unwrap());
+  println!("host.data            : {:?}", host.data(MessageID {session: SessionID(0), system: 2}, s1::AreYouThere));
+  println!("host.linktest        : {:?}", host.linktest(3).join().unwrap());
+  println!("host.separate        : {:?}", host.separate(MessageID {session: SessionID(0), system: 4}).join().unwrap());
+  println!("host.disconnect      : {:?}", host.disconnect());
+  let _ = equipment_thread.join();
+}
@@ -0,0 +1,881 @@
+//! # ASYNCHRONOUS SERVICES
+//! 
+//! Provides the [Generic Services] for use within asynchronous applications
+//! built on the [Tokio] runtime, enabled by the `tokio` feature.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Client] mirrors the [Generic Client], but its procedures return
+//! [Future]s rather than threads. The TCP/IP connection is managed with a
+//! [Tokio] TCP stream, received messages are handled by a spawned task, and
+//! replies are delivered to waiting procedures through oneshot channels.
+//! 
+//! The [Message]s, [Message ID]s, [Parameter Settings], and their
+//! conversions to and from bytes are shared with the [Generic Services] and
+//! [Primitive Services], as is the decision of how to respond to each
+//! received [Message], including the [Select Policy].
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - [Not Selected Timeout]
+//! - [Linktest Heartbeat]
+//! - [Idle Timeout]
+//! - [Deselect Procedure]
+//! - [Resynchronize]
+//! - [Disconnect Reason]
+//! - [Connect and Select Procedure]
+//! - [Passive Bind Retry]
+//! - [TCP Keepalive]
+//! - [Shutdown Procedure]
+//! - [Data With Timeout Procedure]
+//! 
+//! [Tokio]:                        https://tokio.rs
+//! [Future]:                       std::future::Future
+//! [Client]:                       Client
+//! [Generic Client]:               crate::generic::Client
+//! [Resynchronize]:                crate::generic::ParameterSettings::resynchronize
+//! [Generic Services]:             crate::generic
+//! [Primitive Services]:           crate::primitive
+//! [Message]:                      crate::generic::Message
+//! [Message ID]:                   crate::generic::MessageID
+//! [Parameter Settings]:           crate::generic::ParameterSettings
+//! [Select Policy]:                crate::generic::SelectPolicy
+//! [Not Selected Timeout]:         crate::generic::ParameterSettings::t7
+//! [Linktest Heartbeat]:           crate::generic::ParameterSettings::linktest_interval
+//! [Idle Timeout]:                 crate::generic::ParameterSettings::idle_timeout
+//! [Deselect Procedure]:           crate::generic::Client::deselect
+//! [Disconnect Reason]:            crate::generic::Client::disconnect_reason
+//! [Connect and Select Procedure]: crate::generic::Client::connect_and_select
+//! [Passive Bind Retry]:           crate::primitive::ConnectError::AddressInUse
+//! [TCP Keepalive]:                crate::generic::ParameterSettings::keepalive
+//! [Shutdown Procedure]:           crate::generic::Client::shutdown
+//! [Data With Timeout Procedure]:  crate::generic::Client::data_with_timeout
+
+use std::{
+  collections::HashMap,
+  io::{
+    Error,
+    ErrorKind,
+  },
+  net::SocketAddr,
+  ops::{
+    Deref,
+    DerefMut,
+  },
+  sync::{
+    atomic::{
+      AtomicU64,
+      Ordering::Relaxed,
+    },
+    Arc,
+    Mutex,
+    RwLock,
+  },
+  time::Duration,
+};
+use atomic::Atomic;
+use tokio::{
+  io::{
+    AsyncReadExt,
+    AsyncWriteExt,
+  },
+  net::{
+    tcp::{
+      OwnedReadHalf,
+      OwnedWriteHalf,
+    },
+    TcpListener,
+    TcpStream,
+  },
+  sync::{
+    mpsc::{
+      unbounded_channel,
+      UnboundedReceiver,
+      UnboundedSender,
+    },
+    oneshot,
+  },
+  task::JoinHandle,
+  time::timeout,
+};
+use crate::{
+  generic::{
+    malformed,
+    not_open,
+    react,
+    ConnectError,
+    ConnectionMode,
+    Message,
+    MessageContents,
+    MessageID,
+    ParameterSettings,
+    Reaction,
+    SelectionChange,
+    SelectionState,
+    SelectPolicy,
+    SelectStatus,
+    SessionID,
+    Situation,
+  },
+  primitive,
+};
+
+/// ## CLIENT
+/// 
+/// Encapsulates the full functionality of the [HSMS] protocol for use within
+/// asynchronous applications, mirroring the [Generic Client].
+/// 
+/// [HSMS]:           crate
+/// [Generic Client]: crate::generic::Client
+pub struct Client {
+  parameter_settings: ParameterSettings,
+  selection_state: Atomic<SelectionState>,
+  selection_session: Atomic<SessionID>,
+  select_policy: RwLock<SelectPolicy>,
+  connection_count: AtomicU64,
+  writer: tokio::sync::Mutex<Option<OwnedWriteHalf>>,
+  rx_task: Mutex<Option<JoinHandle<()>>>,
+  outbox: Mutex<HashMap<MessageID, oneshot::Sender<Message>>>,
+}
+
+/// ## CONNECTION PROCEDURES
+/// **Based on SEMI E37-1109§6.3-6.5**
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// establishing and breaking a TCP/IP connection.
+/// 
+/// - [New Client]
+/// - [Connect Procedure]
+/// - [Disconnect Procedure]
+/// 
+/// [Client]:               Client
+/// [New Client]:           Client::new
+/// [Connect Procedure]:    Client::connect
+/// [Disconnect Procedure]: Client::disconnect
+impl Client {
+  /// ### NEW CLIENT
+  /// 
+  /// Creates a [Client] in the [NOT CONNECTED] state, ready to initiate the
+  /// [Connect Procedure].
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [NOT CONNECTED]:     primitive::ConnectionState::NotConnected
+  pub fn new(
+    parameter_settings: ParameterSettings
+  ) -> Arc<Self> {
+    Arc::new(Client {
+      parameter_settings,
+      selection_state:   Default::default(),
+      selection_session: Default::default(),
+      select_policy:     RwLock::new(Box::new(|_| SelectStatus::Success)),
+      connection_count:  Default::default(),
+      writer:            Default::default(),
+      rx_task:           Default::default(),
+      outbox:            Default::default(),
+    })
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.3.4-6.3.7**
+  /// 
+  /// Connects the [Client] to the Remote Entity.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [NOT CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connect Procedure] has two different behaviors based on the
+  /// [Connection Mode] provided to it:
+  /// - [PASSIVE] - The socket address of the Local Entity must be provided,
+  ///   and the [Client] binds to it, then listens for and accepts the
+  ///   [Connect Procedure] when initiated by the Remote Entity.
+  /// - [ACTIVE] - The socket address of the Remote Entity must be provided,
+  ///   and the [Client] initiates the [Connect Procedure] and waits up to the
+  ///   time specified by [T5] for the Remote Entity to respond.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Connect Procedure], the [CONNECTED] state is
+  /// entered, and a task is spawned to receive [Message]s. Primary
+  /// [Data Message]s received while [SELECTED] are sent to the provided
+  /// receiver.
+  /// 
+  /// [Connection State]:  primitive::ConnectionState
+  /// [NOT CONNECTED]:     primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:         primitive::ConnectionState::Connected
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  /// [ACTIVE]:            ConnectionMode::Active
+  /// [SELECTED]:          SelectionState::Selected
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [T5]:                ParameterSettings::t5
+  /// [Message]:           Message
+  /// [Data Message]:      MessageContents::DataMessage
+  pub async fn connect(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<(SocketAddr, UnboundedReceiver<(MessageID, semi_e5::Message)>), ConnectError> {
+    let mut writer = self.writer.lock().await;
+    // IS: CONNECTED
+    if writer.is_some() {
+      return Err(ConnectError::AlreadyConnected)
+    }
+    // TCP: CONNECT
+    let (stream, socket) = match self.parameter_settings.connect_mode {
+      // CONNECT MODE: PASSIVE
+      ConnectionMode::Passive => {
+        let listener = TcpListener::bind(entity).await.map_err(ConnectError::Bind)?;
+        listener.accept().await.map_err(ConnectError::Io)?
+      },
+      // CONNECT MODE: ACTIVE
+      ConnectionMode::Active => {
+        let stream = match timeout(self.parameter_settings.t5, TcpStream::connect(entity)).await {
+          Ok(Ok(stream)) => stream,
+          Ok(Err(error)) => return Err(match error.kind() {
+            ErrorKind::InvalidInput => ConnectError::InvalidAddress(error),
+            _ => ConnectError::Io(error),
+          }),
+          Err(_) => return Err(ConnectError::Timeout(Error::from(ErrorKind::TimedOut))),
+        };
+        let socket = stream.peer_addr().map_err(ConnectError::Io)?;
+        (stream, socket)
+      },
+    };
+    // TO: CONNECTED
+    let (reader, write_half) = stream.into_split();
+    *writer = Some(write_half);
+    let count = self.connection_count.fetch_add(1, Relaxed) + 1;
+    let (rx_sender, rx_receiver) = unbounded_channel();
+    let clone: Arc<Client> = self.clone();
+    *self.rx_task.lock().unwrap() = Some(tokio::spawn(async move {
+      clone.receive(count, reader, rx_sender).await;
+    }));
+    log::debug!("asynchronous::Client::connect: CONNECTED {}", socket);
+    Ok((socket, rx_receiver))
+  }
+
+  /// ### DISCONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.4-6.5**
+  /// 
+  /// Disconnects the [Client] from the Remote Entity.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Disconnect Procedure], the [NOT CONNECTED] and
+  /// [NOT SELECTED] states are entered, and any open transactions are
+  /// abandoned.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  pub async fn disconnect(
+    self: &Arc<Self>,
+  ) -> Result<(), Error> {
+    // TO: NOT CONNECTED
+    let mut writer = match self.writer.lock().await.take() {
+      Some(writer) => writer,
+      None => return Err(Error::from(ErrorKind::NotConnected)),
+    };
+    self.connection_count.fetch_add(1, Relaxed);
+    let _ = writer.shutdown().await;
+    if let Some(rx_task) = self.rx_task.lock().unwrap().take() {
+      rx_task.abort();
+    }
+    // TO: NOT SELECTED
+    self.selection_state.store(SelectionState::NotSelected, Relaxed);
+    // OUTBOX: CLEAR
+    self.outbox.lock().unwrap().clear();
+    log::debug!("asynchronous::Client::disconnect: NOT CONNECTED");
+    Ok(())
+  }
+
+  /// ### IS CONNECTED
+  /// 
+  /// Provides whether the [Connection State] is in the [CONNECTED] state.
+  /// 
+  /// [Connection State]: primitive::ConnectionState
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  pub async fn is_connected(
+    self: &Arc<Self>,
+  ) -> bool {
+    self.writer.lock().await.is_some()
+  }
+
+  /// ### SELECTION STATE
+  /// 
+  /// Provides the current [Selection State].
+  /// 
+  /// [Selection State]: SelectionState
+  pub fn selection_state(
+    self: &Arc<Self>,
+  ) -> SelectionState {
+    self.selection_state.load(Relaxed)
+  }
+
+  /// ### SET SELECT POLICY
+  /// 
+  /// Provides the [Client] with a [Select Policy] to be consulted whenever a
+  /// [Select.req] message is received in the [NOT SELECTED] state, replacing
+  /// the previously provided [Select Policy], in the same manner as the
+  /// [Generic Client]. The default [Select Policy] always responds with
+  /// [Success].
+  /// 
+  /// [Client]:         Client
+  /// [Generic Client]: crate::generic::Client::set_select_policy
+  /// [Select Policy]:  SelectPolicy
+  /// [Success]:        SelectStatus::Success
+  /// [Select.req]:     MessageContents::SelectRequest
+  /// [NOT SELECTED]:   SelectionState::NotSelected
+  pub fn set_select_policy(
+    self: &Arc<Self>,
+    select_policy: SelectPolicy,
+  ) {
+    *self.select_policy.write().unwrap().deref_mut() = select_policy;
+  }
+}
+
+/// ## MESSAGE EXCHANGE PROCEDURES
+/// **Based on SEMI E37-1109§7**
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// exchanging [Message]s.
+/// 
+/// - [Data Procedure]
+/// - [Select Procedure]
+/// - [Linktest Procedure]
+/// - [Separate Procedure]
+/// 
+/// [Message]:            Message
+/// [Client]:             Client
+/// [Data Procedure]:     Client::data
+/// [Select Procedure]:   Client::select
+/// [Linktest Procedure]: Client::linktest
+/// [Separate Procedure]: Client::separate
+impl Client {
+  /// ### RECEIVE PROCEDURE
+  /// 
+  /// Receives [Message]s from the TCP/IP connection until it is broken,
+  /// responding to them in the same manner as the [Generic Client], as both
+  /// share the decision of how to react to each [Message].
+  /// 
+  /// A [Deselect.req] is answered and leaves the [SELECTED] state, though
+  /// this [Client] cannot yet initiate the [Deselect Procedure] itself.
+  /// 
+  /// [Message]:            Message
+  /// [Client]:             Client
+  /// [Generic Client]:     crate::generic::Client
+  /// [Deselect.req]:       MessageContents::DeselectRequest
+  /// [SELECTED]:           SelectionState::Selected
+  /// [Deselect Procedure]: crate::generic::Client::deselect
+  async fn receive(
+    self: &Arc<Self>,
+    count: u64,
+    mut reader: OwnedReadHalf,
+    rx_sender: UnboundedSender<(MessageID, semi_e5::Message)>,
+  ) {
+    loop {
+      // RX: Length [Bytes 0-3]
+      let mut length_buffer: [u8; 4] = [0; 4];
+      if reader.read_exact(&mut length_buffer).await.is_err() {break}
+      let length: u32 = u32::from_be_bytes(length_buffer);
+      if length < 10 || length > self.parameter_settings.max_message_length {break}
+      // RX: Header + Data [Bytes 4+]
+      let mut message_buffer: Vec<u8> = vec![0; length as usize];
+      match timeout(self.parameter_settings.t8, reader.read_exact(&mut message_buffer)).await {
+        Ok(Ok(_)) => {},
+        _ => break,
+      }
+      let primitive_message = match primitive::Message::try_from(message_buffer) {
+        Ok(primitive_message) => primitive_message,
+        Err(_) => break,
+      };
+      let primitive_header = primitive_message.header;
+      let rx_result = Message::decode(primitive_message, &self.parameter_settings);
+      match rx_result {
+        Ok(rx_message) => {
+          let session: SessionID = rx_message.id.session;
+          let selection_state: SelectionState = self.selection_state.load(Relaxed);
+          let same_session: bool = self.selection_session.load(Relaxed) == session;
+          let situation: Situation = Situation {
+            selection_state,
+            selected_session: same_session,
+            same_session,
+            busy:             matches!(selection_state, SelectionState::SelectInitiated | SelectionState::DeselectInitiated),
+            multi_session:    false,
+            releasable:       false,
+          };
+          let reaction: Reaction = react(rx_message, &situation, self.select_policy.read().unwrap().deref());
+          match reaction {
+            // INBOX: New Transaction
+            Reaction::Deliver(id, message) => {
+              if rx_sender.send((id, message)).is_err() {break}
+            },
+            // OUTBOX: Find Transaction
+            Reaction::Complete(reply) => {
+              let id: MessageID = reply.id;
+              let reject: Option<Message> = not_open(&reply);
+              if !self.complete(reply) {
+                match reject {
+                  // TX: Reject.req
+                  Some(reject) => if self.transmit_primitive(reject).await.is_err() {break},
+                  None => log::debug!("asynchronous::Client::receive: REJECT.REQ IGNORED {:?}", id),
+                }
+              }
+            },
+            Reaction::Respond(response, change) => {
+              // TO: NOT SELECTED
+              let change: Option<SelectionChange> = match change {
+                Some(change) if !change.is_entering() => {
+                  self.change_selection(change);
+                  None
+                },
+                change => change,
+              };
+              // TX: Response
+              if let Some(response) = response {
+                if self.transmit_primitive(response).await.is_err() {break}
+              }
+              // TO: SELECTED
+              if let Some(change) = change {
+                self.change_selection(change);
+              }
+            },
+          }
+        },
+        Err(reject_reason) => {
+          // AUTO REJECT: Disabled
+          if !self.parameter_settings.auto_reject {
+            log::debug!("asynchronous::Client::receive: MALFORMED {:?}", reject_reason);
+            continue
+          }
+          // TX: Reject.req
+          if self.transmit_primitive(malformed(primitive_header, reject_reason)).await.is_err() {break}
+        },
+      }
+    }
+    // OUTBOX: CLEAR
+    self.outbox.lock().unwrap().clear();
+    // TO: NOT CONNECTED
+    if self.connection_count.load(Relaxed) == count {
+      let _ = self.disconnect().await;
+    }
+  }
+
+  /// ### CHANGE SELECTION
+  /// 
+  /// Makes a [Selection Change] decided by the [Receive Procedure].
+  /// 
+  /// [Selection Change]:  SelectionChange
+  /// [Receive Procedure]: Client::receive
+  fn change_selection(
+    self: &Arc<Self>,
+    change: SelectionChange,
+  ) {
+    match change {
+      // TO: SELECTED
+      SelectionChange::Select(session) => {
+        self.selection_session.store(session, Relaxed);
+        self.selection_state.store(SelectionState::Selected, Relaxed);
+        log::debug!("asynchronous::Client::receive: SELECTED");
+      },
+      // TO: NOT SELECTED
+      SelectionChange::Deselect | SelectionChange::Separate => {
+        if self.selection_state.swap(SelectionState::NotSelected, Relaxed) != SelectionState::NotSelected {
+          log::debug!("asynchronous::Client::receive: NOT SELECTED");
+        }
+      },
+      // SESSIONS: Only One
+      SelectionChange::AddSession(_) | SelectionChange::ReleaseSession(_) => {},
+    }
+  }
+
+  /// ### COMPLETE TRANSACTION
+  /// 
+  /// Delivers a reply [Message] to the procedure waiting on it, returning
+  /// whether such a procedure was found.
+  /// 
+  /// [Message]: Message
+  fn complete(
+    self: &Arc<Self>,
+    message: Message,
+  ) -> bool {
+    match self.outbox.lock().unwrap().deref_mut().remove(&message.id) {
+      Some(sender) => {
+        let _ = sender.send(message);
+        true
+      },
+      None => false,
+    }
+  }
+
+  /// ### TRANSMIT PRIMITIVE MESSAGE
+  /// 
+  /// Serializes a [Message] and writes it to the TCP/IP connection.
+  /// 
+  /// [Message]: Message
+  async fn transmit_primitive(
+    self: &Arc<Self>,
+    message: Message,
+  ) -> Result<(), Error> {
+    let primitive_message = primitive::Message::try_from(message).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+    let length: u32 = 10 + primitive_message.text.len() as u32;
+    let mut frame_buffer: Vec<u8> = Vec::with_capacity(4 + length as usize);
+    frame_buffer.extend_from_slice(&length.to_be_bytes());
+    primitive_message.serialize(&mut frame_buffer);
+    let mut writer = self.writer.lock().await;
+    match writer.as_mut() {
+      Some(writer) => writer.write_all(&frame_buffer).await,
+      None => Err(Error::from(ErrorKind::NotConnected)),
+    }
+  }
+
+  /// ### TRANSMIT PROCEDURE
+  /// **Based on SEMI E37-1109§7.2**
+  /// 
+  /// Serializes a [Message] and transmits it over the TCP/IP connection.
+  /// If a reply is expected, this function will then wait up to the time
+  /// specified for the requisite response [Message] to be recieved.
+  /// 
+  /// [Message]: Message
+  async fn transmit(
+    self: &Arc<Self>,
+    message: Message,
+    reply_expected: bool,
+    delay: Duration,
+  ) -> Result<Option<Message>, Error> {
+    let id = message.id;
+    // OUTBOX: Open Transaction
+    let receiver = if reply_expected {
+      let mut outbox = self.outbox.lock().unwrap();
+      if outbox.contains_key(&id) {
+        return Err(Error::from(ErrorKind::AlreadyExists))
+      }
+      let (sender, receiver) = oneshot::channel();
+      outbox.insert(id, sender);
+      Some(receiver)
+    } else {
+      None
+    };
+    // TX
+    if let Err(error) = self.transmit_primitive(message).await {
+      self.outbox.lock().unwrap().remove(&id);
+      return Err(error)
+    }
+    // RX
+    match receiver {
+      None => Ok(None),
+      Some(receiver) => match timeout(delay, receiver).await {
+        Ok(Ok(reply)) => Ok(Some(reply)),
+        Ok(Err(_)) => Ok(None),
+        Err(_) => {
+          self.outbox.lock().unwrap().remove(&id);
+          Ok(None)
+        },
+      },
+    }
+  }
+
+  /// ### DATA PROCEDURE
+  /// **Based on SEMI E37-1109§7.5-7.6**
+  /// 
+  /// Asks the [Client] to initiate the [Data Procedure] by transmitting a
+  /// [Data Message] and waiting for the corresponding response to be received
+  /// if it is necessary to do so.
+  /// 
+  /// The [Data Message] may be provided as a [semi_e5::Message] or as any
+  /// of the typed messages in [semi_e5::messages], such as S1F1.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Data Message] is checked with [semi_e5::Message::validate] before
+  /// anything is transmitted, failing with an error of kind [InvalidInput]
+  /// if the reply bit is set on a reply.
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// 
+  /// If the [Session ID] provided is the [Selected Session], it is replaced
+  /// by the [Session ID] used to complete the [Select Procedure].
+  /// 
+  /// When a Response [Data Message] is necessary, the [Client] will wait
+  /// to receive it for the amount of time specified by [T3] before it will
+  /// consider it a communications failure and initiate the
+  /// [Disconnect Procedure].
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [SELECTED]:             SelectionState::Selected
+  /// [T3]:                   ParameterSettings::t3
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Data Procedure]:       Client::data
+  /// [Select Procedure]:     Client::select
+  /// [Data Message]:         MessageContents::DataMessage
+  /// [Session ID]:           MessageID::session
+  /// [Selected Session]:     MessageID::SELECTED_SESSION
+  /// [InvalidInput]:         ErrorKind::InvalidInput
+  pub async fn data(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: impl Into<semi_e5::Message>,
+  ) -> Result<Option<semi_e5::Message>, Error> {
+    let message: semi_e5::Message = message.into();
+    let reply_expected: bool = message.expects_reply();
+    // Reply Bit
+    if let Err(error) = message.validate() {
+      return Err(Error::new(ErrorKind::InvalidInput, error))
+    }
+    // IS: NOT SELECTED, SELECT INITIATED
+    if matches!(self.selection_state.load(Relaxed), SelectionState::NotSelected | SelectionState::SelectInitiated) {
+      return Err(Error::from(ErrorKind::AlreadyExists))
+    }
+    // Default Session ID
+    let id: MessageID = if id.session == MessageID::SELECTED_SESSION {
+      MessageID {
+        session: self.selection_session.load(Relaxed),
+        system: id.system,
+      }
+    } else {
+      id
+    };
+    // TX: Data Message
+    match self.transmit(
+      Message {
+        id,
+        contents: MessageContents::DataMessage(message),
+      },
+      reply_expected,
+      self.parameter_settings.t3,
+    ).await? {
+      // RX: Response
+      Some(rx_message) => match rx_message.contents {
+        // RX: Data
+        MessageContents::DataMessage(data_message) => Ok(Some(data_message)),
+        // RX: Reject.req
+        MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+        // RX: Unknown
+        _ => Err(Error::from(ErrorKind::InvalidData)),
+      },
+      // RX: No Response
+      None => {
+        // REPLY EXPECTED
+        if reply_expected {
+          // TO: NOT CONNECTED
+          self.disconnect().await?;
+          Err(Error::from(ErrorKind::ConnectionAborted))
+        }
+        // REPLY NOT EXPECTED
+        else {
+          Ok(None)
+        }
+      },
+    }
+  }
+
+  /// ### SELECT PROCEDURE
+  /// **Based on SEMI E37-1109§7.3-7.4**
+  /// 
+  /// Asks the [Client] to initiate the [Select Procedure] by transmitting a
+  /// [Select.req] message and waiting for the corresponding [Select.rsp]
+  /// message to be received.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [NOT SELECTED] state to use this
+  /// procedure.
+  /// 
+  /// The [SELECT INITIATED] state is entered while the [Select.rsp] is
+  /// awaited, so that a [Select.req] received from the Remote Entity with
+  /// the same [Session ID] is accepted as a Simultaneous Select.
+  /// 
+  /// The [Client] will wait to receive the [Select.rsp] for the amount
+  /// of time specified by [T6] before it will consider it a communications
+  /// failure and initiate the [Disconnect Procedure].
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [SELECT INITIATED]:     SelectionState::SelectInitiated
+  /// [Session ID]:           MessageID::session
+  /// [T6]:                   ParameterSettings::t6
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Select Procedure]:     Client::select
+  /// [Select.req]:           MessageContents::SelectRequest
+  /// [Select.rsp]:           MessageContents::SelectResponse
+  pub async fn select(
+    self: &Arc<Self>,
+    id: MessageID,
+  ) -> Result<(), Error> {
+    // IS: SELECT INITIATED, SELECTED, DESELECT INITIATED
+    // TO: SELECT INITIATED
+    if self.selection_state.compare_exchange(SelectionState::NotSelected, SelectionState::SelectInitiated, Relaxed, Relaxed).is_err() {
+      return Err(Error::from(ErrorKind::AlreadyExists))
+    }
+    self.selection_session.store(id.session, Relaxed);
+    // TX: Select.req
+    let result: Result<(), Error> = match self.transmit(
+      Message {
+        id,
+        contents: MessageContents::SelectRequest,
+      },
+      true,
+      self.parameter_settings.t6,
+    ).await {
+      // RX: Response
+      Ok(Some(rx_message)) => match rx_message.contents {
+        // RX: Select.rsp Success
+        MessageContents::SelectResponse(select_status) if select_status == SelectStatus::Success as u8 => Ok(()),
+        // RX: Select.rsp Failure
+        MessageContents::SelectResponse(_) => Err(Error::from(ErrorKind::PermissionDenied)),
+        // RX: Reject.req
+        MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+        // RX: Unknown
+        _ => Err(Error::from(ErrorKind::InvalidData)),
+      },
+      // RX: No Response
+      Ok(None) => {
+        // TO: NOT CONNECTED
+        self.disconnect().await?;
+        Err(Error::from(ErrorKind::ConnectionAborted))
+      },
+      Err(error) => Err(error),
+    };
+    match result {
+      // TO: SELECTED
+      Ok(()) => match self.selection_state.compare_exchange(SelectionState::SelectInitiated, SelectionState::Selected, Relaxed, Relaxed) {
+        Ok(_) => {
+          log::debug!("asynchronous::Client::select: SELECTED");
+          Ok(())
+        },
+        // IS: NOT SELECTED, Disconnected or Separated
+        Err(_) => Err(Error::from(ErrorKind::ConnectionAborted)),
+      },
+      // TO: NOT SELECTED
+      Err(error) => {
+        let _ = self.selection_state.compare_exchange(SelectionState::SelectInitiated, SelectionState::NotSelected, Relaxed, Relaxed);
+        Err(error)
+      },
+    }
+  }
+
+  /// ### LINKTEST PROCEDURE
+  /// **Based on SEMI E37-1109§7.8**
+  /// 
+  /// Asks the [Client] to initiate the [Linktest Procedure] by transmitting a
+  /// [Linktest.req] message and waiting for the corresponding [Linktest.rsp]
+  /// message to be received.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// The [Client] will wait to receive the [Linktest.rsp] for the amount
+  /// of time specified by [T6] before it will consider it a communications
+  /// failure and initiate the [Disconnect Procedure].
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [T6]:                   ParameterSettings::t6
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Linktest Procedure]:   Client::linktest
+  /// [Linktest.req]:         MessageContents::LinktestRequest
+  /// [Linktest.rsp]:         MessageContents::LinktestResponse
+  pub async fn linktest(
+    self: &Arc<Self>,
+    system: u32,
+  ) -> Result<(), Error> {
+    // TX: Linktest.req
+    match self.transmit(
+      Message {
+        id: MessageID {
+          session: SessionID::LINKTEST,
+          system,
+        },
+        contents: MessageContents::LinktestRequest,
+      },
+      true,
+      self.parameter_settings.t6,
+    ).await? {
+      // RX: Response
+      Some(rx_message) => match rx_message.contents {
+        // RX: Linktest.rsp
+        MessageContents::LinktestResponse => Ok(()),
+        // RX: Reject.req
+        MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+        // RX: Unknown
+        _ => Err(Error::from(ErrorKind::InvalidData)),
+      },
+      // RX: No Response
+      None => {
+        // TO: NOT CONNECTED
+        self.disconnect().await?;
+        Err(Error::from(ErrorKind::ConnectionAborted))
+      },
+    }
+  }
+
+  /// ### SEPARATE PROCEDURE
+  /// **Based on SEMI E37-1109§7.9**
+  /// 
+  /// Asks the [Client] to initiate the [Separate Procedure] by transmitting a
+  /// [Separate.req] message.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// 
+  /// Upon completion of the [Separate Procedure], the [NOT SELECTED] state is
+  /// entered.
+  /// 
+  /// [Connection State]:   primitive::ConnectionState
+  /// [CONNECTED]:          primitive::ConnectionState::Connected
+  /// [Selection State]:    SelectionState
+  /// [NOT SELECTED]:       SelectionState::NotSelected
+  /// [SELECTED]:           SelectionState::Selected
+  /// [Separate Procedure]: Client::separate
+  /// [Separate.req]:       MessageContents::SeparateRequest
+  pub async fn separate(
+    self: &Arc<Self>,
+    id: MessageID,
+  ) -> Result<(), Error> {
+    // IS: NOT SELECTED, SELECT INITIATED
+    if matches!(self.selection_state.load(Relaxed), SelectionState::NotSelected | SelectionState::SelectInitiated) {
+      return Err(Error::from(ErrorKind::PermissionDenied))
+    }
+    // TX: Separate.req
+    self.transmit(
+      Message {
+        id,
+        contents: MessageContents::SeparateRequest,
+      },
+      false,
+      self.parameter_settings.t6,
+    ).await?;
+    // TO: NOT SELECTED
+    self.selection_state.store(SelectionState::NotSelected, Relaxed);
+    log::debug!("asynchronous::Client::separate: NOT SELECTED");
+    Ok(())
+  }
+}
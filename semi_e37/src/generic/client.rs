@@ -0,0 +1,3597 @@
+//! # CLIENT
+//! 
+//! Defines the [Client] and the procedures it provides, along with the
+//! errors those procedures report.
+//! 
+//! [Client]: Client
+
+use super::*;
+
+/// ## SHUTDOWN LINGER
+/// 
+/// How long the [Shutdown Procedure] waits after transmitting a
+/// [Separate.req] before closing the TCP/IP connection, so that the Remote
+/// Entity may receive it before the connection is closed.
+/// 
+/// [Shutdown Procedure]: Client::shutdown
+/// [Separate.req]:       MessageContents::SeparateRequest
+const SHUTDOWN_LINGER: Duration = Duration::from_millis(100);
+
+/// ## CLIENT
+/// 
+/// Encapsulates the full functionality of the [HSMS] protocol without
+/// reference to any subsidiary standards, known as the [Generic Services].
+/// 
+/// [HSMS]:             crate
+/// [Generic Services]: crate::generic
+pub struct Client {
+  parameter_settings: ParameterSettings,
+  primitive_client: Arc<primitive::Client>,
+  selection_state: Atomic<SelectionState>,
+  selection_mutex: Mutex<()>,
+  selection_gate: Mutex<()>,
+  selection_changed: Condvar,
+  selection_count: AtomicU64,
+  selection_session: Atomic<SessionID>,
+  #[cfg(feature = "multi_session")]
+  other_sessions: Mutex<HashSet<SessionID>>,
+  #[cfg(feature = "multi_session")]
+  session_senders: Mutex<HashMap<SessionID, primitive::HookSender<Inbound>>>,
+  connection_count: AtomicU64,
+  dropped_inbound: AtomicU64,
+  draining: AtomicBool,
+  data_activity: Mutex<Instant>,
+  t7_start: Mutex<Instant>,
+  t7_changed: Condvar,
+  outbox: Mutex<HashMap<MessageID, SendOnce<Option<Message>>>>,
+  outbox_changed: Condvar,
+  system: Mutex<u32>,
+  transition_senders: Mutex<Vec<Sender<ConnectionStateTransition>>>,
+  malformed_senders: Mutex<Vec<Sender<(primitive::Message, RejectReason)>>>,
+  select_policy: RwLock<SelectPolicy>,
+}
+
+/// ## SELECTION GUARD
+/// 
+/// Holds the [Selection State] locked for a procedure affecting it, and
+/// upon release wakes the [Receive Procedure] if it is waiting to answer a
+/// [Select.req].
+/// 
+/// [Selection State]:   SelectionState
+/// [Receive Procedure]: Client::receive
+/// [Select.req]:        MessageContents::SelectRequest
+struct SelectionGuard<'a> {
+  client: &'a Client,
+  guard: Option<MutexGuard<'a, ()>>,
+}
+impl Drop for SelectionGuard<'_> {
+  fn drop(&mut self) {
+    drop(self.guard.take());
+    self.client.notify_selection();
+  }
+}
+
+/// ## SELECT POLICY
+/// 
+/// A callback provided to the [Set Select Policy] function, which is invoked
+/// with the [Session ID] of each [Select.req] message received in the
+/// [NOT SELECTED] state, and decides the [Select Status] to respond with.
+/// 
+/// [Set Select Policy]: Client::set_select_policy
+/// [Session ID]:        MessageID::session
+/// [Select.req]:        MessageContents::SelectRequest
+/// [NOT SELECTED]:      SelectionState::NotSelected
+/// [Select Status]:     SelectStatus
+pub type SelectPolicy = Box<dyn Fn(SessionID) -> SelectStatus + Send + Sync>;
+
+/// ### REPLY RECEIVER
+/// 
+/// Receives the reply to a [Message] placed in the outbox, or [None] if the
+/// connection was lost before it arrived.
+type ReplyReceiver = oneshot::Receiver<Option<Message>>;
+
+/// ## CONNECTION PROCEDURES
+/// **Based on SEMI E37-1109§6.3-6.5**
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// establishing and breaking a TCP/IP connection.
+/// 
+/// - [New Client]
+/// - [Connect Procedure]
+/// - [Disconnect Procedure]
+/// - [Graceful Disconnect Procedure]
+/// - [Shutdown Procedure]
+/// - [Supervised Connect Procedure]
+/// - [Connect and Select Procedure]
+/// 
+/// [Client]:                        Client
+/// [New Client]:                    Client::new
+/// [Connect Procedure]:             Client::connect
+/// [Disconnect Procedure]:          Client::disconnect
+/// [Graceful Disconnect Procedure]: Client::disconnect_graceful
+/// [Shutdown Procedure]:            Client::shutdown
+/// [Supervised Connect Procedure]:  Client::connect_supervised
+/// [Connect and Select Procedure]:  Client::connect_and_select
+impl Client {
+  /// ### NEW CLIENT
+  /// 
+  /// 
+  /// Creates a [Client] in the [NOT CONNECTED] state, ready to initiate the
+  /// [Connect Procedure].
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [NOT CONNECTED]:     primitive::ConnectionState::NotConnected
+  pub fn new(
+    parameter_settings: ParameterSettings
+  ) -> Arc<Self> {
+    let primitive_client: Arc<primitive::Client> = primitive::Client::new();
+    primitive_client.set_listen_options(parameter_settings.backlog, parameter_settings.accept_timeout);
+    primitive_client.set_keepalive(parameter_settings.keepalive);
+    if parameter_settings.inbound_overflow == InboundOverflow::Block {
+      primitive_client.set_rx_capacity(parameter_settings.inbound_capacity);
+    }
+    Arc::new(Client {
+      parameter_settings,
+      primitive_client,
+      selection_state:    Default::default(),
+      selection_mutex:    Default::default(),
+      selection_gate:     Default::default(),
+      selection_changed:  Default::default(),
+      selection_count:    Default::default(),
+      selection_session:  Default::default(),
+      #[cfg(feature = "multi_session")]
+      other_sessions:     Default::default(),
+      #[cfg(feature = "multi_session")]
+      session_senders:    Default::default(),
+      connection_count:   Default::default(),
+      dropped_inbound:    Default::default(),
+      draining:           Default::default(),
+      data_activity:      Mutex::new(Instant::now()),
+      t7_start:           Mutex::new(Instant::now()),
+      t7_changed:         Default::default(),
+      outbox:             Default::default(),
+      outbox_changed:     Default::default(),
+      system:             Mutex::new(Self::system_seed()),
+      transition_senders: Default::default(),
+      malformed_senders:  Default::default(),
+      select_policy:      RwLock::new(Box::new(|_| SelectStatus::Success)),
+    })
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.3.4-6.3.7**
+  /// 
+  /// Connects the [Client] to the Remote Entity.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [NOT CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connect Procedure] has two different behaviors based on the
+  /// [Connection Mode] provided to it:
+  /// - [PASSIVE] - The socket address of the Local Entity must be provided,
+  ///   and the [Client] binds to it, then listens for and accepts the
+  ///   [Connect Procedure] when initiated by the Remote Entity, waiting up to
+  ///   the time specified by the [Accept Timeout] if there is one.
+  /// - [ACTIVE] - The socket address of the Remote Entity must be provided,
+  ///   and the [Client] initiates the [Connect Procedure] and waits up to the
+  ///   time specified by [T5] for the Remote Entity to respond, trying each
+  ///   address the socket address resolves to in turn until one succeeds.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Connect Procedure], the [T8] parameter is set as
+  /// the TCP stream's read and write timeout, and the [CONNECTED] state is
+  /// entered.
+  /// 
+  /// The [Client] then begins the [Not Selected Timeout], and will initiate
+  /// the [Disconnect Procedure] if it has not entered the [SELECTED] state
+  /// within the time specified by [T7].
+  /// 
+  /// If a [Linktest Interval] is specified, the [Client] also begins the
+  /// [Linktest Heartbeat], and will initiate the [Disconnect Procedure] if
+  /// any [Linktest Procedure] it initiates fails.
+  /// 
+  /// If an [Idle Timeout] is specified, the [Client] also begins the
+  /// [Idle Timer], and will initiate the [Disconnect Procedure] if it remains
+  /// in the [SELECTED] state without any [Data Message] for that long.
+  /// 
+  /// If an [Inbound Capacity] is specified, the hook provided holds no more
+  /// Primary [Data Message]s than that, and those received while it is full
+  /// are handled according to the [Inbound Overflow] parameter.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Connect Procedure] fails, a [Connect Error] is returned which
+  /// describes the reason for the failure.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Connection Mode]:      primitive::ConnectionMode
+  /// [PASSIVE]:              primitive::ConnectionMode::Passive
+  /// [ACTIVE]:               primitive::ConnectionMode::Active
+  /// [SELECTED]:             SelectionState::Selected
+  /// [Client]:               Client
+  /// [Connect Procedure]:    Client::connect
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Not Selected Timeout]: ParameterSettings::t7
+  /// [Linktest Heartbeat]:   ParameterSettings::linktest_interval
+  /// [Linktest Procedure]:   Client::linktest
+  /// [T5]:                   ParameterSettings::t5
+  /// [T7]:                   ParameterSettings::t7
+  /// [T8]:                   ParameterSettings::t8
+  /// [Linktest Interval]:    ParameterSettings::linktest_interval
+  /// [Accept Timeout]:       ParameterSettings::accept_timeout
+  /// [Idle Timeout]:         ParameterSettings::idle_timeout
+  /// [Idle Timer]:           ParameterSettings::idle_timeout
+  /// [Inbound Capacity]:     ParameterSettings::inbound_capacity
+  /// [Inbound Overflow]:     ParameterSettings::inbound_overflow
+  /// [Data Message]:         MessageContents::DataMessage
+  /// [Connect Error]:        ConnectError
+  pub fn connect(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<(SocketAddr, Receiver<Inbound>), ConnectError> {
+    // Connect Primitive Client
+    let (socket, rx_receiver) = self.primitive_client.connect(entity, self.parameter_settings.connect_mode, self.parameter_settings.t5, self.parameter_settings.t8, self.parameter_settings.max_message_length, self.parameter_settings.resynchronize)?;
+    Ok(self.connected(socket, rx_receiver))
+  }
+
+  /// ### BIND
+  /// 
+  /// Binds the TCP listener used by the [Connect Procedure] with a
+  /// [Connect Mode] of [PASSIVE] ahead of time, and provides the socket
+  /// address it was bound to, as described by the [Primitive Bind] function.
+  /// 
+  /// This allows a Local Entity bound to port 0 to learn its ephemeral port
+  /// before waiting for the Remote Entity to connect.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connect Mode]:      ParameterSettings::connect_mode
+  /// [PASSIVE]:           primitive::ConnectionMode::Passive
+  /// [Primitive Bind]:    primitive::Client::bind
+  pub fn bind(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<SocketAddr, ConnectError> {
+    self.primitive_client.bind(entity)
+  }
+
+  /// ### CONNECT PROCEDURE (SOCKET ADDRESS)
+  /// **Based on SEMI E37-1109§6.3.4-6.3.7**
+  /// 
+  /// Connects the [Client] to the Remote Entity in the same manner as the
+  /// [Connect Procedure], using an already determined socket address rather
+  /// than one which must be resolved.
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  pub fn connect_addr(
+    self: &Arc<Self>,
+    socket: SocketAddr,
+  ) -> Result<(SocketAddr, Receiver<Inbound>), ConnectError> {
+    // Connect Primitive Client
+    let (socket, rx_receiver) = self.primitive_client.connect_addr(socket, self.parameter_settings.connect_mode, self.parameter_settings.t5, self.parameter_settings.t8, self.parameter_settings.max_message_length, self.parameter_settings.resynchronize)?;
+    Ok(self.connected(socket, rx_receiver))
+  }
+
+  /// ### CONNECT AND SELECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.3.4-6.3.7, §7.4**
+  /// 
+  /// Initiates the [Connect Procedure], and then waits for the [SELECTED]
+  /// state to be entered before providing the hook for [Data Message]s.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The way in which the [SELECTED] state is entered depends on the
+  /// [Connection Mode]:
+  /// - [ACTIVE] - The [Client] initiates the [Select Procedure] with the
+  ///   provided [Session ID].
+  /// - [PASSIVE] - The [Client] waits for the Remote Entity to initiate the
+  ///   [Select Procedure], which must occur within the time specified by
+  ///   [T7]. The provided [Session ID] is not used, as the [Select Status]
+  ///   is decided by the [Select Policy].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Connect Procedure] fails, or the [SELECTED] state is not
+  /// entered, a [Connect Select Error] is returned which describes the
+  /// reason for the failure. The [Client] is left in the [NOT CONNECTED]
+  /// state in every such case.
+  /// 
+  /// [Connection Mode]:      primitive::ConnectionMode
+  /// [PASSIVE]:              primitive::ConnectionMode::Passive
+  /// [ACTIVE]:               primitive::ConnectionMode::Active
+  /// [SELECTED]:             SelectionState::Selected
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [Client]:               Client
+  /// [Connect Procedure]:    Client::connect
+  /// [Select Procedure]:     Client::select
+  /// [Select Policy]:        Client::set_select_policy
+  /// [Session ID]:           MessageID::session
+  /// [Select Status]:        SelectStatus
+  /// [Data Message]:         MessageContents::DataMessage
+  /// [T7]:                   ParameterSettings::t7
+  /// [Connect Select Error]: ConnectSelectError
+  pub fn connect_and_select(
+    self: &Arc<Self>,
+    entity: &str,
+    session: SessionID,
+  ) -> Result<(SocketAddr, Receiver<Inbound>), ConnectSelectError> {
+    // Subscribe Before Connecting
+    let transitions: Receiver<ConnectionStateTransition> = self.subscribe_transitions();
+    // TO: CONNECTED
+    let (socket, data_receiver) = self.connect(entity)?;
+    // TO: SELECTED
+    let result: Result<(), ConnectSelectError> = match self.parameter_settings.connect_mode {
+      // CONNECTION MODE: ACTIVE
+      ConnectionMode::Active => {
+        let system: u32 = self.next_system();
+        match self.select(MessageID {session, system}).join() {
+          Ok(result) => result.map_err(ConnectSelectError::Select),
+          Err(_) => Err(ConnectSelectError::Select(SelectError::Io(Error::other("select procedure panicked")))),
+        }
+      },
+      // CONNECTION MODE: PASSIVE
+      ConnectionMode::Passive => loop {
+        match transitions.recv() {
+          Ok(ConnectionStateTransition::NotSelectedToSelected) => break Ok(()),
+          Ok(ConnectionStateTransition::ConnectedToNotConnected) | Err(_) => break Err(ConnectSelectError::NotSelected),
+          Ok(_) => {},
+        }
+      },
+    };
+    // TO: NOT CONNECTED
+    if result.is_err() {
+      let _ = self.disconnect();
+    }
+    result?;
+    Ok((socket, data_receiver))
+  }
+
+  /// ### CONNECTED
+  /// 
+  /// Completes the [Connect Procedure] once the [Primitive Client] has
+  /// entered the [CONNECTED] state.
+  /// 
+  /// [Primitive Client]:  primitive::Client
+  /// [CONNECTED]:         primitive::ConnectionState::Connected
+  /// [Connect Procedure]: Client::connect
+  fn connected(
+    self: &Arc<Self>,
+    socket: SocketAddr,
+    rx_receiver: Receiver<primitive::Message>,
+  ) -> (SocketAddr, Receiver<Inbound>) {
+    // Create Channel
+    let (data_sender, data_receiver) = primitive::HookSender::<Inbound>::channel(self.parameter_settings.inbound_capacity);
+    // TO: CONNECTED, NOT SELECTED
+    let count: u64 = self.connection_count.fetch_add(1, Relaxed) + 1;
+    self.transition(ConnectionStateTransition::NotConnectedToNotSelected);
+    // Start RX Thread
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {clone.receive(count, rx_receiver, data_sender)});
+    // Start T7 Timer
+    self.not_selected_timeout();
+    self.not_selected_timer(count);
+    // Start Linktest Heartbeat
+    if let Some(interval) = self.parameter_settings.linktest_interval {
+      self.linktest_heartbeat(count, interval);
+    }
+    // Start Idle Timer
+    if let Some(idle_timeout) = self.parameter_settings.idle_timeout {
+      self.idle_timer(count, idle_timeout);
+    }
+    // Finish
+    (socket, data_receiver)
+  }
+
+  /// ### DISCONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.4-6.5**
+  /// 
+  /// Disconnects the [Client] from the Remote Entity.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Disconnect Procedure], the [NOT CONNECTED] state
+  /// is entered.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  pub fn disconnect(
+    self: &Arc<Self>,
+  ) -> Result<(), Error> {
+    // TO: NOT CONNECTED
+    let result: Result<(), Error> = self.break_connection();
+    // TO: NOT SELECTED
+    let _guard = self.lock_selection();
+    self.leave_selection();
+    // Finish
+    result
+  }
+
+  /// ### BREAK CONNECTION
+  /// 
+  /// Performs the part of the [Disconnect Procedure] which enters the
+  /// [NOT CONNECTED] state, without locking the [Selection State].
+  /// 
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [Selection State]:      SelectionState
+  fn break_connection(
+    self: &Arc<Self>,
+  ) -> Result<(), Error> {
+    // Stop Linktest Heartbeat: Also stops the receive thread from racing to
+    // disconnect once the TCP/IP connection is shut down.
+    self.connection_count.fetch_add(1, Relaxed);
+    // Stop T7 Timer
+    {
+      let _start = self.t7_start.lock().unwrap();
+      self.t7_changed.notify_all();
+    }
+    // TO: NOT CONNECTED
+    let result: Result<(), Error> = self.primitive_client.disconnect();
+    if result.is_ok() {
+      self.transition(ConnectionStateTransition::ConnectedToNotConnected);
+    }
+    result
+  }
+
+  /// ### LEAVE SELECTION
+  /// 
+  /// Performs the part of the [Disconnect Procedure] which enters the
+  /// [NOT SELECTED] state and cancels the [Not Selected Timeout].
+  /// 
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [Not Selected Timeout]: ParameterSettings::t7
+  fn leave_selection(&self) {
+    if self.selection_state.swap(SelectionState::NotSelected, Relaxed) != SelectionState::NotSelected {
+      log::debug!("generic::Client::disconnect: NOT SELECTED");
+    }
+    // Cancel T7 Timer
+    self.selection_count.fetch_add(1, Relaxed);
+  }
+
+  /// ### GRACEFUL DISCONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.4-6.5**
+  /// 
+  /// Disconnects the [Client] from the Remote Entity once its open
+  /// transactions have completed.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Client] immediately stops initiating new procedures which expect a
+  /// reply, and waits up to the time specified for the replies to procedures
+  /// which are already open to be received, before initiating the
+  /// [Disconnect Procedure].
+  /// 
+  /// Upon completion, the number of procedures which were abandoned without
+  /// having received a reply is returned.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  pub fn disconnect_graceful(
+    self: &Arc<Self>,
+    timeout: Duration,
+  ) -> Result<usize, Error> {
+    // IS: NOT CONNECTED
+    if !self.primitive_client.is_connected() {
+      return Err(Error::from(ErrorKind::NotConnected))
+    }
+    // Stop New Transactions
+    self.draining.store(true, Relaxed);
+    // Wait for Outbox
+    let start = Instant::now();
+    let mut outbox = self.outbox.lock().unwrap();
+    while !outbox.deref().is_empty() {
+      let remaining: Duration = timeout.saturating_sub(start.elapsed());
+      if remaining.is_zero() {break}
+      outbox = self.outbox_changed.wait_timeout(outbox, remaining).unwrap().0;
+    }
+    let abandoned: usize = outbox.deref().len();
+    drop(outbox);
+    // TO: NOT CONNECTED: Abandoned procedures are woken by the disconnection
+    // and may race to disconnect first, which is not a failure.
+    let result: Result<(), Error> = match self.disconnect() {
+      Err(error) if error.kind() == ErrorKind::NotConnected && abandoned > 0 => Ok(()),
+      result => result,
+    };
+    self.draining.store(false, Relaxed);
+    result?;
+    Ok(abandoned)
+  }
+
+  /// ### SHUTDOWN PROCEDURE
+  /// **Based on SEMI E37-1109§6.4-6.5, §7.9**
+  /// 
+  /// Ends the session with the Remote Entity, if there is one, before
+  /// disconnecting the [Client] from it.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Selection State] is in the [SELECTED] state, the
+  /// [Separate Procedure] is initiated with each selected [Session ID], and
+  /// the [Client] waits briefly for the [Separate.req] to reach the Remote
+  /// Entity. The [Disconnect Procedure] is then initiated regardless of the
+  /// outcome, so that the Remote Entity knows the session ended
+  /// intentionally rather than through the loss of the TCP/IP connection.
+  /// 
+  /// The [Disconnect Procedure] remains available to close the TCP/IP
+  /// connection abruptly.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Shutdown Procedure], the [NOT CONNECTED] state
+  /// is entered.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [SELECTED]:             SelectionState::Selected
+  /// [Client]:               Client
+  /// [Separate Procedure]:   Client::separate
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Shutdown Procedure]:   Client::shutdown
+  /// [Separate.req]:         MessageContents::SeparateRequest
+  /// [Session ID]:           MessageID::session
+  pub fn shutdown(
+    self: &Arc<Self>,
+  ) -> Result<(), Error> {
+    // IS: SELECTED
+    let mut separated: bool = false;
+    for session in self.selected_sessions() {
+      // TX: Separate.req
+      let system: u32 = self.next_system();
+      if let Ok(Ok(())) = self.separate(MessageID {session, system}).join() {
+        separated = true;
+      }
+    }
+    if separated {
+      thread::sleep(SHUTDOWN_LINGER);
+    }
+    // TO: NOT CONNECTED
+    self.disconnect()
+  }
+
+  /// ### SUPERVISED CONNECT PROCEDURE
+  /// **Based on SEMI E37-1109§6.3.7**
+  /// 
+  /// Repeatedly initiates the [Connect Procedure] on a background thread,
+  /// keeping the [Client] connected to the Remote Entity until the returned
+  /// [Supervisor] is cancelled.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// This is intended for use with a [Connection Mode] of [ACTIVE], as the
+  /// standard recommends that the active entity periodically retries the
+  /// [Connect Procedure] until it succeeds.
+  /// 
+  /// After each failed [Connect Procedure], and each time the
+  /// [NOT CONNECTED] state is entered, the [Client] waits before trying again
+  /// for at least the time specified by [T5]. The wait grows according to
+  /// the [Retry Policy] while attempts keep failing, and is reset once one
+  /// succeeds.
+  /// 
+  /// Once connected, the [Client] initiates the [Select Procedure] if the
+  /// [Retry Policy] provides a [Session ID] to select.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// [Data Message]s received over every connection are sent to the single
+  /// hook returned alongside the [Supervisor], and each change of
+  /// [Connection State] is reported as usual to the hooks provided by the
+  /// [Subscribe Transitions] function.
+  /// 
+  /// [Connection State]:      primitive::ConnectionState
+  /// [NOT CONNECTED]:         primitive::ConnectionState::NotConnected
+  /// [Connection Mode]:       primitive::ConnectionMode
+  /// [ACTIVE]:                primitive::ConnectionMode::Active
+  /// [Client]:                Client
+  /// [Connect Procedure]:     Client::connect
+  /// [Select Procedure]:      Client::select
+  /// [Subscribe Transitions]: Client::subscribe_transitions
+  /// [Supervisor]:            Supervisor
+  /// [Retry Policy]:          RetryPolicy
+  /// [Session ID]:            RetryPolicy::select_session
+  /// [Data Message]:          MessageContents::DataMessage
+  /// [T5]:                    ParameterSettings::t5
+  pub fn connect_supervised(
+    self: &Arc<Self>,
+    entity: &str,
+    retry_policy: RetryPolicy,
+  ) -> (Supervisor, Receiver<Inbound>) {
+    // Create Channels
+    let (data_sender, data_receiver) = primitive::HookSender::<Inbound>::channel(self.parameter_settings.inbound_capacity);
+    let (cancel_sender, cancel_receiver) = channel::<()>();
+    // Start Supervisor Thread
+    let clone: Arc<Client> = self.clone();
+    let entity: String = entity.to_string();
+    let thread: JoinHandle<()> = thread::spawn(move || {
+      let minimum_delay: Duration = retry_policy.initial_delay.max(clone.parameter_settings.t5);
+      let mut delay: Duration = minimum_delay;
+      loop {
+        // TO: CONNECTED
+        match clone.connect(&entity) {
+          Ok((_socket, rx_receiver)) => {
+            delay = minimum_delay;
+            // IS: CANCELLED
+            if let Err(TryRecvError::Disconnected) = cancel_receiver.try_recv() {
+              let _ = clone.disconnect();
+              return
+            }
+            // TO: SELECTED
+            if let Some(session) = retry_policy.select_session {
+              let system: u32 = clone.next_system();
+              if let Ok(Err(error)) = clone.select(MessageID {session, system}).join() {
+                log::debug!("generic::Client::connect_supervised: SELECT FAILED {}", error);
+              }
+            }
+            // INBOX: Forward Until NOT CONNECTED
+            for data in rx_receiver {
+              let _ = clone.deliver_inbound(&data_sender, data);
+            }
+          },
+          Err(error) => {
+            log::debug!("generic::Client::connect_supervised: CONNECT FAILED {}", error);
+          },
+        }
+        // WAIT: Until Retry or Cancelled
+        match cancel_receiver.recv_timeout(delay) {
+          Err(RecvTimeoutError::Timeout) => {},
+          _ => return,
+        }
+        delay = delay.saturating_mul(retry_policy.multiplier).min(retry_policy.max_delay.max(minimum_delay));
+      }
+    });
+    // Finish
+    (
+      Supervisor {
+        client: self.clone(),
+        cancel_sender,
+        thread,
+      },
+      data_receiver,
+    )
+  }
+
+  /// ### IS CONNECTED
+  /// 
+  /// Reports whether the [Connection State] is in the [CONNECTED] state.
+  /// 
+  /// [Connection State]: primitive::ConnectionState
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  pub fn is_connected(
+    self: &Arc<Self>,
+  ) -> bool {
+    self.primitive_client.is_connected()
+  }
+
+  /// ### PEER ADDRESS
+  /// 
+  /// Provides the socket address of the Remote Entity, or [None] if the
+  /// [Primitive Client] is not in the [CONNECTED] state.
+  /// 
+  /// [Primitive Client]: primitive::Client
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  pub fn peer_addr(
+    self: &Arc<Self>,
+  ) -> Option<SocketAddr> {
+    self.primitive_client.peer_addr()
+  }
+
+  /// ### LOCAL ADDRESS
+  /// 
+  /// Provides the socket address of the Local Entity, or [None] if the
+  /// [Primitive Client] is not in the [CONNECTED] state.
+  /// 
+  /// [Primitive Client]: primitive::Client
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  pub fn local_addr(
+    self: &Arc<Self>,
+  ) -> Option<SocketAddr> {
+    self.primitive_client.local_addr()
+  }
+
+  /// ### DISCONNECT REASON
+  /// 
+  /// Provides the [Disconnect Reason] for which the [Primitive Client] most
+  /// recently left the [CONNECTED] state, or [None] if it is in the
+  /// [CONNECTED] state or has never been connected.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Disconnect Reason] is recorded before the
+  /// [CONNECTED TO NOT CONNECTED] transition is provided to the hooks
+  /// returned by the [Subscribe Transitions] function, and before the hook
+  /// provided by the [Connect Procedure] is closed.
+  /// 
+  /// [Primitive Client]:           primitive::Client
+  /// [CONNECTED]:                  primitive::ConnectionState::Connected
+  /// [Disconnect Reason]:          DisconnectReason
+  /// [CONNECTED TO NOT CONNECTED]: ConnectionStateTransition::ConnectedToNotConnected
+  /// [Subscribe Transitions]:      Client::subscribe_transitions
+  /// [Connect Procedure]:          Client::connect
+  pub fn disconnect_reason(
+    self: &Arc<Self>,
+  ) -> Option<DisconnectReason> {
+    self.primitive_client.disconnect_reason()
+  }
+
+  /// ### PARAMETER SETTINGS
+  /// 
+  /// Provides the [Parameter Settings] with which the [Client] was created.
+  /// 
+  /// [Client]:             Client
+  /// [Parameter Settings]: ParameterSettings
+  pub fn parameter_settings(
+    self: &Arc<Self>,
+  ) -> ParameterSettings {
+    self.parameter_settings
+  }
+
+  /// ### CLIENT STATS
+  /// 
+  /// Provides the number of messages and bytes received and transmitted by
+  /// the [Primitive Client], including control messages.
+  /// 
+  /// [Primitive Client]: primitive::Client
+  pub fn stats(
+    self: &Arc<Self>,
+  ) -> primitive::ClientStats {
+    self.primitive_client.stats()
+  }
+
+  /// ### DROPPED INBOUND
+  /// 
+  /// Provides the number of Primary [Data Message]s discarded because the
+  /// hook provided by the [Connect Procedure], or by the Subscribe Session
+  /// function with the `multi_session` feature, was full while the
+  /// [Inbound Overflow] parameter was [DROP].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Inbound Overflow]:  ParameterSettings::inbound_overflow
+  /// [DROP]:              InboundOverflow::Drop
+  pub fn dropped_inbound(
+    self: &Arc<Self>,
+  ) -> u64 {
+    self.dropped_inbound.load(Relaxed)
+  }
+
+  /// ### DELIVER INBOUND
+  /// 
+  /// Sends an [Inbound] Primary [Data Message] to a hook provided by the
+  /// [Connect Procedure] or the [Subscribe Session] function, applying the
+  /// [Inbound Overflow] policy if it is full, and gives the [Inbound] back
+  /// once the hook has been dropped.
+  /// 
+  /// [Inbound]:           Inbound
+  /// [Connect Procedure]: Client::connect
+  /// [Subscribe Session]: Client::subscribe_session
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Inbound Overflow]:  ParameterSettings::inbound_overflow
+  fn deliver_inbound(
+    &self,
+    sender: &primitive::HookSender<Inbound>,
+    inbound: Inbound,
+  ) -> Result<(), Inbound> {
+    match self.parameter_settings.inbound_overflow {
+      InboundOverflow::Block => sender.send(inbound).map_err(|SendError(inbound)| inbound),
+      InboundOverflow::Drop => match sender.try_send(inbound) {
+        Ok(()) => Ok(()),
+        // Hook Full
+        Err(TrySendError::Full(inbound)) => {
+          log::debug!("generic::Client::deliver_inbound: DROPPED {:?}", inbound.id);
+          self.dropped_inbound.fetch_add(1, Relaxed);
+          Ok(())
+        },
+        // Hook Dropped
+        Err(TrySendError::Disconnected(inbound)) => Err(inbound),
+      },
+    }
+  }
+
+  /// ### SELECTION STATE
+  /// 
+  /// Provides the current [Selection State].
+  /// 
+  /// [Selection State]: SelectionState
+  pub fn selection_state(
+    self: &Arc<Self>,
+  ) -> SelectionState {
+    self.selection_state.load(Relaxed)
+  }
+
+  /// ### SUBSCRIBE TRANSITIONS
+  /// 
+  /// Provides a hook which receives a [Connection State Transition] each time
+  /// the [Client] moves between the [Connection State]s and
+  /// [Selection State]s.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Client] never waits on the hook, and stops sending to it once it
+  /// has been dropped.
+  /// 
+  /// [Client]:                      Client
+  /// [Connection State]:            primitive::ConnectionState
+  /// [Selection State]:             SelectionState
+  /// [Connection State Transition]: ConnectionStateTransition
+  pub fn subscribe_transitions(
+    self: &Arc<Self>,
+  ) -> Receiver<ConnectionStateTransition> {
+    let (sender, receiver) = channel::<ConnectionStateTransition>();
+    self.transition_senders.lock().unwrap().deref_mut().push(sender);
+    receiver
+  }
+
+  /// ### SUBSCRIBE SESSION
+  /// 
+  /// Provides a hook which receives each Primary [Data Message] sent on the
+  /// provided [Session ID] in place of the hook provided by the
+  /// [Connect Procedure], replacing any hook previously provided for it.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The hook remains in place while the [Session ID] is deselected and
+  /// selected again, and once it has been dropped, [Data Message]s sent on
+  /// the [Session ID] return to the hook provided by the
+  /// [Connect Procedure].
+  /// 
+  /// The hook is bounded by the [Inbound Capacity] and applies the
+  /// [Inbound Overflow] policy, in the same manner as the hook provided by
+  /// the [Connect Procedure].
+  /// 
+  /// Only available with the `multi_session` feature.
+  /// 
+  /// [Inbound Capacity]:  ParameterSettings::inbound_capacity
+  /// [Inbound Overflow]:  ParameterSettings::inbound_overflow
+  /// [Connect Procedure]: Client::connect
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Session ID]:        MessageID::session
+  #[cfg(feature = "multi_session")]
+  pub fn subscribe_session(
+    self: &Arc<Self>,
+    session: SessionID,
+  ) -> Receiver<Inbound> {
+    let (sender, receiver) = primitive::HookSender::<Inbound>::channel(self.parameter_settings.inbound_capacity);
+    self.session_senders.lock().unwrap().deref_mut().insert(session, sender);
+    receiver
+  }
+
+  /// ### ROUTE SESSION
+  /// 
+  /// Sends an [Inbound] Primary [Data Message] to the hook provided by the
+  /// [Subscribe Session] function for its [Session ID], if there is one, in
+  /// the same manner as the [Deliver Inbound] function, and otherwise gives
+  /// it back.
+  /// 
+  /// [Inbound]:           Inbound
+  /// [Subscribe Session]: Client::subscribe_session
+  /// [Deliver Inbound]:   Client::deliver_inbound
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Session ID]:        MessageID::session
+  #[cfg(feature = "multi_session")]
+  fn route_session(
+    &self,
+    inbound: Inbound,
+  ) -> Option<Inbound> {
+    // The lock is not held while delivering, as the hook may be full.
+    let sender: primitive::HookSender<Inbound> = match self.session_senders.lock().unwrap().get(&inbound.id.session) {
+      Some(sender) => sender.clone(),
+      None => return Some(inbound),
+    };
+    match self.deliver_inbound(&sender, inbound) {
+      Ok(()) => None,
+      // Hook Dropped
+      Err(inbound) => {
+        self.session_senders.lock().unwrap().remove(&inbound.id.session);
+        Some(inbound)
+      },
+    }
+  }
+
+  /// ### SUBSCRIBE MALFORMED
+  /// 
+  /// Provides a hook which receives each message that could not be
+  /// interpreted as a [Message], along with the [Reject Reason] describing
+  /// why, while the [Auto Reject] parameter is false.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Client] never waits on the hook, and stops sending to it once it
+  /// has been dropped. While the [Auto Reject] parameter is true, nothing is
+  /// sent to the hook.
+  /// 
+  /// [Client]:        Client
+  /// [Message]:       Message
+  /// [Reject Reason]: RejectReason
+  /// [Auto Reject]:   ParameterSettings::auto_reject
+  pub fn subscribe_malformed(
+    self: &Arc<Self>,
+  ) -> Receiver<(primitive::Message, RejectReason)> {
+    let (sender, receiver) = channel::<(primitive::Message, RejectReason)>();
+    self.malformed_senders.lock().unwrap().deref_mut().push(sender);
+    receiver
+  }
+
+  /// ### SET SELECT POLICY
+  /// 
+  /// Provides the [Client] with a [Select Policy] to be consulted whenever a
+  /// [Select.req] message is received in the [NOT SELECTED] state, replacing
+  /// the previously provided [Select Policy]. The default [Select Policy]
+  /// always responds with [Success].
+  /// 
+  /// If the [Select Policy] provides any [Select Status] other than
+  /// [Success], such as [Not Ready] while the Local Entity is initializing or
+  /// [Exhausted] when it can accept no more sessions, that status is
+  /// transmitted in the [Select.rsp] message and the [NOT SELECTED] state is
+  /// not left.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Select Policy] must not itself call the [Set Select Policy]
+  /// function.
+  /// 
+  /// [Client]:            Client
+  /// [Set Select Policy]: Client::set_select_policy
+  /// [Select Policy]:     SelectPolicy
+  /// [Select Status]:     SelectStatus
+  /// [Success]:           SelectStatus::Success
+  /// [Not Ready]:         SelectStatus::NotReady
+  /// [Exhausted]:         SelectStatus::Exhausted
+  /// [Select.req]:        MessageContents::SelectRequest
+  /// [Select.rsp]:        MessageContents::SelectResponse
+  /// [NOT SELECTED]:      SelectionState::NotSelected
+  pub fn set_select_policy(
+    self: &Arc<Self>,
+    select_policy: SelectPolicy,
+  ) {
+    *self.select_policy.write().unwrap().deref_mut() = select_policy;
+  }
+
+  /// ### SET TLS SETTINGS
+  /// 
+  /// Provides [TLS Settings] to be used by subsequent initiations of the
+  /// [Connect Procedure], or returns to unencrypted connections if [None] is
+  /// provided. The [TLS Settings] must match the [Connection Mode] in the
+  /// [Parameter Settings].
+  /// 
+  /// [TLS Settings]:       TlsSettings
+  /// [Connect Procedure]:  Client::connect
+  /// [Connection Mode]:    ConnectionMode
+  /// [Parameter Settings]: ParameterSettings
+  #[cfg(feature = "tls")]
+  pub fn set_tls(
+    self: &Arc<Self>,
+    tls_settings: Option<TlsSettings>,
+  ) {
+    self.primitive_client.set_tls(tls_settings);
+  }
+
+  /// ### TRACE TO PCAP
+  /// 
+  /// Records every message received or transmitted by the
+  /// [Primitive Client], including control messages, to a new file at the
+  /// provided path in the pcap capture format, as described by the
+  /// [Pcap Export].
+  /// 
+  /// [Primitive Client]: primitive::Client::trace_to_pcap
+  /// [Pcap Export]:      crate::pcap
+  pub fn trace_to_pcap(
+    self: &Arc<Self>,
+    path: impl AsRef<std::path::Path>,
+    port: u16,
+  ) -> Result<(), Error> {
+    self.primitive_client.trace_to_pcap(path, port)
+  }
+
+  /// ### RECORD TO
+  /// 
+  /// Appends every message received by the [Primitive Client], including
+  /// control messages, to the file at the provided path, as described by
+  /// the [Frame Recording].
+  /// 
+  /// [Primitive Client]: primitive::Client::record_to
+  /// [Frame Recording]:  crate::record
+  pub fn record_to(
+    self: &Arc<Self>,
+    path: impl AsRef<std::path::Path>,
+  ) -> Result<(), Error> {
+    self.primitive_client.record_to(path)
+  }
+
+  /// ### TRANSITION
+  /// 
+  /// Sends a [Connection State Transition] to each hook provided by the
+  /// [Subscribe Transitions] function, forgetting those which were dropped.
+  /// 
+  /// [Subscribe Transitions]:       Client::subscribe_transitions
+  /// [Connection State Transition]: ConnectionStateTransition
+  fn transition(
+    &self,
+    transition: ConnectionStateTransition,
+  ) {
+    // Idle Timer: Begins on SELECTED
+    if transition == ConnectionStateTransition::NotSelectedToSelected {
+      self.data_activity();
+    }
+    self.transition_senders.lock().unwrap().deref_mut().retain(|sender| sender.send(transition).is_ok());
+  }
+
+  /// ### LOCK SELECTION
+  /// 
+  /// Locks the [Selection State] against any other procedure affecting it,
+  /// providing a [Selection Guard] which signals the [Receive Procedure]
+  /// when it is released.
+  /// 
+  /// [Selection State]:   SelectionState
+  /// [Selection Guard]:   SelectionGuard
+  /// [Receive Procedure]: Client::receive
+  fn lock_selection(&self) -> SelectionGuard<'_> {
+    SelectionGuard {
+      client: self,
+      guard: Some(self.selection_mutex.lock().unwrap_or_else(PoisonError::into_inner)),
+    }
+  }
+
+  /// ### NOTIFY SELECTION
+  /// 
+  /// Wakes the [Receive Procedure] if it is waiting for the [Selection State]
+  /// to be unlocked or to leave the [NOT SELECTED] state.
+  /// 
+  /// [Selection State]:   SelectionState
+  /// [NOT SELECTED]:      SelectionState::NotSelected
+  /// [Receive Procedure]: Client::receive
+  fn notify_selection(&self) {
+    let _gate = self.selection_gate.lock().unwrap_or_else(PoisonError::into_inner);
+    self.selection_changed.notify_all();
+  }
+
+  /// ### WAIT SELECTION
+  /// 
+  /// Locks the [Selection State] for the [Receive Procedure], or provides
+  /// [None] if another procedure keeps it locked outside of the
+  /// [NOT SELECTED] state.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Select Procedure] takes the lock before entering the
+  /// [SELECT INITIATED] state, so this waits until the lock is either taken
+  /// or that state has been entered, being woken by either.
+  /// 
+  /// [Selection State]:   SelectionState
+  /// [NOT SELECTED]:      SelectionState::NotSelected
+  /// [SELECT INITIATED]:  SelectionState::SelectInitiated
+  /// [Receive Procedure]: Client::receive
+  /// [Select Procedure]:  Client::select
+  fn wait_selection(&self) -> Option<MutexGuard<'_, ()>> {
+    let mut gate = self.selection_gate.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+      match self.selection_mutex.try_lock() {
+        Ok(guard) => break Some(guard),
+        Err(TryLockError::WouldBlock) if self.selection_state.load(Relaxed) == SelectionState::NotSelected => {
+          gate = self.selection_changed.wait(gate).unwrap_or_else(PoisonError::into_inner);
+        },
+        Err(_) => break None,
+      }
+    }
+  }
+
+  /// ### NOT SELECTED TIMEOUT
+  /// **Based on SEMI E37-1109§10.2**
+  /// 
+  /// Restarts the [Not Selected Timer], to be used whenever the
+  /// [NOT SELECTED] state is entered while in the [CONNECTED] state.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Client] has not left the [NOT SELECTED] state within the time
+  /// specified by [T7], it will consider it a communications failure and
+  /// initiate the [Disconnect Procedure].
+  /// 
+  /// Any subsequent change of the [Selection State] or [Connection State]
+  /// cancels the timeout.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [T7]:                   ParameterSettings::t7
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Not Selected Timer]:   Client::not_selected_timer
+  fn not_selected_timeout(&self) {
+    let mut since = self.t7_start.lock().unwrap();
+    *since.deref_mut() = Instant::now();
+    self.selection_count.fetch_add(1, Relaxed);
+    self.t7_changed.notify_all();
+  }
+
+  /// ### NOT SELECTED TIMER
+  /// 
+  /// Watches for the [Client] remaining in the [NOT SELECTED] state for
+  /// longer than [T7], using a single thread for the whole time the
+  /// [Client] remains in the [CONNECTED] state.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The timeout is measured from the last use of the
+  /// [Not Selected Timeout] function, and is cancelled by any later change
+  /// of the [Selection State]. The timer stops once the [NOT CONNECTED]
+  /// state is entered.
+  /// 
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [T7]:                   ParameterSettings::t7
+  /// [Client]:               Client
+  /// [Not Selected Timeout]: Client::not_selected_timeout
+  fn not_selected_timer(
+    self: &Arc<Self>,
+    count: u64,
+  ) {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      let t7: Duration = clone.parameter_settings.t7;
+      let mut since = clone.t7_start.lock().unwrap();
+      loop {
+        // IS: NOT CONNECTED
+        if clone.connection_count.load(Relaxed) != count {break}
+        let selection: u64 = clone.selection_count.load(Relaxed);
+        // IS: SELECTED
+        if clone.selection_state.load(Relaxed) != SelectionState::NotSelected {
+          since = clone.t7_changed.wait_timeout(since, t7).unwrap().0;
+          continue
+        }
+        // IS: NOT SELECTED, Within T7
+        let elapsed: Duration = since.elapsed();
+        if elapsed < t7 {
+          since = clone.t7_changed.wait_timeout(since, t7 - elapsed).unwrap().0;
+          continue
+        }
+        drop(since);
+        let expired: bool = {
+          let _guard = clone.lock_selection();
+          clone.connection_count.load(Relaxed) == count
+          && clone.selection_count.load(Relaxed) == selection
+          && clone.selection_state.load(Relaxed) == SelectionState::NotSelected
+        };
+        // TO: NOT CONNECTED
+        if expired {
+          log::debug!("generic::Client::not_selected_timer: T7 EXPIRED");
+          let _ = clone.disconnect();
+          break
+        }
+        since = clone.t7_start.lock().unwrap();
+      }
+    });
+  }
+
+  /// ### LINKTEST HEARTBEAT
+  /// **Based on SEMI E37-1109§7.8**
+  /// 
+  /// Periodically initiates the [Linktest Procedure] while the [Client]
+  /// remains in the [CONNECTED] state, to be used when a
+  /// [Linktest Interval] is specified.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If any [Linktest Procedure] fails, including when the [Linktest.rsp] is
+  /// not received within the time specified by [T6], the [Client] will
+  /// consider it a communications failure and initiate the
+  /// [Disconnect Procedure].
+  /// 
+  /// The heartbeat stops once the [NOT CONNECTED] state is entered.
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [T6]:                   ParameterSettings::t6
+  /// [Linktest Interval]:    ParameterSettings::linktest_interval
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Linktest Procedure]:   Client::linktest
+  /// [Linktest.rsp]:         MessageContents::LinktestResponse
+  fn linktest_heartbeat(
+    self: &Arc<Self>,
+    count: u64,
+    interval: Duration,
+  ) {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      loop {
+        thread::sleep(interval);
+        // IS: NOT CONNECTED
+        if clone.connection_count.load(Relaxed) != count {break}
+        // IS: DISCONNECTING
+        if clone.draining.load(Relaxed) {continue}
+        // TX: Linktest.req
+        match clone.run_linktest(clone.next_system()) {
+          // RX: Linktest.rsp
+          Ok(()) => {},
+          // RX: Failure
+          _ => {
+            // TO: NOT CONNECTED
+            if clone.connection_count.load(Relaxed) == count {
+              log::debug!("generic::Client::linktest_heartbeat: LINKTEST FAILED");
+              let _ = clone.disconnect();
+            }
+            break
+          },
+        }
+      }
+    });
+  }
+
+  /// ### IDLE TIMER
+  /// 
+  /// Watches for the [Client] remaining in the [SELECTED] state without
+  /// transmitting or receiving a [Data Message], to be used when an
+  /// [Idle Timeout] is specified.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If no [Data Message] has been transmitted or received within the time
+  /// specified by the [Idle Timeout] since the later of the last one and
+  /// entering the [SELECTED] state, the [Client] will initiate the
+  /// [Disconnect Procedure].
+  /// 
+  /// The timer stops once the [NOT CONNECTED] state is entered.
+  /// 
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  /// [SELECTED]:             SelectionState::Selected
+  /// [Idle Timeout]:         ParameterSettings::idle_timeout
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Data Message]:         MessageContents::DataMessage
+  fn idle_timer(
+    self: &Arc<Self>,
+    count: u64,
+    idle_timeout: Duration,
+  ) {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      loop {
+        let idle: Duration = clone.data_activity.lock().unwrap().elapsed();
+        thread::sleep(idle_timeout.saturating_sub(idle).max(Duration::from_millis(1)));
+        // IS: NOT CONNECTED
+        if clone.connection_count.load(Relaxed) != count {break}
+        // IS: NOT SELECTED
+        if clone.selection_state.load(Relaxed) != SelectionState::Selected {
+          thread::sleep(idle_timeout);
+          continue
+        }
+        // TO: NOT CONNECTED
+        if clone.data_activity.lock().unwrap().elapsed() >= idle_timeout {
+          log::debug!("generic::Client::idle_timer: IDLE TIMEOUT EXPIRED");
+          let _ = clone.disconnect();
+          break
+        }
+      }
+    });
+  }
+
+  /// ### DATA ACTIVITY
+  /// 
+  /// Restarts the [Idle Timer], to be used whenever a [Data Message] is
+  /// transmitted or received, or the [SELECTED] state is entered.
+  /// 
+  /// [Idle Timer]:   Client::idle_timer
+  /// [SELECTED]:     SelectionState::Selected
+  /// [Data Message]: MessageContents::DataMessage
+  fn data_activity(&self) {
+    *self.data_activity.lock().unwrap().deref_mut() = Instant::now();
+  }
+
+  /// ### NEXT SYSTEM
+  /// **Based on SEMI E37-1109§8.2.6.7**
+  /// 
+  /// Provides [System Bytes] for use in a new transaction initiated by the
+  /// Local Entity.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Successive values are allocated sequentially from a time-based seed, so
+  /// that they do not repeat until all 2^32 values have been provided, and
+  /// are unlikely to coincide with those chosen by a Remote Entity which
+  /// counts upward from zero.
+  /// 
+  /// [System Bytes]: MessageID::system
+  pub fn next_system(
+    self: &Arc<Self>,
+  ) -> u32 {
+    let mut system_guard = self.system.lock().unwrap();
+    let system_counter = system_guard.deref_mut();
+    let system = *system_counter;
+    *system_counter = system_counter.wrapping_add(1);
+    system
+  }
+
+  /// ### SYSTEM SEED
+  /// 
+  /// Provides the time-based initial value used by the [Next System]
+  /// function.
+  /// 
+  /// [Next System]: Client::next_system
+  fn system_seed() -> u32 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+      Ok(duration) => duration.subsec_nanos() ^ (duration.as_secs() as u32),
+      Err(_) => 0,
+    }
+  }
+}
+
+/// ## MESSAGE EXCHANGE PROCEDURES
+/// **Based on SEMI E37-1109§7**
+/// 
+/// Encapsulates the parts of the [Client]'s functionality dealing with
+/// exchanging [Message]s.
+/// 
+/// - [Data Procedure] - [Data Message]s
+/// - [Select Procedure] - [Select.req] and [Select.rsp]
+/// - [Deselect Procedure] - [Deselect.req] and [Deselect.rsp]
+/// - [Linktest Procedure] - [Linktest.req] and [Linktest.rsp]
+/// - [Separate Procedure] - [Separate.req]
+/// - [Reject Procedure] - [Reject.req]
+/// 
+/// [Message]:            Message
+/// [Client]:             Client
+/// [Select Procedure]:   Client::select
+/// [Data Procedure]:     Client::data
+/// [Deselect Procedure]: Client::deselect
+/// [Linktest Procedure]: Client::linktest
+/// [Separate Procedure]: Client::separate
+/// [Reject Procedure]:   Client::reject
+/// [Data Message]:       MessageContents::DataMessage
+/// [Select.req]:         MessageContents::SelectRequest
+/// [Select.rsp]:         MessageContents::SelectResponse
+/// [Deselect.req]:       MessageContents::DeselectRequest
+/// [Deselect.rsp]:       MessageContents::DeselectResponse
+/// [Linktest.req]:       MessageContents::LinktestRequest
+/// [Linktest.rsp]:       MessageContents::LinktestResponse
+/// [Reject.req]:         MessageContents::RejectRequest
+/// [Separate.req]:       MessageContents::SeparateRequest
+impl Client {
+  /// ### RECEIVE PROCEDURE
+  /// 
+  /// An [Client] in the [CONNECTED] state will automatically receive
+  /// [Message]s and respond based on their [Message Contents] and the current
+  /// [Selection State].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Data Message]
+  /// 
+  /// - [NOT SELECTED] - The [Client] will respond by transmitting a
+  ///   [Reject.req] message, rejecting the [HSMS Data Procedure] and
+  ///   completing the [HSMS Reject Procedure].
+  /// - [SELECTED], other [Session ID] - The [Client] will respond by
+  ///   transmitting a [Reject.req] message indicating that the entity is not
+  ///   selected, rejecting the [Data Procedure] and completing the
+  ///   [Reject Procedure].
+  /// - [SELECTED], Primary [Data Message] - The [Client] will send the
+  ///   [Data Message] to the hook provided by the [Connect Procedure] as an
+  ///   [Inbound], which may be used to reply to it.
+  /// - [SELECTED], Response [Data Message] - The [Client] will respond by
+  ///   correllating the message to a previously sent Primary [Data Message],
+  ///   finishing a previously initiated [Data Procedure] if successful,
+  ///   or if unsuccessful by transmitting a [Reject.req] message, rejecting
+  ///   the [Data Procedure] and completing the [Reject Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Select.req]:
+  /// 
+  /// - [NOT SELECTED] - The [Client] will respond with a [Select.rsp]
+  ///   accepting and completing the [Select Procedure].
+  /// - [SELECT INITIATED], matching [Session ID] - The [Client] will respond
+  ///   with a [Select.rsp] accepting the Remote Entity's
+  ///   [Select Procedure], but will not complete its own until its own
+  ///   [Select.rsp] is received.
+  /// - [SELECT INITIATED], other [Session ID] - The [Client] will respond
+  ///   with a [Select.rsp] message rejecting the [Select Procedure].
+  /// - [SELECTED] - The [Client] will respond with a [Select.rsp] message
+  ///   rejecting the [Select Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Select.rsp]:
+  /// 
+  /// - [NOT SELECTED] - The [Client] will complete the [Select Procedure].
+  /// - [SELECTED] - The [Client] will respond with a [Reject.req] message,
+  ///   completing the [Reject Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Deselect.req]:
+  /// 
+  /// - [NOT SELECTED] - The [Client] will respond with a [Deselect.rsp]
+  ///   message rejecting the [Deselect Procedure].
+  /// - [SELECTED], matching [Session ID] - The [Client] will respond with a
+  ///   [Deselect.rsp] accepting and completing the [Deselect Procedure].
+  ///   If the [Client] has itself initiated the [Deselect Procedure], that
+  ///   procedure is instead completed by the Remote Entity's [Deselect.rsp].
+  /// - [SELECTED], other [Session ID] - The [Client] will respond with a
+  ///   [Deselect.rsp] message rejecting the [Deselect Procedure].
+  /// - Any other procedure affecting the [Selection State] in progress - The
+  ///   [Client] will respond with a [Deselect.rsp] message indicating that
+  ///   it is busy.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Deselect.rsp]:
+  /// 
+  /// - The [Client] will respond by correllating the message to a previously
+  ///   sent [Deselect.req] message, finishing a previously initiated
+  ///   [Deselect Procedure] if successful, or if unsuccessful by transmitting
+  ///   a [Reject.req] message, completing the [Reject Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Linktest.req]:
+  /// 
+  /// - The [Client] will respond with a [Linktest.rsp], completing the
+  ///   [Linktest Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Linktest.rsp]:
+  /// 
+  /// - The [Client] will respond by correllating the message to a previously
+  ///   sent [Linktest.req] message, finishing a previously initiated
+  ///   [Linktest Procedure] if successful, or if unsuccessful by transmitting
+  ///   a [Reject.req] message, completing the [Reject Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Reject.req]:
+  /// 
+  /// - The [Client] will correllate the message to a previously sent message
+  ///   by its [Message ID], failing the procedure awaiting its response,
+  ///   with an error of kind [PermissionDenied], or with the [Select Error]
+  ///   for a rejected [Select.req] in the case of the [Select Procedure].
+  /// - If no procedure is awaiting a response with that [Message ID], the
+  ///   [Client] will log the message and otherwise ignore it.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### [Separate.req]:
+  /// 
+  /// - [NOT SELECTED] - The [Client] will not do anything.
+  /// - [SELECTED] - The [Client] will complete the [Separate Procedure].
+  /// - [DESELECT INITIATED] - The [Client] will complete the
+  ///   [Separate Procedure] without waiting for its own [Deselect Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### Unknown [Primitive Message]:
+  /// 
+  /// - The [Client] will respond by transmitting a [Reject.req] message,
+  ///   completing the [Reject Procedure]. 
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// #### Multiple Sessions:
+  /// 
+  /// With the `multi_session` feature enabled, several [Session ID]s may be
+  /// selected at once:
+  /// - A [Select.req] for another [Session ID] received in the [SELECTED]
+  ///   state is answered as in the [NOT SELECTED] state, selecting it as
+  ///   well without any change of [Selection State].
+  /// - A [Deselect.req] or [Separate.req] for one of several selected
+  ///   [Session ID]s ends only that session, without any change of
+  ///   [Selection State].
+  /// - A Primary [Data Message] for any selected [Session ID] is accepted,
+  ///   and is sent to the hook provided for it by the Subscribe Session
+  ///   function, if there is one.
+  /// 
+  /// [Primitive Message]:  primitive::Message
+  /// [Connection State]:   primitive::ConnectionState
+  /// [NOT CONNECTED]:      primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:          primitive::ConnectionState::Connected
+  /// [Message]:            Message
+  /// [Message Contents]:   MessageContents
+  /// [Data Message]:       MessageContents::DataMessage
+  /// [Select.req]:         MessageContents::SelectRequest
+  /// [Select.rsp]:         MessageContents::SelectResponse
+  /// [Deselect.req]:       MessageContents::DeselectRequest
+  /// [Deselect.rsp]:       MessageContents::DeselectResponse
+  /// [Linktest.req]:       MessageContents::LinktestRequest
+  /// [Linktest.rsp]:       MessageContents::LinktestResponse
+  /// [Reject.req]:         MessageContents::RejectRequest
+  /// [Inbound]:            Inbound
+  /// [Separate.req]:       MessageContents::SeparateRequest
+  /// [Session ID]:         MessageID::session
+  /// [Client]:             Client
+  /// [Connect Procedure]:  Client::connect
+  /// [Select Procedure]:   Client::select
+  /// [Data Procedure]:     Client::data
+  /// [Deselect Procedure]: Client::deselect
+  /// [Linktest Procedure]: Client::linktest
+  /// [Separate Procedure]: Client::separate
+  /// [Reject Procedure]:   Client::reject
+  /// [Selection State]:    SelectionState
+  /// [NOT SELECTED]:       SelectionState::NotSelected
+  /// [SELECTED]:           SelectionState::Selected
+  /// [SELECT INITIATED]:   SelectionState::SelectInitiated
+  /// [DESELECT INITIATED]: SelectionState::DeselectInitiated
+  /// [Message ID]:         MessageID
+  /// [PermissionDenied]:   ErrorKind::PermissionDenied
+  /// [Select Error]:       SelectError::RejectRequest
+  fn receive(
+    self: &Arc<Self>,
+    count: u64,
+    rx_receiver: Receiver<primitive::Message>,
+    rx_sender: primitive::HookSender<Inbound>,
+  ) {
+    for primitive_message in rx_receiver {
+      let primitive_header = primitive_message.header;
+      let malformed_message = if self.parameter_settings.auto_reject {None} else {Some(primitive_message.clone())};
+      let rx_result = Message::decode(primitive_message, &self.parameter_settings);
+      match rx_result {
+        Ok(rx_message) => {
+          // RX: Data Message
+          if let MessageContents::DataMessage(_) = rx_message.contents {
+            self.data_activity();
+          }
+          // SELECTION: Lock for Select.req, Deselect.req
+          let optional_guard: Option<MutexGuard<'_, ()>> = match rx_message.contents {
+            MessageContents::SelectRequest   => self.wait_selection(),
+            MessageContents::DeselectRequest => self.selection_mutex.try_lock().ok(),
+            _ => None,
+          };
+          let situation: Situation = self.situation(rx_message.id.session, optional_guard.is_none());
+          let reaction: Reaction = react(rx_message, &situation, self.select_policy.read().unwrap().deref());
+          match reaction {
+            // INBOX: New Transaction
+            Reaction::Deliver(id, message) => {
+              let inbound: Inbound = Inbound {
+                id,
+                message,
+                client: self.clone(),
+              };
+              // INBOX: Session Hook
+              #[cfg(feature = "multi_session")]
+              let Some(inbound) = self.route_session(inbound) else {continue};
+              if self.deliver_inbound(&rx_sender, inbound).is_err() {break}
+            },
+            // OUTBOX: Find Transaction by Session ID and System Bytes
+            Reaction::Complete(reply) => {
+              let optional_sender = self.outbox.lock().unwrap().deref_mut().remove(&reply.id);
+              self.outbox_changed.notify_all();
+              match optional_sender {
+                // OUTBOX: Complete Transaction
+                Some(sender) => {
+                  let _ = sender.send(Some(reply));
+                },
+                // OUTBOX: Transaction Not Found
+                None => match not_open(&reply) {
+                  // TX: Reject.req
+                  Some(reject) => if self.transmit_primitive(reject).is_err() {break},
+                  None => log::debug!("generic::Client::receive: REJECT.REQ IGNORED {:?} {}", reply.id, reply.contents),
+                },
+              }
+            },
+            Reaction::Respond(response, change) => {
+              // TO: NOT SELECTED, Release Session
+              let change: Option<SelectionChange> = match change {
+                Some(change) if !change.is_entering() => {
+                  self.change_selection(change);
+                  None
+                },
+                change => change,
+              };
+              // TX: Response
+              if let Some(response) = response {
+                if self.transmit_primitive(response).is_err() {break}
+              }
+              // TO: SELECTED, Add Session
+              if let Some(change) = change {
+                self.change_selection(change);
+              }
+            },
+          }
+        },
+        Err(reject_reason) => {
+          // AUTO REJECT: Disabled
+          if let Some(malformed_message) = malformed_message {
+            self.malformed_senders.lock().unwrap().deref_mut().retain(|sender| sender.send((malformed_message.clone(), reject_reason)).is_ok());
+            continue
+          }
+          // TX: Reject.req
+          if self.transmit_primitive(malformed(primitive_header, reject_reason)).is_err() {break}
+        },
+      }
+    }
+    // OUTBOX: CLEAR
+    for (_, sender) in self.outbox.lock().unwrap().deref_mut().drain() {
+      let _ = sender.send(None);
+    }
+    self.outbox_changed.notify_all();
+    // TO: NOT CONNECTED
+    if self.connection_count.load(Relaxed) == count {
+      let _ = self.disconnect();
+    }
+  }
+
+  /// ### SITUATION
+  /// 
+  /// Provides the [Situation] in which a [Message] with the given
+  /// [Session ID] is reacted to by the [Receive Procedure].
+  /// 
+  /// [Situation]:         Situation
+  /// [Message]:           Message
+  /// [Session ID]:        MessageID::session
+  /// [Receive Procedure]: Client::receive
+  fn situation(
+    &self,
+    session: SessionID,
+    busy: bool,
+  ) -> Situation {
+    Situation {
+      selection_state:  self.selection_state.load(Relaxed),
+      selected_session: self.is_selected_session(session),
+      same_session:     self.selection_session.load(Relaxed) == session,
+      busy,
+      multi_session:    cfg!(feature = "multi_session"),
+      #[cfg(feature = "multi_session")]
+      releasable:       self.is_releasable_session(session),
+      #[cfg(not(feature = "multi_session"))]
+      releasable:       false,
+    }
+  }
+
+  /// ### CHANGE SELECTION
+  /// 
+  /// Makes a [Selection Change] decided by the [Receive Procedure].
+  /// 
+  /// [Selection Change]:  SelectionChange
+  /// [Receive Procedure]: Client::receive
+  fn change_selection(
+    self: &Arc<Self>,
+    change: SelectionChange,
+  ) {
+    match change {
+      // TO: SELECTED
+      SelectionChange::Select(session) => {
+        self.selection_session.store(session, Relaxed);
+        #[cfg(feature = "multi_session")]
+        self.other_sessions.lock().unwrap().clear();
+        self.selection_state.store(SelectionState::Selected, Relaxed);
+        self.selection_count.fetch_add(1, Relaxed);
+        self.transition(ConnectionStateTransition::NotSelectedToSelected);
+        log::debug!("generic::Client::receive: SELECTED");
+      },
+      // SESSIONS: Add
+      #[cfg(feature = "multi_session")]
+      SelectionChange::AddSession(session) => {
+        self.other_sessions.lock().unwrap().insert(session);
+        log::debug!("generic::Client::receive: SESSION {} SELECTED", session);
+      },
+      // SESSIONS: Release
+      #[cfg(feature = "multi_session")]
+      SelectionChange::ReleaseSession(session) => {
+        self.release_session(session);
+        log::debug!("generic::Client::receive: SESSION {} RELEASED", session);
+      },
+      #[cfg(not(feature = "multi_session"))]
+      SelectionChange::AddSession(_) | SelectionChange::ReleaseSession(_) => {},
+      // TO: NOT SELECTED
+      SelectionChange::Deselect => {
+        self.selection_state.store(SelectionState::NotSelected, Relaxed);
+        self.not_selected_timeout();
+        self.transition(ConnectionStateTransition::SelectedToNotSelected);
+        log::debug!("generic::Client::receive: NOT SELECTED");
+      },
+      // TO: NOT SELECTED, From Any
+      SelectionChange::Separate => {
+        // The Select and Deselect Procedures hold the lock while awaiting
+        // a response which only this thread can deliver.
+        let guard: Option<MutexGuard<'_, ()>> = match self.selection_mutex.try_lock() {
+          Ok(guard) => Some(guard),
+          Err(_) => match self.selection_state.load(Relaxed) {
+            SelectionState::Selected => Some(self.selection_mutex.lock().unwrap()),
+            SelectionState::NotSelected | SelectionState::SelectInitiated | SelectionState::DeselectInitiated => None,
+          },
+        };
+        let separated: bool = match guard {
+          // IS: Any
+          Some(_) => self.selection_state.swap(SelectionState::NotSelected, Relaxed) != SelectionState::NotSelected,
+          // IS: DESELECT INITIATED
+          None => self.selection_state.compare_exchange(SelectionState::DeselectInitiated, SelectionState::NotSelected, Relaxed, Relaxed).is_ok(),
+        };
+        if separated {
+          self.not_selected_timeout();
+          self.transition(ConnectionStateTransition::SelectedToNotSelected);
+          log::debug!("generic::Client::receive: NOT SELECTED");
+        }
+      },
+    }
+  }
+
+  /// ### TRANSMIT PROCEDURE
+  /// **Based on SEMI E37-1109§7.2**
+  /// 
+  /// Serializes a [Message] and transmits it over the TCP/IP connection.
+  /// If a reply is expected, this function will then wait up to the time
+  /// specified for the requisite response [Message] to be recieved.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// [Message]:          Message
+  /// [Connection State]: primitive::ConnectionState
+  /// [NOT CONNECTED]:    primitive::ConnectionState::NotConnected
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  fn transmit(
+    self: &Arc<Self>,
+    message: Message,
+    reply_expected: bool,
+    delay: Duration,
+  ) -> Result<Option<Message>, Error> {
+    let message_id: MessageID = message.id;
+    match self.transmit_open(message, reply_expected)? {
+      // REPLY NOT EXPECTED: Finish
+      None => Ok(None),
+      // REPLY EXPECTED
+      Some(receiver) => Ok(self.transmit_await(message_id, receiver, delay)),
+    }
+  }
+
+  /// ### TRANSMIT OPEN PROCEDURE
+  /// 
+  /// Performs the first half of the [Transmit Procedure], transmitting the
+  /// [Message] and, if a reply is expected, opening its transaction and
+  /// providing the receiver on which the response [Message] will arrive.
+  /// 
+  /// [Message]:            Message
+  /// [Transmit Procedure]: Client::transmit
+  fn transmit_open(
+    self: &Arc<Self>,
+    message: Message,
+    reply_expected: bool,
+  ) -> Result<Option<ReplyReceiver>, Error> {
+    let message_id = message.id;
+    // OUTBOX: LOCK
+    let outbox_lock = if reply_expected {Some(self.deref().outbox.lock().unwrap())} else {None};
+    // OUTBOX: Disconnecting
+    if outbox_lock.is_some() && self.draining.load(Relaxed) {
+      return Err(Error::from(ErrorKind::NotConnected))
+    }
+    // OUTBOX: Transaction Already Open
+    if let Some(outbox) = &outbox_lock {
+      if outbox.deref().contains_key(&message_id) {
+        return Err(Error::from(ErrorKind::AlreadyExists))
+      }
+    }
+    // TX
+    match self.transmit_primitive(message) {
+      // TX: Success
+      Ok(()) => {
+        match outbox_lock {
+          // REPLY NOT EXPECTED: Finish
+          None => Ok(None),
+          // REPLY EXPECTED
+          Some(mut outbox) => {
+            // OUTBOX: Create Transaction
+            let (sender, receiver) = oneshot::channel::<Option<Message>>();
+            outbox.deref_mut().insert(message_id, sender);
+            Ok(Some(receiver))
+          }
+        }
+      },
+      // TX: Not Serializable
+      Err(error) if error.kind() == ErrorKind::InvalidInput => Err(error),
+      // TX: Failure
+      Err(error) => {
+        // TO: NOT CONNECTED, NOT SELECTED
+        // The caller may be a Select, Deselect, or Separate Procedure holding
+        // the selection lock, so the NOT SELECTED state is entered without it.
+        let _ = self.break_connection();
+        self.leave_selection();
+        Err(error)
+      },
+    }
+  }
+
+  /// ### TRANSMIT AWAIT PROCEDURE
+  /// 
+  /// Performs the second half of the [Transmit Procedure], waiting up to the
+  /// time specified for the response [Message] to a transaction opened by
+  /// the [Transmit Open Procedure], and closing the transaction if it does
+  /// not arrive.
+  /// 
+  /// [Message]:                 Message
+  /// [Transmit Procedure]:      Client::transmit
+  /// [Transmit Open Procedure]: Client::transmit_open
+  fn transmit_await(
+    self: &Arc<Self>,
+    message_id: MessageID,
+    receiver: ReplyReceiver,
+    delay: Duration,
+  ) -> Option<Message> {
+    // RX
+    let rx_result = receiver.recv_timeout(delay);
+    // OUTBOX: Remove Transaction
+    if rx_result.is_err() {
+      self.outbox.lock().unwrap().deref_mut().remove(&message_id);
+      self.outbox_changed.notify_all();
+    }
+    // RX: Success, Failure
+    rx_result.unwrap_or(None)
+  }
+
+  /// ### TRANSMIT PRIMITIVE PROCEDURE
+  /// 
+  /// Serializes a [Message] and provides it to the [Primitive Client] to
+  /// transmit over the TCP/IP connection, without waiting for any reply.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Fails with [InvalidInput] if the [Message] cannot be serialized, or if
+  /// its Message Length would exceed the [Maximum Message Length], in which
+  /// case the error wraps a [Message Too Long]. The Message Length is found
+  /// from the [Encoded Length] before anything is serialized.
+  /// 
+  /// [Message]:                Message
+  /// [Primitive Client]:       primitive::Client
+  /// [InvalidInput]:           ErrorKind::InvalidInput
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  /// [Message Too Long]:       MessageTooLong
+  /// [Encoded Length]:         semi_e5::Message::encoded_len
+  fn transmit_primitive(
+    self: &Arc<Self>,
+    message: Message,
+  ) -> Result<(), Error> {
+    let is_data_message: bool = !message.is_control_message();
+    // Message Length: Header + Text, Checked Before Serializing
+    let length: usize = match &message.contents {
+      MessageContents::DataMessage(data) => data.encoded_len(),
+      _ => 10,
+    };
+    if length > self.parameter_settings.max_message_length as usize {
+      return Err(Error::new(ErrorKind::InvalidInput, MessageTooLong {
+        length,
+        max_message_length: self.parameter_settings.max_message_length,
+      }))
+    }
+    let primitive_message = primitive::Message::try_from(message).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+    self.primitive_client.transmit(primitive_message)?;
+    if is_data_message {
+      self.data_activity();
+    }
+    Ok(())
+  }
+
+  /// ### DATA PROCEDURE
+  /// **Based on SEMI E37-1109§7.5-7.6**
+  /// 
+  /// Asks the [Client] to initiate the [Data Procedure] by transmitting a
+  /// [Data Message] and waiting for the corresponding response to be received
+  /// if it is necessary to do so.
+  /// 
+  /// The [Data Message] may be provided as a [semi_e5::Message] or as any
+  /// of the typed messages in [semi_e5::messages], such as S1F1.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Data Message] is checked with [semi_e5::Message::validate] before
+  /// anything is transmitted, failing with an error of kind [InvalidInput]
+  /// if the reply bit is set on a reply. It also fails with an error of kind
+  /// [InvalidInput], wrapping a [Message Too Long], if its serialized length
+  /// exceeds the [Maximum Message Length]. In either case the connection is
+  /// left as it was.
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// 
+  /// If the [Session ID] provided is the [Selected Session], it is replaced
+  /// by the [Session ID] used to complete the [Select Procedure].
+  /// 
+  /// When a Response [Data Message] is necessary, the [Client] will wait
+  /// to receive it for the amount of time specified by [T3] before it will
+  /// consider it a communications failure and initiate the
+  /// [Disconnect Procedure]. The [Data With Timeout Procedure] waits for a
+  /// different amount of time instead.
+  /// 
+  /// Unlike the other procedures, this one runs on the calling thread and
+  /// blocks it until complete, so that routine transactions do not each
+  /// start a thread. To keep several [Data Message]s outstanding at once,
+  /// use the [Windowed Data Procedure] or call this from several threads.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the [CONNECTED]
+  /// state will automatically respond to having received a [Data Message]
+  /// based on its contents and the current [Selection State]:
+  /// - [NOT SELECTED] - The [Client] will respond by transmitting a
+  ///   [Reject.req] message, rejecting the [HSMS Data Procedure] and
+  ///   completing the [HSMS Reject Procedure].
+  /// - [SELECTED], other [Session ID] - The [Client] will respond by
+  ///   transmitting a [Reject.req] message indicating that the entity is not
+  ///   selected, rejecting the [Data Procedure] and completing the
+  ///   [Reject Procedure].
+  /// - [SELECTED], Primary [Data Message] - The [Client] will send the
+  ///   [Data Message] to the hook provided by the [Connect Procedure] as an
+  ///   [Inbound], which may be used to reply to it.
+  /// - [SELECTED], Response [Data Message] - The [Client] will respond by
+  ///   correllating the message to a previously sent Primary [Data Message]
+  ///   by its [Message ID], finishing a previously initiated
+  ///   [Data Procedure] if successful, or if unsuccessful by transmitting a
+  ///   [Reject.req] message, rejecting the [Data Procedure] and completing
+  ///   the [Reject Procedure].
+  /// 
+  /// [Connection State]:            primitive::ConnectionState
+  /// [CONNECTED]:                   primitive::ConnectionState::Connected
+  /// [Selection State]:             SelectionState
+  /// [NOT SELECTED]:                SelectionState::NotSelected
+  /// [SELECTED]:                    SelectionState::Selected
+  /// [T3]:                          ParameterSettings::t3
+  /// [Client]:                      Client
+  /// [Connect Procedure]:           Client::connect
+  /// [Disconnect Procedure]:        Client::disconnect
+  /// [Data Procedure]:              Client::data
+  /// [Reject Procedure]:            Client::reject
+  /// [Select Procedure]:            Client::select
+  /// [Data Message]:                MessageContents::DataMessage
+  /// [Reject.req]:                  MessageContents::RejectRequest
+  /// [Inbound]:                     Inbound
+  /// [Message ID]:                  MessageID
+  /// [Session ID]:                  MessageID::session
+  /// [Selected Session]:            MessageID::SELECTED_SESSION
+  /// [InvalidInput]:                ErrorKind::InvalidInput
+  /// [Message Too Long]:            MessageTooLong
+  /// [Maximum Message Length]:      ParameterSettings::max_message_length
+  /// [Data With Timeout Procedure]: Client::data_with_timeout
+  /// [Windowed Data Procedure]:     Client::data_windowed
+  pub fn data(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: impl Into<semi_e5::Message>,
+  ) -> Result<Option<semi_e5::Message>, Error> {
+    self.data_with_timeout(id, message, self.parameter_settings.t3)
+  }
+
+  /// ### DATA WITH TIMEOUT PROCEDURE
+  /// **Based on SEMI E37-1109§7.5-7.6**
+  /// 
+  /// Initiates the [Data Procedure] exactly as described there, except that
+  /// the [Client] waits for a necessary Response [Data Message] for the
+  /// amount of time provided, rather than that specified by [T3].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// This is intended for the few transactions which legitimately take the
+  /// Remote Entity longer to answer than routine ones, and should be used
+  /// sparingly, as a lost reply is only noticed once the time provided has
+  /// elapsed. If it elapses, the [Disconnect Procedure] is still initiated,
+  /// as with [T3].
+  /// 
+  /// [T3]:                   ParameterSettings::t3
+  /// [Client]:               Client
+  /// [Data Procedure]:       Client::data
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Data Message]:         MessageContents::DataMessage
+  pub fn data_with_timeout(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: impl Into<semi_e5::Message>,
+    timeout: Duration,
+  ) -> Result<Option<semi_e5::Message>, Error> {
+    let (id, receiver) = self.data_open(id, message.into())?;
+    self.data_await(id, receiver, timeout)
+  }
+
+  /// ### DATA OPEN PROCEDURE
+  /// 
+  /// Performs the first half of the [Data Procedure], checking and
+  /// transmitting the [Data Message] and, if a reply is expected, providing
+  /// the [Message ID] used and the receiver on which the response will
+  /// arrive.
+  /// 
+  /// [Data Procedure]: Client::data
+  /// [Data Message]:   MessageContents::DataMessage
+  /// [Message ID]:     MessageID
+  fn data_open(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: semi_e5::Message,
+  ) -> Result<(MessageID, Option<ReplyReceiver>), Error> {
+    let reply_expected: bool = message.expects_reply();
+    // Reply Bit
+    if let Err(error) = message.validate() {
+      return Err(Error::new(ErrorKind::InvalidInput, error))
+    }
+    match self.selection_state.load(Relaxed) {
+      // IS: NOT SELECTED, SELECT INITIATED
+      SelectionState::NotSelected | SelectionState::SelectInitiated => Err(Error::from(ErrorKind::AlreadyExists)),
+      // IS: SELECTED, DESELECT INITIATED
+      SelectionState::Selected | SelectionState::DeselectInitiated => {
+        // Default Session ID
+        let id: MessageID = if id.session == MessageID::SELECTED_SESSION {
+          MessageID {
+            session: self.selection_session.load(Relaxed),
+            system: id.system,
+          }
+        } else {
+          id
+        };
+        // TX: Data Message
+        let receiver = self.transmit_open(
+          Message {
+            id,
+            contents: MessageContents::DataMessage(message),
+          },
+          reply_expected,
+        )?;
+        Ok((id, receiver))
+      },
+    }
+  }
+
+  /// ### DATA AWAIT PROCEDURE
+  /// 
+  /// Performs the second half of the [Data Procedure], waiting up to the
+  /// time provided for the Response [Data Message] to a transaction opened
+  /// by the [Data Open Procedure], if a reply is expected.
+  /// 
+  /// [Data Procedure]:      Client::data
+  /// [Data Open Procedure]: Client::data_open
+  /// [Data Message]:        MessageContents::DataMessage
+  fn data_await(
+    self: &Arc<Self>,
+    id: MessageID,
+    receiver: Option<ReplyReceiver>,
+    timeout: Duration,
+  ) -> Result<Option<semi_e5::Message>, Error> {
+    // REPLY NOT EXPECTED
+    let Some(receiver) = receiver else {return Ok(None)};
+    match self.transmit_await(id, receiver, timeout) {
+      // RX: Response
+      Some(rx_message) => {
+        match rx_message.contents {
+          // RX: Data
+          MessageContents::DataMessage(data_message) => Ok(Some(data_message)),
+          // RX: Reject.req
+          MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+          // RX: Unknown
+          _ => Err(Error::from(ErrorKind::InvalidData)),
+        }
+      },
+      // RX: No Response
+      None => {
+        // TO: NOT CONNECTED
+        self.disconnect()?;
+        Err(Error::from(ErrorKind::ConnectionAborted))
+        // TODO: HSMS-SS does NOT disconnect when the Data Procedure fails, may require this behavior to be optional.
+      },
+    }
+  }
+
+  /// ### TRANSACT
+  /// 
+  /// Performs the [Data Procedure] with a Primary [Data Message] requesting a
+  /// reply, waits for it to complete, and provides the Response
+  /// [Data Message].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// This is a convenience over the [Data Procedure] for the common case of
+  /// sending a primary message and receiving its reply. Failures are reported
+  /// as a [Transact Error] rather than an [Error] kind.
+  /// 
+  /// [Data Procedure]: Client::data
+  /// [Data Message]:   MessageContents::DataMessage
+  /// [Transact Error]: TransactError
+  /// [Error]:          Error
+  pub fn transact(
+    self: &Arc<Self>,
+    id: MessageID,
+    message: impl Into<semi_e5::Message>,
+  ) -> Result<semi_e5::Message, TransactError> {
+    let message: semi_e5::Message = message.into();
+    // Reply Bit
+    if let Err(error) = message.validate() {
+      return Err(TransactError::Io(Error::new(ErrorKind::InvalidInput, error)))
+    }
+    // IS: NOT SELECTED, SELECT INITIATED
+    if matches!(self.selection_state.load(Relaxed), SelectionState::NotSelected | SelectionState::SelectInitiated) {
+      return Err(TransactError::NotSelected)
+    }
+    // NO REPLY REQUESTED
+    if !message.expects_reply() {
+      return Err(TransactError::NoReply)
+    }
+    // DATA PROCEDURE: Open
+    // A failure to transmit also aborts the connection, but is not a timeout.
+    let (id, receiver) = self.data_open(id, message).map_err(TransactError::Io)?;
+    // DATA PROCEDURE: Await
+    match self.data_await(id, receiver, self.parameter_settings.t3) {
+      Ok(Some(reply)) => Ok(reply),
+      Ok(None) => Err(TransactError::NoReply),
+      Err(error) => match error.kind() {
+        ErrorKind::PermissionDenied  => Err(TransactError::Rejected),
+        ErrorKind::ConnectionAborted => Err(TransactError::Timeout),
+        _                            => Err(TransactError::Io(error)),
+      },
+    }
+  }
+
+  /// ### SERVE PROCEDURE
+  /// 
+  /// Answers each Primary [Data Message] received from the hook provided by
+  /// the [Connect Procedure] using a [Message Router], until the hook is
+  /// closed by the [Disconnect Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// A Response [Data Message] provided by the [Message Router] is
+  /// transmitted with the [Message ID] of the Primary [Data Message] if it
+  /// requested a reply, and is otherwise discarded. Failures to transmit are
+  /// logged and do not stop the procedure.
+  /// 
+  /// A Stream 9 [Data Message] required by the [Unhandled] action is
+  /// transmitted as a new Primary [Data Message] with the [Session ID] of the
+  /// offending one and the [Next System].
+  /// 
+  /// [Connect Procedure]:    Client::connect
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Message Router]:       MessageRouter
+  /// [Data Message]:         MessageContents::DataMessage
+  /// [Message ID]:           MessageID
+  /// [Unhandled]:            Unhandled
+  /// [Session ID]:           MessageID::session
+  /// [Next System]:          Client::next_system
+  pub fn serve(
+    self: &Arc<Self>,
+    receiver: Receiver<Inbound>,
+    router: &MessageRouter,
+  ) {
+    for inbound in receiver {
+      match router.handle(inbound.message.clone()) {
+        Routing::Reply(reply) => {
+          if !inbound.message.expects_reply() {
+            log::debug!("generic::Client::serve: REPLY DISCARDED");
+            continue
+          }
+          if let Err(error) = inbound.reply(reply) {
+            log::debug!("generic::Client::serve: {}", error);
+          }
+        },
+        Routing::Ignore => {},
+        Routing::Error(error_message) => {
+          let header: [u8;10] = primitive::MessageHeader {
+            session_id        : inbound.id.session.into(),
+            byte_2            : ((inbound.message.w as u8) << 7) | inbound.message.stream,
+            byte_3            : inbound.message.function,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::DataMessage as u8,
+            system            : inbound.id.system,
+          }.into();
+          let Some(header) = semi_e5::items::MessageHeader::new(header.to_vec()) else {continue};
+          let id = MessageID {session: inbound.id.session, system: self.next_system()};
+          if let Err(error) = self.data(id, error_message(header)) {
+            log::debug!("generic::Client::serve: {}", error);
+          }
+        },
+      }
+    }
+  }
+
+  /// ### WINDOWED DATA PROCEDURE
+  /// 
+  /// Performs the [Data Procedure] for each of a sequence of
+  /// [Data Message]s, keeping at most a given number of them outstanding at
+  /// any time, and provides the result of each as it completes.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// [Data Message]s are transmitted in the order provided, with each waiting
+  /// to be transmitted until fewer than the maximum number of previously
+  /// transmitted [Data Message]s remain outstanding, which provides
+  /// back-pressure when the Remote Entity is slow to respond. A maximum of 0
+  /// is treated as 1.
+  /// 
+  /// The window is served by a fixed number of threads, one for each
+  /// [Data Message] allowed to be outstanding, each of which transmits the
+  /// next [Data Message] in turn and waits for its response, rather than by
+  /// a thread for each [Data Message].
+  /// 
+  /// The returned [Receiver] yields the [Message ID] and result of each
+  /// [Data Procedure] in the order in which they complete, and is closed once
+  /// every [Data Procedure] has completed. Each result is as would be
+  /// provided by the [Data Procedure].
+  /// 
+  /// [Data Procedure]: Client::data
+  /// [Data Message]:   MessageContents::DataMessage
+  /// [Message ID]:     MessageID
+  pub fn data_windowed(
+    self: &Arc<Self>,
+    messages: Vec<(MessageID, semi_e5::Message)>,
+    max_outstanding: usize,
+  ) -> Receiver<(MessageID, Result<Option<semi_e5::Message>, Error>)> {
+    let (result_sender, result_receiver) = channel::<(MessageID, Result<Option<semi_e5::Message>, Error>)>();
+    let workers: usize = max_outstanding.max(1).min(messages.len());
+    let queue: Arc<Mutex<std::vec::IntoIter<(MessageID, semi_e5::Message)>>> = Arc::new(Mutex::new(messages.into_iter()));
+    for _ in 0..workers {
+      let clone: Arc<Client> = self.clone();
+      let queue = queue.clone();
+      let result_sender = result_sender.clone();
+      thread::spawn(move || {
+        loop {
+          // WINDOW: Take Next, Transmitted in Order
+          let (id, opened) = {
+            let mut queue = queue.lock().unwrap();
+            let Some((id, message)) = queue.next() else {break};
+            (id, clone.data_open(id, message))
+          };
+          // DATA PROCEDURE: Await Response
+          let result = opened.and_then(|(open_id, receiver)| {
+            clone.data_await(open_id, receiver, clone.parameter_settings.t3)
+          });
+          let _ = result_sender.send((id, result));
+        }
+      });
+    }
+    result_receiver
+  }
+
+  /// ### SELECT PROCEDURE
+  /// **Based on SEMI E37-1109§7.3-7.4**
+  /// 
+  /// Asks the [Client] to initiate the [Select Procedure] by transmitting a
+  /// [Select.req] message and waiting for the corresponding [Select.rsp]
+  /// message to be received.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [NOT SELECTED] state to use this
+  /// procedure.
+  /// 
+  /// The [Client] will wait to receive the [Select.rsp] for the amount
+  /// of time specified by [T6] before it will consider it a communications
+  /// failure and initiate the [Disconnect Procedure]. While waiting, the
+  /// [SELECT INITIATED] state is entered.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the [CONNECTED]
+  /// state will automatically respond to having received a [Select.req]
+  /// message based on its current [Selection State]:
+  /// - [NOT SELECTED] - The [Client] will respond with a [Select.rsp]
+  ///   accepting and completing the [Select Procedure].
+  /// - [SELECT INITIATED], matching [Session ID] - The [Client] will respond
+  ///   with a [Select.rsp] accepting the Remote Entity's
+  ///   [Select Procedure], but will not complete its own until its own
+  ///   [Select.rsp] is received.
+  /// - [SELECT INITIATED], other [Session ID] - The [Client] will respond
+  ///   with a [Select.rsp] message rejecting the [Select Procedure].
+  /// - [SELECTED] - The [Client] will respond with a [Select.rsp] message
+  ///   rejecting the [Select Procedure].
+  /// 
+  /// With the `multi_session` feature enabled, this procedure may also be
+  /// used in the [SELECTED] state to select another [Session ID], in which
+  /// case the [Selection State] is not changed, and a [Select.req] for
+  /// another [Session ID] received in the [SELECTED] state is accepted. If a
+  /// [Separate.req] leaves the [SELECTED] state while the [Select.rsp] is
+  /// awaited, it is entered again with the new [Session ID] alone.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Select Procedure], the [SELECTED] state
+  /// is entered.
+  /// 
+  /// If the [Select Procedure] fails, a [Select Error] is returned which
+  /// describes the reason for the failure, including any [Select Status]
+  /// received, so that the Remote Entity's refusal can be told apart from a
+  /// communications failure when deciding whether and when to retry.
+  /// 
+  /// [Select Error]:         SelectError
+  /// [Select Status]:        SelectStatus
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [SELECTED]:             SelectionState::Selected
+  /// [SELECT INITIATED]:     SelectionState::SelectInitiated
+  /// [T6]:                   ParameterSettings::t6
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Select Procedure]:     Client::select
+  /// [Select.req]:           MessageContents::SelectRequest
+  /// [Select.rsp]:           MessageContents::SelectResponse
+  /// [Separate.req]:         MessageContents::SeparateRequest
+  /// [Session ID]:           MessageID::session
+  pub fn select(
+    self: &Arc<Self>,
+    id: MessageID,
+  ) -> JoinHandle<Result<(), SelectError>> {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      'disconnect: {
+        #[cfg_attr(not(feature = "multi_session"), allow(unused_variables))]
+        let guard = clone.lock_selection();
+        // IS: NOT CONNECTED
+        if !clone.primitive_client.is_connected() {
+          return Err(SelectError::NotConnected)
+        }
+        match clone.selection_state.load(Relaxed) {
+          SelectionState::NotSelected => {
+            // TO: SELECT INITIATED
+            clone.selection_session.store(id.session, Relaxed);
+            clone.selection_state.store(SelectionState::SelectInitiated, Relaxed);
+            clone.notify_selection();
+            // TX: Select.req
+            let result: Result<(), SelectError> = match clone.transmit(
+              Message {
+                id,
+                contents: MessageContents::SelectRequest,
+              },
+              true,
+              clone.parameter_settings.t6,
+            ) {
+              // RX: Response
+              Ok(Some(rx_message)) => match Self::select_response(rx_message) {
+                // RX: Select.rsp Success
+                Ok(()) => {
+                  // TO: SELECTED, Unless Disconnected Meanwhile
+                  if clone.selection_state.compare_exchange(SelectionState::SelectInitiated, SelectionState::Selected, Relaxed, Relaxed).is_err() {
+                    return Err(SelectError::Io(Error::from(ErrorKind::ConnectionAborted)))
+                  }
+                  #[cfg(feature = "multi_session")]
+                  clone.other_sessions.lock().unwrap().clear();
+                  clone.selection_count.fetch_add(1, Relaxed);
+                  clone.transition(ConnectionStateTransition::NotSelectedToSelected);
+                  log::debug!("generic::Client::select: SELECTED");
+                  return Ok(())
+                },
+                // RX: Select.rsp Failure, Reject.req, Unknown
+                failure => failure,
+              },
+              // RX: No Response
+              Ok(None) => {
+                // TO: NOT CONNECTED, NOT SELECTED
+                clone.selection_state.store(SelectionState::NotSelected, Relaxed);
+                break 'disconnect;
+              },
+              // TX: Failure
+              Err(error) => Err(SelectError::from(error)),
+            };
+            // TO: NOT SELECTED
+            clone.selection_state.store(SelectionState::NotSelected, Relaxed);
+            return result
+          },
+          // IS: SELECTED, Other Session ID
+          #[cfg(feature = "multi_session")]
+          SelectionState::Selected if !clone.is_selected_session(id.session) => {
+            // The SELECTED state is kept while the Select.rsp is awaited, so
+            // the lock is released for a Separate.req received meanwhile.
+            drop(guard);
+            // TX: Select.req
+            match clone.transmit(
+              Message {
+                id,
+                contents: MessageContents::SelectRequest,
+              },
+              true,
+              clone.parameter_settings.t6,
+            ) {
+              // RX: Response
+              Ok(Some(rx_message)) => {
+                let result: Result<(), SelectError> = Self::select_response(rx_message);
+                if result.is_ok() {
+                  let _guard = clone.lock_selection();
+                  match clone.selection_state.load(Relaxed) {
+                    // SESSIONS: Add
+                    SelectionState::Selected => {
+                      clone.other_sessions.lock().unwrap().insert(id.session);
+                      log::debug!("generic::Client::select: SESSION {} SELECTED", id.session);
+                    },
+                    // IS: NOT CONNECTED
+                    _ if !clone.primitive_client.is_connected() => {
+                      return Err(SelectError::Io(Error::from(ErrorKind::ConnectionAborted)))
+                    },
+                    // TO: SELECTED, Separated Meanwhile
+                    _ => {
+                      clone.selection_session.store(id.session, Relaxed);
+                      clone.other_sessions.lock().unwrap().clear();
+                      clone.selection_state.store(SelectionState::Selected, Relaxed);
+                      clone.selection_count.fetch_add(1, Relaxed);
+                      clone.transition(ConnectionStateTransition::NotSelectedToSelected);
+                      log::debug!("generic::Client::select: SELECTED");
+                    },
+                  }
+                }
+                return result
+              },
+              // RX: No Response
+              Ok(None) => break 'disconnect,
+              // TX: Failure
+              Err(error) => return Err(SelectError::from(error)),
+            }
+          },
+          SelectionState::SelectInitiated | SelectionState::Selected | SelectionState::DeselectInitiated => {
+            return Err(SelectError::AlreadySelected)
+          },
+        }
+      }
+      clone.disconnect().map_err(SelectError::from)?;
+      Err(SelectError::Timeout)
+    })
+  }
+
+  /// ### SELECT RESPONSE
+  /// 
+  /// Interprets the reply to a [Select.req] received by the
+  /// [Select Procedure].
+  /// 
+  /// [Select Procedure]: Client::select
+  /// [Select.req]:       MessageContents::SelectRequest
+  fn select_response(
+    rx_message: Message,
+  ) -> Result<(), SelectError> {
+    match rx_message.contents {
+      // RX: Select.rsp
+      MessageContents::SelectResponse(select_status) => {
+        match SelectStatus::try_from(select_status) {
+          // RX: Select.rsp Success
+          Ok(SelectStatus::Success) => Ok(()),
+          // RX: Select.rsp Failure
+          Ok(select_status) => Err(SelectError::Rejected(select_status)),
+          // RX: Select.rsp Unknown Failure
+          Err(_) => Err(SelectError::UnknownStatus(select_status)),
+        }
+      },
+      // RX: Reject.req
+      MessageContents::RejectRequest(_type, reason) => Err(SelectError::RejectRequest(reason)),
+      // RX: Unknown
+      _ => Err(SelectError::Io(Error::from(ErrorKind::InvalidData))),
+    }
+  }
+
+  /// ### SELECTED SESSION
+  /// 
+  /// Provides the [Session ID] used to complete the [Select Procedure] if the
+  /// [Selection State] is in the [SELECTED] state.
+  /// 
+  /// [Selection State]:  SelectionState
+  /// [SELECTED]:         SelectionState::Selected
+  /// [Select Procedure]: Client::select
+  /// [Session ID]:       MessageID::session
+  pub fn selected_session(
+    self: &Arc<Self>,
+  ) -> Option<SessionID> {
+    let _guard = self.lock_selection();
+    match self.selection_state.load(Relaxed) {
+      SelectionState::NotSelected | SelectionState::SelectInitiated => None,
+      SelectionState::Selected | SelectionState::DeselectInitiated => Some(self.selection_session.load(Relaxed)),
+    }
+  }
+
+  /// ### SELECTED SESSIONS
+  /// 
+  /// Provides every [Session ID] which is currently selected, beginning
+  /// with the one provided by the [Selected Session] function.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Unless the `multi_session` feature is enabled, at most one
+  /// [Session ID] is ever selected.
+  /// 
+  /// [Selected Session]: Client::selected_session
+  /// [Session ID]:       MessageID::session
+  pub fn selected_sessions(
+    self: &Arc<Self>,
+  ) -> Vec<SessionID> {
+    let selected_session: Option<SessionID> = self.selected_session();
+    #[cfg(feature = "multi_session")]
+    if let Some(session) = selected_session {
+      let mut sessions: Vec<SessionID> = vec![session];
+      sessions.extend(self.other_sessions.lock().unwrap().iter().copied());
+      return sessions
+    }
+    selected_session.into_iter().collect()
+  }
+
+  /// ### IS SELECTED SESSION
+  /// 
+  /// Whether the provided [Session ID] is selected, assuming that the
+  /// [Selection State] is in the [SELECTED] or [DESELECT INITIATED] state.
+  /// 
+  /// [Selection State]:    SelectionState
+  /// [SELECTED]:           SelectionState::Selected
+  /// [DESELECT INITIATED]: SelectionState::DeselectInitiated
+  /// [Session ID]:         MessageID::session
+  fn is_selected_session(
+    &self,
+    session: SessionID,
+  ) -> bool {
+    if self.selection_session.load(Relaxed) == session {return true}
+    #[cfg(feature = "multi_session")]
+    if self.other_sessions.lock().unwrap().contains(&session) {return true}
+    false
+  }
+
+  /// ### RELEASE SESSION
+  /// 
+  /// Ends one of several selected sessions without leaving the [SELECTED]
+  /// state, returning false without doing anything if the provided
+  /// [Session ID] is not selected or is the only one selected.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Session ID] is the one provided by the [Selected Session]
+  /// function, another selected [Session ID] takes its place.
+  /// 
+  /// [SELECTED]:         SelectionState::Selected
+  /// [Selected Session]: Client::selected_session
+  /// [Session ID]:       MessageID::session
+  #[cfg(feature = "multi_session")]
+  fn release_session(
+    &self,
+    session: SessionID,
+  ) -> bool {
+    let mut other_sessions = self.other_sessions.lock().unwrap();
+    if other_sessions.remove(&session) {return true}
+    if self.selection_session.load(Relaxed) != session {return false}
+    match other_sessions.iter().next().copied() {
+      Some(other_session) => {
+        other_sessions.remove(&other_session);
+        self.selection_session.store(other_session, Relaxed);
+        true
+      },
+      None => false,
+    }
+  }
+
+  /// ### IS RELEASABLE SESSION
+  /// 
+  /// Whether the [Release Session] function would end the provided
+  /// [Session ID] without leaving the [SELECTED] state.
+  /// 
+  /// [SELECTED]:        SelectionState::Selected
+  /// [Release Session]: Client::release_session
+  /// [Session ID]:      MessageID::session
+  #[cfg(feature = "multi_session")]
+  fn is_releasable_session(
+    &self,
+    session: SessionID,
+  ) -> bool {
+    let other_sessions = self.other_sessions.lock().unwrap();
+    if other_sessions.contains(&session) {return true}
+    self.selection_session.load(Relaxed) == session && !other_sessions.is_empty()
+  }
+
+  /// ### DESELECT PROCEDURE
+  /// **Based on SEMI E37-1109§7.7**
+  /// 
+  /// Asks the [Client] to initiate the [Deselect Procedure] by transmitting a
+  /// [Deselect.req] message and waiting for the corresponding [Deselect.rsp]
+  /// message to be received.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// Both are checked while the [Selection State] is locked, before anything
+  /// is transmitted, failing with an error of kind [NotConnected] or
+  /// [PermissionDenied] respectively.
+  /// 
+  /// The [Client] will wait to receive the [Deselect.rsp] for the amount of
+  /// time specified by [T6] before it will consider it a communications
+  /// failure and initiate the [Disconnect Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the [CONNECTED]
+  /// state will automatically respond to having received a [Deselect.req]
+  /// message based on its current [Selection State]:
+  /// - [NOT SELECTED] - The [Client] will respond with a [Deselect.rsp]
+  ///   rejecting the [Deselect Procedure].
+  /// - [SELECTED] - The [Client] will respond with a [Deselect.rsp] accepting
+  ///   and completing the [Deselect Procedure].
+  /// 
+  /// - [DESELECT INITIATED] - The [Client] will respond with a [Deselect.rsp]
+  ///   accepting the Simultaneous Deselect if the [Session ID] matches its
+  ///   own, or rejecting it otherwise.
+  /// 
+  /// If both entities initiate the [Deselect Procedure] at the same time,
+  /// each accepts the other's [Deselect.req], and each procedure is
+  /// completed by the corresponding [Deselect.rsp].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [DESELECT INITIATED] state is entered while waiting for the
+  /// [Deselect.rsp]. Upon completion of the [Deselect Procedure], the
+  /// [NOT SELECTED] state is entered, and upon its failure, the [SELECTED]
+  /// state is returned to. If a [Separate.req] is received while waiting,
+  /// the [NOT SELECTED] state is entered immediately and is kept regardless
+  /// of the [Deselect.rsp].
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [SELECTED]:             SelectionState::Selected
+  /// [DESELECT INITIATED]:   SelectionState::DeselectInitiated
+  /// [Session ID]:           MessageID::session
+  /// [T6]:                   ParameterSettings::t6
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Deselect Procedure]:   Client::deselect
+  /// [Deselect.req]:         MessageContents::DeselectRequest
+  /// [Deselect.rsp]:         MessageContents::DeselectResponse
+  /// [Separate.req]:         MessageContents::SeparateRequest
+  /// [NotConnected]:         ErrorKind::NotConnected
+  /// [PermissionDenied]:     ErrorKind::PermissionDenied
+  pub fn deselect(
+    self: &Arc<Self>,
+    id: MessageID,
+  ) -> JoinHandle<Result<(), Error>> {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      'disconnect: {
+        #[cfg_attr(not(feature = "multi_session"), allow(unused_variables))]
+        let guard = clone.lock_selection();
+        // IS: NOT CONNECTED
+        if !clone.primitive_client.is_connected() {
+          return Err(Error::from(ErrorKind::NotConnected))
+        }
+        match clone.selection_state.load(Relaxed) {
+          // IS: NOT SELECTED, SELECT INITIATED, DESELECT INITIATED
+          SelectionState::NotSelected | SelectionState::SelectInitiated | SelectionState::DeselectInitiated => {
+            return Err(Error::from(ErrorKind::PermissionDenied))
+          },
+          // IS: SELECTED, Other Sessions Remain
+          #[cfg(feature = "multi_session")]
+          SelectionState::Selected if !clone.other_sessions.lock().unwrap().is_empty() => {
+            if !clone.is_selected_session(id.session) {
+              return Err(Error::from(ErrorKind::PermissionDenied))
+            }
+            // The SELECTED state is kept while the Deselect.rsp is awaited, so
+            // the lock is released for a Separate.req received meanwhile.
+            drop(guard);
+            // TX: Deselect.req
+            let result = clone.transmit(
+              Message {
+                id,
+                contents: MessageContents::DeselectRequest,
+              },
+              true,
+              clone.parameter_settings.t6,
+            );
+            return match result {
+              // RX: Deselect.rsp Success
+              Ok(Some(Message {contents: MessageContents::DeselectResponse(deselect_status), ..}))
+                if deselect_status == DeselectStatus::Success as u8 =>
+              {
+                // SESSIONS: Remove, Unless Separated Meanwhile
+                let _guard = clone.lock_selection();
+                if clone.selection_state.load(Relaxed) == SelectionState::Selected && clone.release_session(id.session) {
+                  log::debug!("generic::Client::deselect: SESSION {} DESELECTED", id.session);
+                }
+                Ok(())
+              },
+              // RX: No Response
+              Ok(None) => break 'disconnect,
+              // RX: Deselect.rsp Failure, Reject.req
+              Ok(Some(Message {contents: MessageContents::DeselectResponse(_) | MessageContents::RejectRequest(..), ..})) => Err(Error::from(ErrorKind::PermissionDenied)),
+              // RX: Unknown
+              Ok(Some(_)) => Err(Error::from(ErrorKind::InvalidData)),
+              // TX: Failure
+              Err(error) => Err(error),
+            }
+          },
+          // IS: SELECTED
+          SelectionState::Selected => {
+            // TO: DESELECT INITIATED
+            clone.selection_state.store(SelectionState::DeselectInitiated, Relaxed);
+            log::debug!("generic::Client::deselect: DESELECT INITIATED");
+            // TX: Deselect.req
+            let result = clone.transmit(
+              Message {
+                id,
+                contents: MessageContents::DeselectRequest,
+              },
+              true,
+              clone.parameter_settings.t6,
+            );
+            match result {
+              // RX: Deselect.rsp Success
+              Ok(Some(Message {contents: MessageContents::DeselectResponse(deselect_status), ..}))
+                if deselect_status == DeselectStatus::Success as u8 =>
+              {
+                // TO: NOT SELECTED, Unless Separated Meanwhile
+                if clone.selection_state.compare_exchange(SelectionState::DeselectInitiated, SelectionState::NotSelected, Relaxed, Relaxed).is_ok() {
+                  clone.not_selected_timeout();
+                  clone.transition(ConnectionStateTransition::SelectedToNotSelected);
+                  log::debug!("generic::Client::deselect: NOT SELECTED");
+                }
+                return Ok(())
+              },
+              // RX: No Response
+              Ok(None) => {
+                // TO: NOT CONNECTED, NOT SELECTED
+                break 'disconnect;
+              },
+              // RX: Deselect.rsp Failure, Reject.req, Unknown, or Error
+              failure => {
+                // TO: SELECTED, Unless Separated Meanwhile
+                if clone.selection_state.compare_exchange(SelectionState::DeselectInitiated, SelectionState::Selected, Relaxed, Relaxed).is_ok() {
+                  log::debug!("generic::Client::deselect: SELECTED");
+                }
+                return match failure? {
+                  // RX: Deselect.rsp Failure
+                  Some(Message {contents: MessageContents::DeselectResponse(_deselect_status), ..}) => Err(Error::from(ErrorKind::PermissionDenied)),
+                  // RX: Reject.req
+                  Some(Message {contents: MessageContents::RejectRequest(_type, _reason), ..}) => Err(Error::from(ErrorKind::PermissionDenied)),
+                  // RX: Unknown
+                  _ => Err(Error::from(ErrorKind::InvalidData)),
+                }
+              },
+            }
+          },
+        }
+      }
+      clone.disconnect()?;
+      Err(Error::from(ErrorKind::ConnectionAborted))
+    })
+  }
+
+  /// ### LINKTEST PROCEDURE
+  /// **Based on SEMI E37-1109§7.8**
+  /// 
+  /// Asks the [Client] to initiate the [Linktest Procedure] by transmitting a
+  /// [Linktest.req] message and waiting for the corresponding [Linktest.rsp]
+  /// message to be received.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// The [Client] will wait to receive the [Linktest.rsp] for the amount of
+  /// time specified by [T6] before it will consider it a communications
+  /// failure and initiate the [Disconnect Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the
+  /// [CONNECTED] state will automatically respond to having received a
+  /// [Linktest.req] message:
+  /// - The [Client] will respond with a [Linktest.rsp], completing the
+  ///   [Linktest Procedure].
+  /// 
+  /// [Connection State]:     primitive::ConnectionState
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Selection State]:      SelectionState
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  /// [SELECTED]:             SelectionState::Selected
+  /// [T6]:                   ParameterSettings::t6
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Linktest Procedure]:   Client::linktest
+  /// [Linktest.req]:         MessageContents::LinktestRequest
+  /// [Linktest.rsp]:         MessageContents::LinktestResponse
+  pub fn linktest(
+    self: &Arc<Self>,
+    system: u32,
+  ) -> JoinHandle<Result<(), Error>> {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || clone.run_linktest(system))
+  }
+
+  /// ### RUN LINKTEST PROCEDURE
+  /// 
+  /// Performs the [Linktest Procedure] on the calling thread, blocking it
+  /// until the [Linktest.rsp] is received or the time specified by [T6]
+  /// elapses, so that the [Linktest Heartbeat] does not start a thread for
+  /// each one.
+  /// 
+  /// [Linktest Procedure]: Client::linktest
+  /// [Linktest Heartbeat]: Client::linktest_heartbeat
+  /// [Linktest.rsp]:       MessageContents::LinktestResponse
+  /// [T6]:                 ParameterSettings::t6
+  fn run_linktest(
+    self: &Arc<Self>,
+    system: u32,
+  ) -> Result<(), Error> {
+    // TX: Linktest.req
+    match self.transmit(
+      Message {
+        id: MessageID {
+          session: SessionID::LINKTEST,
+          system,
+        },
+        contents: MessageContents::LinktestRequest,
+      },
+      true,
+      self.parameter_settings.t6,
+    )?{
+      // RX: Response
+      Some(rx_message) => {
+        match rx_message.contents {
+          // RX: Linktest.rsp
+          MessageContents::LinktestResponse => Ok(()),
+          // RX: Reject.req
+          MessageContents::RejectRequest(_type, _reason) => Err(Error::from(ErrorKind::PermissionDenied)),
+          // RX: Unknown
+          _ => Err(Error::from(ErrorKind::InvalidData)),
+        }
+      },
+      // RX: No Response
+      None => {
+        // TO: NOT CONNECTED, NOT SELECTED
+        self.disconnect()?;
+        Err(Error::from(ErrorKind::ConnectionAborted))
+      },
+    }
+  }
+
+  /// ### SEPARATE PROCEDURE
+  /// **Based on SEMI E37-1109§7.9**
+  /// 
+  /// Asks the [Client] to initiate the [Separate Procedure] by transmitting a
+  /// [Separate.req] message.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state and the
+  /// [Selection State] must be in the [SELECTED] state to use this procedure.
+  /// Both are checked while the [Selection State] is locked, before anything
+  /// is transmitted, failing with an error of kind [NotConnected] or
+  /// [PermissionDenied] respectively, so that a [Separate.req] is never sent
+  /// for a session which the Remote Entity has already ended.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the [CONNECTED]
+  /// state will automatically respond to having received a [Separate.req]
+  /// message based on its current [Selection State]:
+  /// - [NOT SELECTED] - The [Client] will not do anything.
+  /// - [SELECTED] - The [Client] will complete the [Separate Procedure].
+  /// - [DESELECT INITIATED] - The [Client] will complete the
+  ///   [Separate Procedure] without waiting for its own [Deselect Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Upon completion of the [Separate Procedure], the [NOT SELECTED] state is
+  /// entered.
+  /// 
+  /// [Connection State]:   primitive::ConnectionState
+  /// [CONNECTED]:          primitive::ConnectionState::Connected
+  /// [Selection State]:    SelectionState
+  /// [NOT SELECTED]:       SelectionState::NotSelected
+  /// [SELECTED]:           SelectionState::Selected
+  /// [DESELECT INITIATED]: SelectionState::DeselectInitiated
+  /// [Client]:             Client
+  /// [Separate Procedure]: Client::separate
+  /// [Deselect Procedure]: Client::deselect
+  /// [Separate.req]:       MessageContents::SeparateRequest
+  /// [NotConnected]:       ErrorKind::NotConnected
+  /// [PermissionDenied]:   ErrorKind::PermissionDenied
+  pub fn separate(
+    self: &Arc<Self>,
+    id: MessageID,
+  ) -> JoinHandle<Result<(), Error>> {
+    let clone: Arc<Client> = self.clone();
+    thread::spawn(move || {
+      let _guard = clone.lock_selection();
+      // IS: NOT CONNECTED
+      if !clone.primitive_client.is_connected() {
+        return Err(Error::from(ErrorKind::NotConnected))
+      }
+      match clone.selection_state.load(Relaxed) {
+        // IS: NOT SELECTED, SELECT INITIATED, DESELECT INITIATED
+        SelectionState::NotSelected | SelectionState::SelectInitiated | SelectionState::DeselectInitiated => {
+          Err(Error::from(ErrorKind::PermissionDenied))
+        },
+        // IS: SELECTED, Other Sessions Remain
+        #[cfg(feature = "multi_session")]
+        SelectionState::Selected if !clone.other_sessions.lock().unwrap().is_empty() => {
+          if !clone.is_selected_session(id.session) {
+            return Err(Error::from(ErrorKind::PermissionDenied))
+          }
+          // TX: Separate.req
+          clone.transmit(
+            Message {
+              id,
+              contents: MessageContents::SeparateRequest,
+            },
+            false,
+            clone.parameter_settings.t6,
+          )?;
+          // SESSIONS: Remove
+          clone.release_session(id.session);
+          log::debug!("generic::Client::separate: SESSION {} SEPARATED", id.session);
+          Ok(())
+        },
+        // IS: SELECTED
+        SelectionState::Selected => {
+          // TX: Separate.req
+          clone.transmit(
+            Message {
+              id,
+              contents: MessageContents::SeparateRequest,
+            },
+            false,
+            clone.parameter_settings.t6,
+          )?;
+          // TO: NOT SELECTED
+          clone.selection_state.store(SelectionState::NotSelected, Relaxed);
+          clone.not_selected_timeout();
+          clone.transition(ConnectionStateTransition::SelectedToNotSelected);
+          log::debug!("generic::Client::separate: NOT SELECTED");
+          Ok(())
+        },
+      }
+    })
+  }
+
+  /// ### REJECT PROCEDURE (TODO)
+  /// **Based on SEMI E37-1109§7.10**
+  /// 
+  /// Asks the [Client] to initiate the [Reject Procedure] by transmitting a
+  /// [Reject.req] message.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Although not done within this function, a [Client] in the [CONNECTED]
+  /// state will automatically respond to having received a [Reject.req]:
+  /// - If a procedure is awaiting a response with the same [Message ID], it
+  ///   fails with an error of kind [PermissionDenied], or with the
+  ///   [Select Error] for a rejected [Select.req] in the case of the
+  ///   [Select Procedure].
+  /// - Otherwise, the [Reject.req] is logged and ignored.
+  /// 
+  /// [Connection State]: primitive::ConnectionState
+  /// [CONNECTED]:        primitive::ConnectionState::Connected
+  /// [Selection State]:  SelectionState
+  /// [NOT SELECTED]:     SelectionState::NotSelected
+  /// [SELECTED]:         SelectionState::Selected
+  /// [Client]:           Client
+  /// [Reject Procedure]: Client::reject
+  /// [Reject.req]:       MessageContents::RejectRequest
+  /// [Select.req]:       MessageContents::SelectRequest
+  /// [Select Procedure]: Client::select
+  /// [Message ID]:       MessageID
+  /// [PermissionDenied]: ErrorKind::PermissionDenied
+  /// [Select Error]:     SelectError::RejectRequest
+  pub fn reject(
+    self: &Arc<Self>,
+    _reason: RejectReason,
+  ) -> Result<(), Error> {
+    todo!()
+  }
+}
+
+/// ## RETRY POLICY
+/// 
+/// Determines how the [Supervised Connect Procedure] waits between
+/// successive attempts at the [Connect Procedure], and whether it initiates
+/// the [Select Procedure] once connected.
+/// 
+/// [Supervised Connect Procedure]: Client::connect_supervised
+/// [Connect Procedure]:            Client::connect
+/// [Select Procedure]:             Client::select
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+  /// ### INITIAL DELAY
+  /// 
+  /// The time waited before the first retry, which is raised to the time
+  /// specified by [T5] if it is shorter.
+  /// 
+  /// [T5]: ParameterSettings::t5
+  pub initial_delay: Duration,
+
+  /// ### MAXIMUM DELAY
+  /// 
+  /// The longest time waited between retries, however many have failed.
+  pub max_delay: Duration,
+
+  /// ### MULTIPLIER
+  /// 
+  /// The factor by which the time waited grows after each failed retry.
+  pub multiplier: u32,
+
+  /// ### SELECT SESSION
+  /// 
+  /// The [Session ID] with which to initiate the [Select Procedure] after
+  /// each successful [Connect Procedure].
+  /// 
+  /// If [None], the [Select Procedure] is left to the application.
+  /// 
+  /// [Session ID]:        MessageID::session
+  /// [Select Procedure]:  Client::select
+  /// [Connect Procedure]: Client::connect
+  pub select_session: Option<SessionID>,
+}
+impl Default for RetryPolicy {
+  /// ### DEFAULT RETRY POLICY
+  /// 
+  /// Provides a [Retry Policy] with these values.
+  /// 
+  /// - [Initial Delay] of zero, so that [T5] is used
+  /// - [Maximum Delay] of 60 seconds
+  /// - [Multiplier] of 2
+  /// - [Select Session] of [None]
+  /// 
+  /// [Retry Policy]:   RetryPolicy
+  /// [Initial Delay]:  RetryPolicy::initial_delay
+  /// [Maximum Delay]:  RetryPolicy::max_delay
+  /// [Multiplier]:     RetryPolicy::multiplier
+  /// [Select Session]: RetryPolicy::select_session
+  /// [T5]:             ParameterSettings::t5
+  fn default() -> Self {
+    Self {
+      initial_delay: Duration::ZERO,
+      max_delay: Duration::from_secs(60),
+      multiplier: 2,
+      select_session: None,
+    }
+  }
+}
+
+/// ## SUPERVISOR
+/// 
+/// The handle returned by the [Supervised Connect Procedure], which keeps
+/// retrying until it is cancelled.
+/// 
+/// Dropping the [Supervisor] without cancelling it stops further retries,
+/// but leaves the current connection, if any, in place.
+/// 
+/// [Supervised Connect Procedure]: Client::connect_supervised
+/// [Supervisor]:                   Supervisor
+pub struct Supervisor {
+  client: Arc<Client>,
+  cancel_sender: Sender<()>,
+  thread: JoinHandle<()>,
+}
+impl Supervisor {
+  /// ### CANCEL
+  /// 
+  /// Stops the [Supervised Connect Procedure] from retrying, initiates the
+  /// [Disconnect Procedure], and waits for the supervising thread to finish.
+  /// 
+  /// A [Connect Procedure] already in progress is allowed to complete first,
+  /// which takes no longer than the time specified by [T5] when the
+  /// [Connection Mode] is [ACTIVE].
+  /// 
+  /// [Supervised Connect Procedure]: Client::connect_supervised
+  /// [Connect Procedure]:            Client::connect
+  /// [Disconnect Procedure]:         Client::disconnect
+  /// [Connection Mode]:              primitive::ConnectionMode
+  /// [ACTIVE]:                       primitive::ConnectionMode::Active
+  /// [T5]:                           ParameterSettings::t5
+  pub fn cancel(self) {
+    drop(self.cancel_sender);
+    let _ = self.client.disconnect();
+    let _ = self.thread.join();
+  }
+}
+
+/// ## INBOUND
+/// 
+/// A Primary [Data Message] received from the Remote Entity, as sent to the
+/// hook provided by the [Connect Procedure], along with a handle for
+/// answering it.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The [Reply] function transmits a Response [Data Message] with the same
+/// [Message ID] as the Primary [Data Message], so that it is correlated with
+/// the transaction opened by the Remote Entity.
+/// 
+/// [Connect Procedure]: Client::connect
+/// [Data Message]:      MessageContents::DataMessage
+/// [Message ID]:        MessageID
+/// [Reply]:             Inbound::reply
+#[derive(Clone)]
+pub struct Inbound {
+  /// ### MESSAGE ID
+  /// 
+  /// The [Message ID] of the Primary [Data Message].
+  /// 
+  /// [Message ID]:   MessageID
+  /// [Data Message]: MessageContents::DataMessage
+  pub id: MessageID,
+
+  /// ### MESSAGE
+  /// 
+  /// The contents of the Primary [Data Message].
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  pub message: semi_e5::Message,
+
+  client: Arc<Client>,
+}
+impl Inbound {
+  /// ### REPLY
+  /// 
+  /// Transmits a Response [Data Message] to the Remote Entity using the
+  /// [Message ID] of the Primary [Data Message], by way of the
+  /// [Data Procedure].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Fails with an error of kind [InvalidInput] if the Primary
+  /// [Data Message] did not request a reply, and otherwise as the
+  /// [Data Procedure] does.
+  /// 
+  /// [Data Procedure]: Client::data
+  /// [Data Message]:   MessageContents::DataMessage
+  /// [Message ID]:     MessageID
+  /// [InvalidInput]:   ErrorKind::InvalidInput
+  pub fn reply(
+    &self,
+    message: impl Into<semi_e5::Message>,
+  ) -> Result<(), Error> {
+    if !self.message.expects_reply() {
+      return Err(Error::new(ErrorKind::InvalidInput, "no reply requested"))
+    }
+    self.client.data(self.id, message).map(|_| ())
+  }
+}
+impl fmt::Debug for Inbound {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Inbound")
+      .field("id", &self.id)
+      .field("message", &self.message)
+      .finish()
+  }
+}
+
+/// ## DRAIN INBOUND
+/// 
+/// Takes every [Inbound] Primary [Data Message] already waiting on the hook
+/// provided by the [Connect Procedure], without blocking, so that a burst of
+/// them can be handled in one wake-up.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Provides an empty [Vec] if none are waiting, including once the hook has
+/// been closed.
+/// 
+/// [Inbound]:           Inbound
+/// [Data Message]:      MessageContents::DataMessage
+/// [Connect Procedure]: Client::connect
+pub fn drain_inbound(
+  receiver: &Receiver<Inbound>,
+) -> Vec<Inbound> {
+  receiver.try_iter().collect()
+}
+
+/// ## TRY RECEIVE INBOUND
+/// 
+/// Takes the next [Inbound] Primary [Data Message] waiting on the hook
+/// provided by the [Connect Procedure], if there is one, without blocking,
+/// so that the hook can be polled from an existing event loop.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Provides [None] if none is waiting, and fails only once none is waiting
+/// and the hook has been closed, so that the event loop can stop polling.
+/// 
+/// [Inbound]:           Inbound
+/// [Data Message]:      MessageContents::DataMessage
+/// [Connect Procedure]: Client::connect
+pub fn try_recv_inbound(
+  receiver: &Receiver<Inbound>,
+) -> Result<Option<Inbound>, RecvError> {
+  match receiver.try_recv() {
+    Ok(inbound) => Ok(Some(inbound)),
+    Err(TryRecvError::Empty) => Ok(None),
+    Err(TryRecvError::Disconnected) => Err(RecvError),
+  }
+}
+
+/// ## RECEIVE BATCH
+/// 
+/// Waits up to the provided amount of time for an [Inbound] Primary
+/// [Data Message] on the hook provided by the [Connect Procedure], then takes
+/// any others already waiting without blocking, providing at most the
+/// provided maximum number of them.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Fails only if none was received, with [Timeout] if the time elapsed, or
+/// with [Disconnected] if the hook has been closed. Any [Inbound] left
+/// waiting beyond the maximum is kept for the next call.
+/// 
+/// [Inbound]:           Inbound
+/// [Data Message]:      MessageContents::DataMessage
+/// [Connect Procedure]: Client::connect
+/// [Timeout]:           RecvTimeoutError::Timeout
+/// [Disconnected]:      RecvTimeoutError::Disconnected
+pub fn recv_batch(
+  receiver: &Receiver<Inbound>,
+  max: usize,
+  timeout: Duration,
+) -> Result<Vec<Inbound>, RecvTimeoutError> {
+  if max == 0 {return Ok(vec![])}
+  let mut batch: Vec<Inbound> = vec![receiver.recv_timeout(timeout)?];
+  batch.extend(receiver.try_iter().take(max - 1));
+  Ok(batch)
+}
+
+/// ## TRANSACT ERROR
+/// 
+/// The reason a [Transact] did not produce a Response [Data Message].
+/// 
+/// [Transact]:     Client::transact
+/// [Data Message]: MessageContents::DataMessage
+#[derive(Debug)]
+pub enum TransactError {
+  /// ### NOT SELECTED
+  /// 
+  /// The [Selection State] was [NOT SELECTED], so the Primary [Data Message]
+  /// was not sent.
+  /// 
+  /// [Selection State]: SelectionState
+  /// [NOT SELECTED]:    SelectionState::NotSelected
+  /// [Data Message]:    MessageContents::DataMessage
+  NotSelected,
+
+  /// ### NO REPLY
+  /// 
+  /// The Primary [Data Message] did not request a reply, so there is no
+  /// Response [Data Message] to wait for.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  NoReply,
+
+  /// ### REJECTED
+  /// 
+  /// The Remote Entity responded with a [Reject.req] message.
+  /// 
+  /// [Reject.req]: MessageContents::RejectRequest
+  Rejected,
+
+  /// ### TIMEOUT
+  /// 
+  /// No Response [Data Message] was received within the time specified by
+  /// [T3], and the connection has been dropped.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  /// [T3]:           ParameterSettings::t3
+  Timeout,
+
+  /// ### IO
+  /// 
+  /// Any other failure of the [Data Procedure], including a failure to
+  /// transmit the Primary [Data Message].
+  /// 
+  /// [Data Procedure]: Client::data
+  /// [Data Message]:   MessageContents::DataMessage
+  Io(Error),
+}
+impl Display for TransactError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      TransactError::NotSelected => write!(f, "not selected"),
+      TransactError::NoReply     => write!(f, "no reply requested"),
+      TransactError::Rejected    => write!(f, "rejected by remote entity"),
+      TransactError::Timeout     => write!(f, "reply timed out"),
+      TransactError::Io(error)   => write!(f, "{}", error),
+    }
+  }
+}
+impl std::error::Error for TransactError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      TransactError::Io(error) => Some(error),
+      _ => None,
+    }
+  }
+}
+impl From<TransactError> for Error {
+  /// ### ERROR FROM TRANSACT ERROR
+  /// 
+  /// Provides compatibility with callers expecting an [Error], using the same
+  /// kinds as the [Data Procedure].
+  /// 
+  /// [Error]:          Error
+  /// [Data Procedure]: Client::data
+  fn from(transact_error: TransactError) -> Self {
+    match transact_error {
+      TransactError::NotSelected => Error::from(ErrorKind::AlreadyExists),
+      TransactError::NoReply     => Error::from(ErrorKind::InvalidInput),
+      TransactError::Rejected    => Error::from(ErrorKind::PermissionDenied),
+      TransactError::Timeout     => Error::from(ErrorKind::ConnectionAborted),
+      TransactError::Io(error)   => error,
+    }
+  }
+}
+
+/// ## MESSAGE TOO LONG
+/// 
+/// The reason a [Data Message] was refused before being transmitted, because
+/// its Message Length would exceed the [Maximum Message Length].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Provided as the inner error of an [Error] of kind [InvalidInput] by the
+/// [Data Procedure], and may be recovered with [Error::get_ref] and
+/// downcasting.
+/// 
+/// [Data Message]:           MessageContents::DataMessage
+/// [Maximum Message Length]: ParameterSettings::max_message_length
+/// [Error]:                  Error
+/// [InvalidInput]:           ErrorKind::InvalidInput
+/// [Data Procedure]:         Client::data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageTooLong {
+  /// ### LENGTH
+  /// 
+  /// The Message Length, in bytes, which the [Data Message] would have had.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  pub length: usize,
+
+  /// ### MAXIMUM MESSAGE LENGTH
+  /// 
+  /// The [Maximum Message Length] which was exceeded.
+  /// 
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  pub max_message_length: u32,
+}
+impl Display for MessageTooLong {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "message length {} exceeds maximum of {}", self.length, self.max_message_length)
+  }
+}
+impl std::error::Error for MessageTooLong {}
+
+/// ## SELECT ERROR
+/// 
+/// The reason a [Select Procedure] did not enter the [SELECTED] state.
+/// 
+/// [Select Procedure]: Client::select
+/// [SELECTED]:         SelectionState::Selected
+#[derive(Debug)]
+pub enum SelectError {
+  /// ### NOT CONNECTED
+  /// 
+  /// The [Connection State] was [NOT CONNECTED], so the [Select.req] was not
+  /// sent.
+  /// 
+  /// [Connection State]: primitive::ConnectionState
+  /// [NOT CONNECTED]:    primitive::ConnectionState::NotConnected
+  /// [Select.req]:       MessageContents::SelectRequest
+  NotConnected,
+
+  /// ### ALREADY SELECTED
+  /// 
+  /// The [Selection State] was already [SELECTED], so the [Select.req] was
+  /// not sent.
+  /// 
+  /// [Selection State]: SelectionState
+  /// [SELECTED]:        SelectionState::Selected
+  /// [Select.req]:      MessageContents::SelectRequest
+  AlreadySelected,
+
+  /// ### REJECTED
+  /// 
+  /// The Remote Entity responded with a [Select.rsp] message containing the
+  /// given [Select Status] other than [Success].
+  /// 
+  /// [Select.rsp]:    MessageContents::SelectResponse
+  /// [Select Status]: SelectStatus
+  /// [Success]:       SelectStatus::Success
+  Rejected(SelectStatus),
+
+  /// ### UNKNOWN STATUS
+  /// 
+  /// The Remote Entity responded with a [Select.rsp] message containing a
+  /// [Select Status] value reserved for Subsidiary Standards or the Local
+  /// Entity.
+  /// 
+  /// [Select.rsp]:    MessageContents::SelectResponse
+  /// [Select Status]: SelectStatus
+  UnknownStatus(u8),
+
+  /// ### REJECT REQUEST
+  /// 
+  /// The Remote Entity responded with a [Reject.req] message containing the
+  /// given reason code.
+  /// 
+  /// [Reject.req]: MessageContents::RejectRequest
+  RejectRequest(u8),
+
+  /// ### TIMEOUT
+  /// 
+  /// No [Select.rsp] was received within the time specified by [T6], and the
+  /// connection has been dropped.
+  /// 
+  /// [Select.rsp]: MessageContents::SelectResponse
+  /// [T6]:         ParameterSettings::t6
+  Timeout,
+
+  /// ### IO
+  /// 
+  /// Any other failure of the [Select Procedure].
+  /// 
+  /// [Select Procedure]: Client::select
+  Io(Error),
+}
+impl Display for SelectError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      SelectError::NotConnected          => write!(f, "not connected"),
+      SelectError::AlreadySelected       => write!(f, "already selected"),
+      SelectError::Rejected(status)      => write!(f, "select rejected: {}", status),
+      SelectError::UnknownStatus(status) => write!(f, "select rejected with status {}", status),
+      SelectError::RejectRequest(reason) => match RejectReason::try_from(*reason) {
+        Ok(reason) => write!(f, "reject.req received: {}", reason),
+        Err(())    => write!(f, "reject.req received with reason {}", reason),
+      },
+      SelectError::Timeout               => write!(f, "select response timed out"),
+      SelectError::Io(error)             => write!(f, "{}", error),
+    }
+  }
+}
+impl std::error::Error for SelectError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      SelectError::Io(error) => Some(error),
+      _ => None,
+    }
+  }
+}
+impl From<Error> for SelectError {
+  /// ### SELECT ERROR FROM ERROR
+  /// 
+  /// Classifies an [Error] encountered while transmitting the [Select.req].
+  /// 
+  /// [Error]:      Error
+  /// [Select.req]: MessageContents::SelectRequest
+  fn from(error: Error) -> Self {
+    match error.kind() {
+      ErrorKind::NotConnected => SelectError::NotConnected,
+      _                       => SelectError::Io(error),
+    }
+  }
+}
+impl From<SelectError> for Error {
+  /// ### ERROR FROM SELECT ERROR
+  /// 
+  /// Provides compatibility with callers expecting an [Error], using the
+  /// kinds previously returned by the [Select Procedure].
+  /// 
+  /// [Error]:            Error
+  /// [Select Procedure]: Client::select
+  fn from(select_error: SelectError) -> Self {
+    match select_error {
+      SelectError::NotConnected     => Error::from(ErrorKind::NotConnected),
+      SelectError::AlreadySelected  => Error::from(ErrorKind::AlreadyExists),
+      SelectError::Rejected(_)      => Error::from(ErrorKind::PermissionDenied),
+      SelectError::UnknownStatus(_) => Error::from(ErrorKind::PermissionDenied),
+      SelectError::RejectRequest(_) => Error::from(ErrorKind::PermissionDenied),
+      SelectError::Timeout          => Error::from(ErrorKind::ConnectionAborted),
+      SelectError::Io(error)        => error,
+    }
+  }
+}
+
+/// ## CONNECT SELECT ERROR
+/// 
+/// The reason the [Connect and Select Procedure] did not provide a
+/// connection in the [SELECTED] state.
+/// 
+/// [Connect and Select Procedure]: Client::connect_and_select
+/// [SELECTED]:                     SelectionState::Selected
+#[derive(Debug)]
+pub enum ConnectSelectError {
+  /// ### CONNECT
+  /// 
+  /// The [Connect Procedure] failed.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  Connect(ConnectError),
+
+  /// ### SELECT
+  /// 
+  /// The [Select Procedure] initiated with a [Connection Mode] of [ACTIVE]
+  /// failed.
+  /// 
+  /// [Select Procedure]: Client::select
+  /// [Connection Mode]:  primitive::ConnectionMode
+  /// [ACTIVE]:           primitive::ConnectionMode::Active
+  Select(SelectError),
+
+  /// ### NOT SELECTED
+  /// 
+  /// With a [Connection Mode] of [PASSIVE], the connection was broken before
+  /// the Remote Entity initiated the [Select Procedure], whether due to [T7]
+  /// or a failure of the TCP/IP connection. The cause is provided by the
+  /// [Disconnect Reason] function.
+  /// 
+  /// [Connection Mode]:   primitive::ConnectionMode
+  /// [PASSIVE]:           primitive::ConnectionMode::Passive
+  /// [Select Procedure]:  Client::select
+  /// [Disconnect Reason]: Client::disconnect_reason
+  /// [T7]:                ParameterSettings::t7
+  NotSelected,
+}
+impl Display for ConnectSelectError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ConnectSelectError::Connect(error) => write!(f, "connect failed: {}", error),
+      ConnectSelectError::Select(error)  => write!(f, "select failed: {}", error),
+      ConnectSelectError::NotSelected    => write!(f, "disconnected before selected"),
+    }
+  }
+}
+impl std::error::Error for ConnectSelectError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ConnectSelectError::Connect(error) => Some(error),
+      ConnectSelectError::Select(error)  => Some(error),
+      ConnectSelectError::NotSelected    => None,
+    }
+  }
+}
+impl From<ConnectError> for ConnectSelectError {
+  /// ### CONNECT SELECT ERROR FROM CONNECT ERROR
+  /// 
+  /// Wraps the [Connect Error] of a failed [Connect Procedure].
+  /// 
+  /// [Connect Error]:     ConnectError
+  /// [Connect Procedure]: Client::connect
+  fn from(connect_error: ConnectError) -> Self {
+    ConnectSelectError::Connect(connect_error)
+  }
+}
+impl From<ConnectSelectError> for Error {
+  /// ### ERROR FROM CONNECT SELECT ERROR
+  /// 
+  /// Provides compatibility with callers expecting an [Error], preserving the
+  /// kinds provided by the [Connect Error] and [Select Error].
+  /// 
+  /// [Error]:         Error
+  /// [Connect Error]: ConnectError
+  /// [Select Error]:  SelectError
+  fn from(connect_select_error: ConnectSelectError) -> Self {
+    match connect_select_error {
+      ConnectSelectError::Connect(error) => Error::from(error),
+      ConnectSelectError::Select(error)  => Error::from(error),
+      ConnectSelectError::NotSelected    => Error::from(ErrorKind::ConnectionAborted),
+    }
+  }
+}
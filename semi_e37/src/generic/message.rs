@@ -0,0 +1,1174 @@
+//! # MESSAGE
+//! 
+//! Defines the [Message]s exchanged by the [Client], and their conversion to
+//! and from [Primitive Message]s and raw frames.
+//! 
+//! [Client]:            Client
+//! [Message]:           Message
+//! [Primitive Message]: primitive::Message
+
+use super::*;
+
+/// ## DECODE ERROR
+/// 
+/// The reason a complete frame could not be decoded by [Decode Frame].
+/// 
+/// [Decode Frame]: decode_frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecodeError {
+  /// ### INCOMPLETE
+  /// 
+  /// The frame ended before the Message Length, or the number of bytes it
+  /// specifies, could be read.
+  Incomplete,
+
+  /// ### INVALID LENGTH
+  /// 
+  /// The Message Length was less than the size of a [Message Header].
+  /// 
+  /// [Message Header]: primitive::MessageHeader
+  InvalidLength,
+
+  /// ### TRAILING BYTES
+  /// 
+  /// The frame contained more bytes than specified by its Message Length.
+  TrailingBytes,
+
+  /// ### REJECTED
+  /// 
+  /// The frame was valid according to the [Primitive Services] but not
+  /// according to the [Generic Services], for the given [Reject Reason].
+  /// 
+  /// [Primitive Services]: primitive
+  /// [Generic Services]:   crate::generic
+  /// [Reject Reason]:      RejectReason
+  Rejected(RejectReason),
+}
+impl Display for DecodeError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      DecodeError::Incomplete       => write!(f, "incomplete frame"),
+      DecodeError::InvalidLength    => write!(f, "message length shorter than header"),
+      DecodeError::TrailingBytes    => write!(f, "trailing bytes after message"),
+      DecodeError::Rejected(reason) => write!(f, "rejected: {:?}", reason),
+    }
+  }
+}
+impl std::error::Error for DecodeError {}
+
+/// ## MESSAGE
+/// **Based on SEMI E37-1109§8.2-8.3**
+/// 
+/// Data using the structure defined by the [Generic Services], enforcing
+/// compliance as determined by a [Presentation Type] of 0, broken down into
+/// its [Message ID] and [Message Contents].
+/// 
+/// [Generic Services]:  crate::generic
+/// [Presentation Type]: PresentationType
+/// [Message ID]:        MessageID
+/// [Message Contents]:  MessageContents
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Message {
+  pub id: MessageID,
+  pub contents: MessageContents,
+}
+impl Message {
+  /// ### AS DATA MESSAGE
+  /// 
+  /// Provides a reference to the [SECS-II] formatted contents of a
+  /// [Data Message], or [None] if the [Message] is of another kind.
+  /// 
+  /// [SECS-II]:      semi_e5
+  /// [Message]:      Message
+  /// [Data Message]: MessageContents::DataMessage
+  pub fn as_data_message(&self) -> Option<&semi_e5::Message> {
+    match &self.contents {
+      MessageContents::DataMessage(message) => Some(message),
+      _ => None,
+    }
+  }
+
+  /// ### INTO DATA MESSAGE
+  /// 
+  /// Consumes the [Message], producing the [SECS-II] formatted contents of a
+  /// [Data Message], or returning the [Message] unchanged if it is of
+  /// another kind.
+  /// 
+  /// [SECS-II]:      semi_e5
+  /// [Message]:      Message
+  /// [Data Message]: MessageContents::DataMessage
+  pub fn into_data_message(self) -> Result<semi_e5::Message, Message> {
+    match self.contents {
+      MessageContents::DataMessage(message) => Ok(message),
+      contents => Err(Message {
+        id: self.id,
+        contents,
+      }),
+    }
+  }
+
+  /// ### AS SELECT RESPONSE
+  /// 
+  /// Provides the [Select Status] of a [Select.rsp], or [None] if the
+  /// [Message] is of another kind.
+  /// 
+  /// [Message]:       Message
+  /// [Select.rsp]:    MessageContents::SelectResponse
+  /// [Select Status]: SelectStatus
+  pub fn as_select_response(&self) -> Option<u8> {
+    match self.contents {
+      MessageContents::SelectResponse(status) => Some(status),
+      _ => None,
+    }
+  }
+
+  /// ### AS DESELECT RESPONSE
+  /// 
+  /// Provides the [Deselect Status] of a [Deselect.rsp], or [None] if the
+  /// [Message] is of another kind.
+  /// 
+  /// [Message]:         Message
+  /// [Deselect.rsp]:    MessageContents::DeselectResponse
+  /// [Deselect Status]: DeselectStatus
+  pub fn as_deselect_response(&self) -> Option<u8> {
+    match self.contents {
+      MessageContents::DeselectResponse(status) => Some(status),
+      _ => None,
+    }
+  }
+
+  /// ### AS REJECT REQUEST
+  /// 
+  /// Provides the rejected [Presentation Type] or [Session Type] and the
+  /// [Reason Code] of a [Reject.req], or [None] if the [Message] is of
+  /// another kind.
+  /// 
+  /// [Message]:           Message
+  /// [Reject.req]:        MessageContents::RejectRequest
+  /// [Presentation Type]: PresentationType
+  /// [Session Type]:      SessionType
+  /// [Reason Code]:       RejectReason
+  pub fn as_reject_request(&self) -> Option<(u8, u8)> {
+    match self.contents {
+      MessageContents::RejectRequest(kind, reason) => Some((kind, reason)),
+      _ => None,
+    }
+  }
+
+  /// ### IS CONTROL MESSAGE
+  /// 
+  /// Reports whether the [Message] is a Control Message, that is, anything
+  /// other than a [Data Message].
+  /// 
+  /// [Message]:      Message
+  /// [Data Message]: MessageContents::DataMessage
+  pub fn is_control_message(&self) -> bool {
+    !matches!(self.contents, MessageContents::DataMessage(_))
+  }
+
+  /// ### CHECK STATUS
+  /// 
+  /// Provides the [Message] unchanged if it is a [Select.rsp] whose status
+  /// is a known [Select Status], a [Deselect.rsp] whose status is a known
+  /// [Deselect Status], or any other kind of [Message], and otherwise fails
+  /// with [Malformed Data].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Values reserved for Subsidiary Standards or the Local Entity are not
+  /// known, and so fail. This check is made by the [Client] on received
+  /// [Message]s only when [Strict] is enabled.
+  /// 
+  /// [Message]:         Message
+  /// [Select.rsp]:      MessageContents::SelectResponse
+  /// [Deselect.rsp]:    MessageContents::DeselectResponse
+  /// [Select Status]:   SelectStatus
+  /// [Deselect Status]: DeselectStatus
+  /// [Malformed Data]:  RejectReason::MalformedData
+  /// [Client]:          Client
+  /// [Strict]:          ParameterSettings::strict
+  pub fn check_status(self) -> Result<Self, RejectReason> {
+    match self.contents {
+      MessageContents::SelectResponse(status)   if SelectStatus::try_from(status).is_err()   => Err(RejectReason::MalformedData),
+      MessageContents::DeselectResponse(status) if DeselectStatus::try_from(status).is_err() => Err(RejectReason::MalformedData),
+      _ => Ok(self),
+    }
+  }
+
+  /// ### LENIENT DECODE
+  /// 
+  /// Interprets a [Primitive Message] as a [Message] in the same way as its
+  /// [TryFrom] implementation, except that non-zero bytes in the
+  /// [Message Header] fields reserved by a Control Message's [Session Type]
+  /// are logged and ignored rather than failing with [Malformed Data].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Only the reserved [Header Byte 2] and [Header Byte 3] are tolerated; a
+  /// Control Message with a [Message Text] or an unsuitable [Session ID]
+  /// still fails. This is used by the [Client] on received [Message]s only
+  /// when [Strict Decode] is disabled.
+  /// 
+  /// [Primitive Message]: primitive::Message
+  /// [Message]:           Message
+  /// [Message Header]:    primitive::MessageHeader
+  /// [Header Byte 2]:     primitive::MessageHeader::byte_2
+  /// [Header Byte 3]:     primitive::MessageHeader::byte_3
+  /// [Message Text]:      primitive::Message::text
+  /// [Session Type]:      SessionType
+  /// [Session ID]:        SessionID
+  /// [Malformed Data]:    RejectReason::MalformedData
+  /// [Client]:            Client
+  /// [Strict Decode]:     ParameterSettings::strict_decode
+  pub fn try_from_lenient(mut message: primitive::Message) -> Result<Self, RejectReason> {
+    let (byte_2_reserved, byte_3_reserved) = match SessionType::try_from(message.header.session_type) {
+      Ok(
+        SessionType::SelectRequest
+        | SessionType::DeselectRequest
+        | SessionType::LinktestRequest
+        | SessionType::LinktestResponse
+        | SessionType::SeparateRequest
+      ) => (true, true),
+      Ok(SessionType::SelectResponse | SessionType::DeselectResponse) => (true, false),
+      _ => (false, false),
+    };
+    if (byte_2_reserved && message.header.byte_2 != 0) || (byte_3_reserved && message.header.byte_3 != 0) {
+      log::debug!(
+        "generic::Message::try_from_lenient: RESERVED BYTES IGNORED {:02X} {:02X}",
+        message.header.byte_2,
+        message.header.byte_3,
+      );
+      if byte_2_reserved {message.header.byte_2 = 0}
+      if byte_3_reserved {message.header.byte_3 = 0}
+    }
+    Self::try_from(message)
+  }
+
+  /// ### DECODE
+  /// 
+  /// Interprets a received [Primitive Message] as a [Message] in the same
+  /// way as a [Client] created with the provided [Parameter Settings],
+  /// according to its [Strict Decode] and [Strict] parameters.
+  /// 
+  /// [Primitive Message]:  primitive::Message
+  /// [Message]:            Message
+  /// [Client]:             Client
+  /// [Parameter Settings]: ParameterSettings
+  /// [Strict Decode]:      ParameterSettings::strict_decode
+  /// [Strict]:             ParameterSettings::strict
+  pub(crate) fn decode(
+    message: primitive::Message,
+    parameter_settings: &ParameterSettings,
+  ) -> Result<Self, RejectReason> {
+    let result: Result<Self, RejectReason> = if parameter_settings.strict_decode {
+      Self::try_from(message)
+    } else {
+      Self::try_from_lenient(message)
+    };
+    result.and_then(|message| {
+      if parameter_settings.strict {message.check_status()} else {Ok(message)}
+    })
+  }
+}
+impl TryFrom<Message> for primitive::Message {
+  type Error = semi_e5::Error;
+
+  /// ### PRIMITIVE MESSAGE FROM GENERIC MESSAGE
+  /// 
+  /// Due to the fact that valid [Generic Message]s are a subset of valid
+  /// [Primitive Message]s, this operation only fails when the [Item] of a
+  /// [Data Message] cannot be serialized.
+  /// 
+  /// [Generic Message]:   Message
+  /// [Primitive Message]: primitive::Message
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Item]:              semi_e5::Item
+  fn try_from(message: Message) -> Result<Self, Self::Error> {
+    Ok(match message.contents {
+      MessageContents::DataMessage(e5_message) => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : ((e5_message.w as u8) << 7) | e5_message.stream,
+            byte_3            : e5_message.function,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::DataMessage as u8,
+            system            : message.id.system,
+          },
+          text: match e5_message.text {
+            Some(item) => {
+              let mut text: Vec<u8> = Vec::with_capacity(item.encoded_len());
+              item.serialize_into(&mut text)?;
+              text
+            },
+            None => vec![],
+          },
+        }
+      },
+      MessageContents::SelectRequest => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : 0,
+            byte_3            : 0,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::SelectRequest as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::SelectResponse(select_status) => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : 0,
+            byte_3            : select_status,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::SelectResponse as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::DeselectRequest => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : 0,
+            byte_3            : 0,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::DeselectRequest as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::DeselectResponse(deselect_status) => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : 0,
+            byte_3            : deselect_status,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::DeselectResponse as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::LinktestRequest => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : SessionID::LINKTEST.into(),
+            byte_2            : 0,
+            byte_3            : 0,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::LinktestRequest as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::LinktestResponse => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : SessionID::LINKTEST.into(),
+            byte_2            : 0,
+            byte_3            : 0,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::LinktestResponse as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::RejectRequest(message_type, reason_code) => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : message_type,
+            byte_3            : reason_code,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::RejectRequest as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+      MessageContents::SeparateRequest => {
+        primitive::Message {
+          header: primitive::MessageHeader {
+            session_id        : message.id.session.into(),
+            byte_2            : 0,
+            byte_3            : 0,
+            presentation_type : PresentationType::SecsII as u8,
+            session_type      : SessionType::SeparateRequest as u8,
+            system            : message.id.system,
+          },
+          text: vec![],
+        }
+      },
+    })
+  }
+}
+impl TryFrom<primitive::Message> for Message {
+  type Error = RejectReason;
+
+  /// ## GENERIC MESSAGE FROM PRIMITIVE MESSAGE
+  /// 
+  /// Due to the fact that valid [Generic Message]s are a subset of valid
+  /// [Primitive Message]s, this operation is fallable when the
+  /// [Primitive Message] is not a [Generic Message].
+  /// 
+  /// The [LINKTEST] [Session ID] is required by the [Linktest.req] and
+  /// [Linktest.rsp], and the [BROADCAST] [Session ID] is refused by the
+  /// [Select.req], [Deselect.req], and [Separate.req], as it does not
+  /// identify a session.
+  /// 
+  /// [Generic Message]:   Message
+  /// [Primitive Message]: primitive::Message
+  /// [Session ID]:        SessionID
+  /// [LINKTEST]:          SessionID::LINKTEST
+  /// [BROADCAST]:         SessionID::BROADCAST
+  /// [Select.req]:        MessageContents::SelectRequest
+  /// [Deselect.req]:      MessageContents::DeselectRequest
+  /// [Linktest.req]:      MessageContents::LinktestRequest
+  /// [Linktest.rsp]:      MessageContents::LinktestResponse
+  /// [Separate.req]:      MessageContents::SeparateRequest
+  fn try_from(message: primitive::Message) -> Result<Self, Self::Error> {
+    if message.header.presentation_type != 0 {return Err(RejectReason::UnsupportedPresentationType)}
+    let session: SessionID = SessionID::from(message.header.session_id);
+    Ok(Message {
+      id: MessageID {
+        session,
+        system: message.header.system,
+      },
+      contents: match SessionType::try_from(message.header.session_type) {
+        Ok(SessionType::DataMessage) => {
+          MessageContents::DataMessage(semi_e5::Message{
+            stream   : message.header.byte_2 & 0b0111_1111,
+            function : message.header.byte_3,
+            w        : message.header.byte_2 & 0b1000_0000 > 0,
+            text     : match semi_e5::Item::try_from(message.text) {
+              // Valid Item
+              Ok(text) => Some(text),
+              // Invalid Item
+              Err(error) => {
+                match error {
+                  // Empty Text: Considered Valid Here
+                  semi_e5::Error::EmptyText => {None},
+                  // Other Error: Malformed Data
+                  _ => {return Err(RejectReason::MalformedData)}
+                }
+              },
+            },
+          })
+        },
+        Ok(SessionType::SelectRequest) => {
+          if session == SessionID::BROADCAST {return Err(RejectReason::MalformedData)}
+          if message.header.byte_2 != 0      {return Err(RejectReason::MalformedData)}
+          if message.header.byte_3 != 0      {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()        {return Err(RejectReason::MalformedData)}
+          MessageContents::SelectRequest
+        },
+        Ok(SessionType::SelectResponse) => {
+          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
+          MessageContents::SelectResponse(message.header.byte_3)
+        },
+        Ok(SessionType::DeselectRequest) => {
+          if session == SessionID::BROADCAST {return Err(RejectReason::MalformedData)}
+          if message.header.byte_2 != 0      {return Err(RejectReason::MalformedData)}
+          if message.header.byte_3 != 0      {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()        {return Err(RejectReason::MalformedData)}
+          MessageContents::DeselectRequest
+        },
+        Ok(SessionType::DeselectResponse) => {
+          if message.header.byte_2 != 0 {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()   {return Err(RejectReason::MalformedData)}
+          MessageContents::DeselectResponse(message.header.byte_3)
+        },
+        Ok(SessionType::LinktestRequest) => {
+          if session != SessionID::LINKTEST {return Err(RejectReason::MalformedData)}
+          if message.header.byte_2 != 0     {return Err(RejectReason::MalformedData)}
+          if message.header.byte_3 != 0     {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()       {return Err(RejectReason::MalformedData)}
+          MessageContents::LinktestRequest
+        },
+        Ok(SessionType::LinktestResponse) => {
+          if session != SessionID::LINKTEST {return Err(RejectReason::MalformedData)}
+          if message.header.byte_2 != 0     {return Err(RejectReason::MalformedData)}
+          if message.header.byte_3 != 0     {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()       {return Err(RejectReason::MalformedData)}
+          MessageContents::LinktestResponse
+        },
+        Ok(SessionType::RejectRequest) => {
+          if !message.text.is_empty() {return Err(RejectReason::MalformedData)}
+          MessageContents::RejectRequest(message.header.byte_2, message.header.byte_3)
+        },
+        Ok(SessionType::SeparateRequest) => {
+          if session == SessionID::BROADCAST {return Err(RejectReason::MalformedData)}
+          if message.header.byte_2 != 0      {return Err(RejectReason::MalformedData)}
+          if message.header.byte_3 != 0      {return Err(RejectReason::MalformedData)}
+          if !message.text.is_empty()        {return Err(RejectReason::MalformedData)}
+          MessageContents::SeparateRequest
+        },
+        Err(()) => {return Err(RejectReason::UnsupportedSessionType)}
+      },
+    })
+  }
+}
+
+/// ## DECODE FRAME
+/// **Based on SEMI E37-1109§8.2**
+/// 
+/// Decodes a single complete frame, consisting of the 4 byte Message Length
+/// followed by the [Message Header] and [Message Text], into a [Message].
+/// 
+/// This is the same decode path used by the [Client] on received data, and
+/// is provided as a single entry point for fuzzing.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// No sequence of bytes can cause this function to panic, and it allocates
+/// only in proportion to the length of the frame provided, never to the
+/// Message Length or any [Item] length it declares.
+/// 
+/// [Message]:        Message
+/// [Message Header]: primitive::MessageHeader
+/// [Message Text]:   primitive::Message::text
+/// [Client]:         Client
+/// [Item]:           semi_e5::Item
+pub fn decode_frame(bytes: &[u8]) -> Result<Message, DecodeError> {
+  let (length, rest) = bytes.split_first_chunk::<4>().ok_or(DecodeError::Incomplete)?;
+  let length: usize = u32::from_be_bytes(*length) as usize;
+  if length < 10 {return Err(DecodeError::InvalidLength)}
+  let frame: &[u8] = rest.get(..length).ok_or(DecodeError::Incomplete)?;
+  if rest.len() > length {return Err(DecodeError::TrailingBytes)}
+  let primitive_message = primitive::Message::try_from(frame.to_vec()).map_err(|_| DecodeError::InvalidLength)?;
+  Message::try_from(primitive_message).map_err(DecodeError::Rejected)
+}
+
+/// ## MESSAGE ID
+/// **Based on SEMI E37-1109§8.2**
+/// 
+/// The uniquely identifying components of a [Message] in forming a valid
+/// transaction, including the [Session ID] and [System Bytes].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// A reply is correlated to its open transaction by the [Message ID] alone.
+/// Any reply with the same [Session ID] and [System Bytes] as the request
+/// completes the transaction, regardless of its contents. In particular, a
+/// Response [Data Message] matches even though its [Function] is one greater
+/// than that of the Primary [Data Message].
+/// 
+/// The Remote Entity must therefore echo the [Session ID] and [System Bytes]
+/// of a request in its reply.
+/// 
+/// [Message]:      Message
+/// [Message ID]:   MessageID
+/// [Session ID]:   MessageID::session
+/// [System Bytes]: MessageID::system
+/// [Data Message]: MessageContents::DataMessage
+/// [Function]:     semi_e5::Message::function
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageID {
+  /// ### SESSION ID
+  /// **Based on SEMI E37-1109§8.2.6.1**
+  /// 
+  /// Provides an association between [Message]s across multiple
+  /// transactions, particularly to link the [Select Procedure] and
+  /// [Deselect Procedure] to subsequent [Data Message]s.
+  /// 
+  /// [Select Procedure]:   Client::select
+  /// [Deselect Procedure]: Client::deselect
+  /// [Message]:            Message
+  /// [Data Message]:       MessageContents::DataMessage
+  pub session: SessionID,
+
+  /// ### SYSTEM BYTES
+  /// **Based on SEMI E37-1109§8.2.6.7**
+  /// 
+  /// Identifies a transaction uniquely among the set of open transactions.
+  /// 
+  /// Together with the [Session ID], must not be reused by the Local Entity
+  /// while a previous transaction using it remains open. Values provided by
+  /// the [Next System] function satisfy this as long as they are not also
+  /// chosen manually.
+  /// 
+  /// [Session ID]:  MessageID::session
+  /// [Next System]: Client::next_system
+  pub system: u32,
+}
+impl MessageID {
+  /// ### SELECTED SESSION
+  /// 
+  /// A [Session ID] which, when provided to the [Data Procedure], is replaced
+  /// by the [Session ID] used to complete the [Select Procedure].
+  /// 
+  /// [Select Procedure]: Client::select
+  /// [Data Procedure]:   Client::data
+  /// [Session ID]:       MessageID::session
+  pub const SELECTED_SESSION: SessionID = SessionID(0xFFFF);
+}
+
+/// ## SESSION ID
+/// **Based on SEMI E37-1109§8.2.6.1**
+/// 
+/// The [Session ID] of a [Message ID], kept distinct from other 16-bit
+/// values so that the reserved [Session ID]s are referred to by name.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The [Session ID] is transmitted in network byte order regardless of the
+/// byte order of the Local Entity, and converts to and from a [u16] holding
+/// its value.
+/// 
+/// [Message ID]: MessageID
+/// [Session ID]: MessageID::session
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[repr(transparent)]
+pub struct SessionID(pub u16);
+impl SessionID {
+  /// ### LINKTEST
+  /// 
+  /// The [Session ID] required of the [Linktest.req] and [Linktest.rsp].
+  /// 
+  /// [Session ID]:   SessionID
+  /// [Linktest.req]: MessageContents::LinktestRequest
+  /// [Linktest.rsp]: MessageContents::LinktestResponse
+  pub const LINKTEST: SessionID = SessionID(0xFFFF);
+
+  /// ### BROADCAST
+  /// 
+  /// The [Session ID] which does not identify any particular session, and
+  /// so is refused by the [Select.req], [Deselect.req], and [Separate.req].
+  /// 
+  /// [Session ID]:   SessionID
+  /// [Select.req]:   MessageContents::SelectRequest
+  /// [Deselect.req]: MessageContents::DeselectRequest
+  /// [Separate.req]: MessageContents::SeparateRequest
+  pub const BROADCAST: SessionID = SessionID(0xFFFF);
+}
+// SAFETY: SessionID is a transparent wrapper around a u16, which has no
+// padding or uninitialized bytes.
+unsafe impl NoUninit for SessionID {}
+impl From<u16> for SessionID {
+  fn from(session: u16) -> Self {
+    SessionID(session)
+  }
+}
+impl From<SessionID> for u16 {
+  fn from(session: SessionID) -> Self {
+    session.0
+  }
+}
+impl Display for SessionID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// ## MESSAGE CONTENTS
+/// **Based on SEMI E37-1109§8.3.1-8.3.21**
+/// 
+/// The contents of a [Message], broken down by its [Session Type]:
+/// 
+/// - [SECS-II] formatted [Data Message]
+/// - [Select.req]
+/// - [Select.rsp]
+/// - [Deselect.req]
+/// - [Deselect.rsp]
+/// - [Linktest.req]
+/// - [Linktest.rsp]
+/// - [Reject.req]
+/// - [Separate.req]
+/// 
+/// [SECS-II]:      semi_e5
+/// [Message]:      Message
+/// [Session Type]: SessionType
+/// [Data Message]: MessageContents::DataMessage
+/// [Select.req]:   MessageContents::SelectRequest
+/// [Select.rsp]:   MessageContents::SelectResponse
+/// [Deselect.req]: MessageContents::DeselectRequest
+/// [Deselect.rsp]: MessageContents::DeselectResponse
+/// [Linktest.req]: MessageContents::LinktestRequest
+/// [Linktest.rsp]: MessageContents::LinktestResponse
+/// [Reject.req]:   MessageContents::RejectRequest
+/// [Separate.req]: MessageContents::SeparateRequest
+#[repr(u8)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageContents {
+  /// ## DATA MESSAGE
+  /// **Based on SEMI E37-1109§8.3.1-8.3.3**
+  /// 
+  /// A [Message] with a [Session Type] of 0, used by the initiator of or
+  /// responding entity in the [Data Procedure] to send data.
+  /// 
+  /// Contains [SECS-II] formatted data.
+  /// 
+  /// [SECS-II]:        semi_e5
+  /// [Message]:        Message
+  /// [Session Type]:   SessionType
+  /// [Data Procedure]: Client::data
+  DataMessage(semi_e5::Message) = SessionType::DataMessage as u8,
+
+  /// ## SELECT REQUEST
+  /// **Based on SEMI E37-1109§8.3.4**
+  /// 
+  /// A [Message] with a [Session Type] of 1, used by the initiator of the
+  /// [Select Procedure] for establishing communications.
+  /// 
+  /// [Message]:          Message
+  /// [Select Procedure]: Client::select
+  /// [Session Type]:     SessionType
+  SelectRequest = SessionType::SelectRequest as u8,
+
+  /// ## SELECT RESPONSE
+  /// **Based on SEMI E37-1109§8.3.5-8.3.7**
+  /// 
+  /// A [Message] with a [Session Type] of 2, used by the responding
+  /// entity in the [Select Procedure].
+  /// 
+  /// Contains a [Select Status], indicating the success or failure mode of
+  /// the [Select Procedure].
+  /// 
+  /// [Message]:          Message
+  /// [Select Procedure]: Client::select
+  /// [Session Type]:     SessionType
+  /// [Select Status]:    SelectStatus
+  SelectResponse(u8) = SessionType::SelectResponse as u8,
+
+  /// ## DESELECT REQUEST
+  /// **Based on SEMI E37-1109§8.3.8-8.3.10**
+  /// 
+  /// A [Message] with a [Session Type] of 3, used by the initiator of the
+  /// [Deselect Procedure] for breaking communications.
+  /// 
+  /// [Message]:            Message
+  /// [Deselect Procedure]: Client::deselect
+  /// [Session Type]:       SessionType
+  DeselectRequest = SessionType::DeselectRequest as u8,
+
+  /// ## DESELECT RESPONSE
+  /// **Based on SEMI E37-1109§8.3.11-8.3.13**
+  /// 
+  /// An [Message] with a [Session Type] of 4, used by the responding entity
+  /// in the [Deselect Procedure].
+  /// 
+  /// Contains a [Deselect Status], indicating the success or failure mode of
+  /// the [Deselect Procedure].
+  /// 
+  /// [Message]:            Message
+  /// [Deselect Procedure]: Client::deselect
+  /// [Session Type]:       SessionType
+  /// [Deselect Status]:    DeselectStatus
+  DeselectResponse(u8) = SessionType::DeselectResponse as u8,
+
+  /// ## LINKTEST REQUEST
+  /// **Based on SEMI E37-1109§8.3.14-8.3.16**
+  /// 
+  /// A [Message] with a [Session Type] of 5, used by the initiator of the
+  /// [Linktest Procedure] for checking communications stability.
+  /// 
+  /// [Message]:            Message
+  /// [Session Type]:       SessionType
+  /// [Linktest Procedure]: Client::linktest
+  LinktestRequest = SessionType::LinktestRequest as u8,
+
+  /// ## LINKTEST RESPONSE
+  /// **Based on SEMI E37-1109§8.3.17-8.3.19**
+  /// 
+  /// A [Message] with a [Session Type] of 6, used by the responding entity
+  /// in the [Linktest Procedure].
+  /// 
+  /// [Message]:            Message
+  /// [Session Type]:       SessionType
+  /// [Linktest Procedure]: Client::linktest
+  LinktestResponse = SessionType::LinktestResponse as u8,
+
+  /// ## REJECT REQUEST
+  /// **Based on SEMI E37-1109§8.3.20-8.3.21**
+  /// 
+  /// A [Message] with a [Session Type] of 7, used by the responding entity
+  /// in the [Reject Procedure].
+  /// 
+  /// Contains the [Presentation Type] or [Session Type] of the [Message] being
+  /// rejected, and the [Reason Code] indicating why the message was rejected.
+  /// The [Presentation Type] is used when the [Reason Code] is
+  /// [Unsupported Presentation Type], and the [Session Type] otherwise.
+  /// 
+  /// [Message]:                       Message
+  /// [Reject Procedure]:              Client::reject
+  /// [Presentation Type]:             PresentationType
+  /// [Session Type]:                  SessionType
+  /// [Reason Code]:                   RejectReason
+  /// [Unsupported Presentation Type]: RejectReason::UnsupportedPresentationType
+  RejectRequest(u8, u8) = SessionType::RejectRequest as u8,
+
+  /// ## SEPARATE REQUEST
+  /// **Based on SEMI E37-1109§8.3.22**
+  /// 
+  /// A [Message] with a [Session Type] of 9, used by the initiator of the
+  /// [Separate Procedure] for breaking communications.
+  /// 
+  /// [Message]:            Message
+  /// [Separate Procedure]: Client::separate
+  /// [Session Type]:       SessionType
+  SeparateRequest = SessionType::SeparateRequest as u8,
+}
+impl Display for MessageContents {
+  /// ### DISPLAY MESSAGE CONTENTS
+  /// 
+  /// Names the [Session Type] and, where the header carries one, the
+  /// [Select Status], [Deselect Status], or [Reject Reason], falling back
+  /// to the raw value when it is not recognized.
+  /// 
+  /// [Session Type]:    SessionType
+  /// [Select Status]:   SelectStatus
+  /// [Deselect Status]: DeselectStatus
+  /// [Reject Reason]:   RejectReason
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      MessageContents::DataMessage(message) => write!(
+        f, "{} S{}F{}{}",
+        SessionType::DataMessage, message.stream, message.function, if message.w {"W"} else {""},
+      ),
+      MessageContents::SelectRequest => write!(f, "{}", SessionType::SelectRequest),
+      MessageContents::SelectResponse(status) => match SelectStatus::try_from(*status) {
+        Ok(status) => write!(f, "{}({})", SessionType::SelectResponse, status),
+        Err(())    => write!(f, "{}({})", SessionType::SelectResponse, status),
+      },
+      MessageContents::DeselectRequest => write!(f, "{}", SessionType::DeselectRequest),
+      MessageContents::DeselectResponse(status) => match DeselectStatus::try_from(*status) {
+        Ok(status) => write!(f, "{}({})", SessionType::DeselectResponse, status),
+        Err(())    => write!(f, "{}({})", SessionType::DeselectResponse, status),
+      },
+      MessageContents::LinktestRequest  => write!(f, "{}", SessionType::LinktestRequest),
+      MessageContents::LinktestResponse => write!(f, "{}", SessionType::LinktestResponse),
+      MessageContents::RejectRequest(session_type, reason) => {
+        write!(f, "{}(", SessionType::RejectRequest)?;
+        match SessionType::try_from(*session_type) {
+          Ok(session_type) => write!(f, "{}", session_type)?,
+          Err(())          => write!(f, "{}", session_type)?,
+        }
+        match RejectReason::try_from(*reason) {
+          Ok(reason) => write!(f, ", {})", reason),
+          Err(())    => write!(f, ", {})", reason),
+        }
+      },
+      MessageContents::SeparateRequest => write!(f, "{}", SessionType::SeparateRequest),
+    }
+  }
+}
+
+/// ## SESSION TYPE
+/// **Based on SEMI E37-1109§8.2.6.5-8.2.6.6**
+/// 
+/// Defines the type of [Message] being sent.
+/// 
+/// Values 11-127 are reserved for Subsidiary Standards.
+/// 
+/// Values 8, 10, and 128-255 are reserved and may not be used.
+/// 
+/// [Message]: Message
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SessionType {
+  /// ### DATA MESSAGE
+  /// 
+  /// Denotes a [SECS-II] formatted [Data Message].
+  /// 
+  /// [SECS-II]:      semi_e5
+  /// [Data Message]: MessageContents::DataMessage 
+  DataMessage = 0,
+
+  /// ### SELECT REQUEST
+  /// 
+  /// Denotes a [Select.req] message.
+  /// 
+  /// [Select.req]: MessageContents::SelectRequest
+  SelectRequest = 1,
+
+  /// ### SELECT RESPONSE
+  /// 
+  /// Denotes a [Select.rsp] message.
+  /// 
+  /// [Select.rsp]: MessageContents::SelectResponse
+  SelectResponse = 2,
+
+  /// ### DESELECT REQUEST
+  /// 
+  /// Denotes a [Deselect.req] message.
+  /// 
+  /// [Deselect.req]: MessageContents::DeselectRequest
+  DeselectRequest = 3,
+
+  /// ### DESELECT RESPONSE
+  /// 
+  /// Denotes a [Deselect.rsp] message.
+  /// 
+  /// [Deselect.rsp]: MessageContents::DeselectResponse
+  DeselectResponse = 4,
+
+  /// ### LINKTEST REQUEST
+  /// 
+  /// Denotes a [Linktest.req] message.
+  /// 
+  /// [Linktest.req]: MessageContents::LinktestRequest
+  LinktestRequest = 5,
+
+  /// ### LINKTEST RESPONSE
+  /// 
+  /// Denotes a [Linktest.rsp] message.
+  /// 
+  /// [Linktest.rsp]: MessageContents::LinktestResponse
+  LinktestResponse = 6,
+
+  /// ### REJECT REQUEST
+  /// 
+  /// Denotes a [Reject.req] message.
+  /// 
+  /// [Reject.req]: MessageContents::RejectRequest
+  RejectRequest = 7,
+
+  /// ### SEPARATE REQUEST
+  /// 
+  /// Denotes a [Separate.req] message.
+  /// 
+  /// [Separate.req]: MessageContents::SeparateRequest
+  SeparateRequest = 9,
+}
+impl TryFrom<u8> for SessionType {
+  type Error = ();
+
+  /// ### SESSION TYPE FROM BYTE
+  /// 
+  /// Fails if the value is reserved for Subsidiary Standards or may not be
+  /// used.
+  fn try_from(byte: u8) -> Result<Self, Self::Error> {
+    match byte {
+      0 => Ok(SessionType::DataMessage),
+      1 => Ok(SessionType::SelectRequest),
+      2 => Ok(SessionType::SelectResponse),
+      3 => Ok(SessionType::DeselectRequest),
+      4 => Ok(SessionType::DeselectResponse),
+      5 => Ok(SessionType::LinktestRequest),
+      6 => Ok(SessionType::LinktestResponse),
+      7 => Ok(SessionType::RejectRequest),
+      9 => Ok(SessionType::SeparateRequest),
+      _ => Err(()),
+    }
+  }
+}
+impl Display for SessionType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      SessionType::DataMessage      => write!(f, "Data Message"),
+      SessionType::SelectRequest    => write!(f, "Select.req"),
+      SessionType::SelectResponse   => write!(f, "Select.rsp"),
+      SessionType::DeselectRequest  => write!(f, "Deselect.req"),
+      SessionType::DeselectResponse => write!(f, "Deselect.rsp"),
+      SessionType::LinktestRequest  => write!(f, "Linktest.req"),
+      SessionType::LinktestResponse => write!(f, "Linktest.rsp"),
+      SessionType::RejectRequest    => write!(f, "Reject.req"),
+      SessionType::SeparateRequest  => write!(f, "Separate.req"),
+    }
+  }
+}
+
+/// ## SELECT STATUS
+/// **Based on SEMI E37-1109§8.3.7.2**
+/// 
+/// [Byte 3] of a [Deselect.rsp] message, used as the indication of success or
+/// reason for failure of the [Select Procedure].
+/// 
+/// Values 4-127 are reserved for Subsidiary Standards.
+/// 
+/// Values 128-255 are reserved for the Local Entity.
+/// 
+/// [Byte 3]:           primitive::MessageHeader::byte_3
+/// [Deselect.rsp]:     MessageContents::DeselectResponse
+/// [Select Procedure]: Client::select
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectStatus {
+  Success       = 0,
+  AlreadyActive = 1,
+  NotReady      = 2,
+  Exhausted     = 3,
+}
+impl TryFrom<u8> for SelectStatus {
+  type Error = ();
+
+  /// ### SELECT STATUS FROM BYTE 3
+  /// 
+  /// Fails if the value is reserved for Subsidiary Standards or the Local
+  /// Entity.
+  fn try_from(byte: u8) -> Result<Self, Self::Error> {
+    match byte {
+      0 => Ok(SelectStatus::Success),
+      1 => Ok(SelectStatus::AlreadyActive),
+      2 => Ok(SelectStatus::NotReady),
+      3 => Ok(SelectStatus::Exhausted),
+      _ => Err(()),
+    }
+  }
+}
+impl Display for SelectStatus {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      SelectStatus::Success       => write!(f, "success"),
+      SelectStatus::AlreadyActive => write!(f, "already active"),
+      SelectStatus::NotReady      => write!(f, "not ready"),
+      SelectStatus::Exhausted     => write!(f, "exhausted"),
+    }
+  }
+}
+
+/// ## DESELECT STATUS
+/// **Based on SEMI E37-1109§8.3.13.2**
+/// 
+/// [Byte 3] of a [Deselect.rsp] message, used as the indication of success or
+/// reason for failure of the [Deselect Procedure].
+/// 
+/// Values 3-127 are reserved for Subsidiary Standards.
+/// 
+/// Values 128-255 are reserved for the Local Entity.
+/// 
+/// [Byte 3]:             primitive::MessageHeader::byte_3
+/// [Deselect.rsp]:       MessageContents::DeselectResponse
+/// [Deselect Procedure]: Client::deselect
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeselectStatus {
+  Success        = 0,
+  NotEstablished = 1,
+  Busy           = 2,
+}
+impl TryFrom<u8> for DeselectStatus {
+  type Error = ();
+
+  /// ### DESELECT STATUS FROM BYTE 3
+  /// 
+  /// Fails if the value is reserved for Subsidiary Standards or the Local
+  /// Entity.
+  fn try_from(byte: u8) -> Result<Self, Self::Error> {
+    match byte {
+      0 => Ok(DeselectStatus::Success),
+      1 => Ok(DeselectStatus::NotEstablished),
+      2 => Ok(DeselectStatus::Busy),
+      _ => Err(()),
+    }
+  }
+}
+impl Display for DeselectStatus {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      DeselectStatus::Success        => write!(f, "success"),
+      DeselectStatus::NotEstablished => write!(f, "not established"),
+      DeselectStatus::Busy           => write!(f, "busy"),
+    }
+  }
+}
+
+/// ## REJECT REASON
+/// **Based on SEMI E37-1109§8.3.21.3**
+/// 
+/// [Byte 3] of a [Reject.req] message, specifying the reason a message has
+/// been rejected in the [Reject Procedure].
+/// 
+/// Values 4-127 are reserved for Subsidiary Standards.
+/// 
+/// Values 0, and 128-255 are reserved for the Local Entity.
+/// 
+/// [Byte 3]:           primitive::MessageHeader::byte_3
+/// [Reject.req]:       MessageContents::RejectRequest
+/// [Reject Procedure]: Client::reject
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RejectReason {
+  /// ### MALFORMED DATA
+  /// **Local Entity Specific Reason**
+  /// 
+  /// A [Message] was recieved which was valid according to the
+  /// [Primitive Services] but invalid according to the [Generic Services].
+  /// 
+  /// [Message]:            primitive::Message
+  /// [Primitive Services]: primitive
+  /// [Generic Services]:   crate::generic
+  MalformedData = 0,
+
+  /// ### SESSION TYPE NOT SUPPORTED
+  /// 
+  /// A [Message] was received whose [Session Type] value is not allowed.
+  /// 
+  /// [Message]:      primitive::Message
+  /// [Session Type]: SessionType
+  UnsupportedSessionType = 1,
+
+  /// ### PRESENTATION TYPE NOT SUPPORTED
+  /// 
+  /// A [Message] was received whose [Presentation Type] value is not allowed.
+  /// 
+  /// [Message]:           primitive::Message
+  /// [Presentation Type]: crate::PresentationType
+  UnsupportedPresentationType = 2,
+
+  /// ### TRANSACTION NOT OPEN
+  /// 
+  /// A [Select.rsp], [Deselect.rsp], or [Linktest.rsp] was recieved when there
+  /// was no outstanding [Select.req], [Deselect.req], or [Linktest.req] which
+  /// corresponded to it.
+  /// 
+  /// [Select.req]:   MessageContents::SelectRequest
+  /// [Select.rsp]:   MessageContents::SelectResponse
+  /// [Deselect.req]: MessageContents::DeselectRequest
+  /// [Deselect.rsp]: MessageContents::DeselectResponse
+  /// [Linktest.req]: MessageContents::LinktestRequest
+  /// [Linktest.rsp]: MessageContents::LinktestResponse
+  TransactionNotOpen = 3,
+
+  /// ### ENTITY NOT SELECTED
+  /// 
+  /// A [Data Message] was recieved when not in the [SELECTED] state.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  /// [SELECTED]:     SelectionState::Selected
+  EntityNotSelected = 4,
+}
+impl TryFrom<u8> for RejectReason {
+  type Error = ();
+
+  /// ### REJECT REASON FROM BYTE 3
+  /// 
+  /// Fails if the value is reserved for Subsidiary Standards or the Local
+  /// Entity, other than the [Malformed Data] reason used by this crate.
+  /// 
+  /// [Malformed Data]: RejectReason::MalformedData
+  fn try_from(byte: u8) -> Result<Self, Self::Error> {
+    match byte {
+      0 => Ok(RejectReason::MalformedData),
+      1 => Ok(RejectReason::UnsupportedSessionType),
+      2 => Ok(RejectReason::UnsupportedPresentationType),
+      3 => Ok(RejectReason::TransactionNotOpen),
+      4 => Ok(RejectReason::EntityNotSelected),
+      _ => Err(()),
+    }
+  }
+}
+impl Display for RejectReason {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      RejectReason::MalformedData               => write!(f, "malformed data"),
+      RejectReason::UnsupportedSessionType      => write!(f, "session type not supported"),
+      RejectReason::UnsupportedPresentationType => write!(f, "presentation type not supported"),
+      RejectReason::TransactionNotOpen          => write!(f, "transaction not open"),
+      RejectReason::EntityNotSelected           => write!(f, "entity not selected"),
+    }
+  }
+}
@@ -0,0 +1,155 @@
+//! # GENERIC SERVICES
+//! 
+//! Defines the full functionality of the [HSMS] protocol without modification
+//! by any subsidiary standards. This involves the sending of messages of
+//! particular types and at particular times as allowed by the protocol.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! To use the [Generic Services]:
+//! 
+//! - Build [Message]s which use a [Message ID] and [Message Contents]:
+//!   - [Data Message]
+//!   - [Select.req]
+//!   - [Select.rsp]
+//!   - [Deselect.req]
+//!   - [Deselect.rsp]
+//!   - [Linktest.req]
+//!   - [Linktest.rsp]
+//!   - [Reject.req]
+//!   - [Separate.req]
+//! - Create an [Client] by providing the [New Client] function with
+//!   [Parameter Settings], optionally built with the
+//!   [Parameter Settings Builder].
+//! - Manage the [Connection State] with the [Connect Procedure] and
+//!   [Disconnect Procedure].
+//! - Manage the [Selection State] with the [Select Procedure],
+//!   [Deselect Procedure], and [Separate Procedure].
+//! - Receive [Data Message]s with the hook provided by the
+//!   [Connect Procedure], and reply to them with the [Inbound] handle,
+//!   optionally without blocking with [Try Receive Inbound], or several at
+//!   once with [Drain Inbound] or [Receive Batch].
+//! - Test connection integrity with the [Linktest Procedure].
+//! - Send [Data Message]s with the [Data Procedure].
+//! - Answer [Data Message]s by [Stream] and [Function] with a
+//!   [Message Router] and the [Serve Procedure].
+//! - Send [Reject.req] messages [Reject Procedure].
+//! 
+//! [HSMS]:                       crate
+//! [Generic Services]:           crate::generic
+//! [Client]:                     Client
+//! [New Client]:                 Client::new
+//! [Connect Procedure]:          Client::connect
+//! [Inbound]:                    Inbound
+//! [Try Receive Inbound]:        try_recv_inbound
+//! [Drain Inbound]:              drain_inbound
+//! [Receive Batch]:              recv_batch
+//! [Message Router]:             MessageRouter
+//! [Serve Procedure]:            Client::serve
+//! [Stream]:                     semi_e5::Message::stream
+//! [Function]:                   semi_e5::Message::function
+//! [Disconnect Procedure]:       Client::disconnect
+//! [Select Procedure]:           Client::select
+//! [Deselect Procedure]:         Client::deselect
+//! [Separate Procedure]:         Client::separate
+//! [Linktest Procedure]:         Client::linktest
+//! [Data Procedure]:             Client::data
+//! [Reject Procedure]:           Client::reject
+//! [Message]:                    Message
+//! [Message ID]:                 MessageID
+//! [Message Contents]:           MessageContents
+//! [Data Message]:               MessageContents::DataMessage
+//! [Select.req]:                 MessageContents::SelectRequest
+//! [Select.rsp]:                 MessageContents::SelectResponse
+//! [Deselect.req]:               MessageContents::DeselectRequest
+//! [Deselect.rsp]:               MessageContents::DeselectResponse
+//! [Linktest.req]:               MessageContents::LinktestRequest
+//! [Linktest.rsp]:               MessageContents::LinktestResponse
+//! [Reject.req]:                 MessageContents::RejectRequest
+//! [Separate.req]:               MessageContents::SeparateRequest
+//! [Connection State]:           crate::primitive::ConnectionState
+//! [Selection State]:            SelectionState
+//! [Parameter Settings]:         ParameterSettings
+//! [Parameter Settings Builder]: ParameterSettingsBuilder
+
+use std::{
+  collections::HashMap,
+  fmt::{
+    self,
+    Display,
+    Formatter,
+  },
+  io::{
+    Error,
+    ErrorKind,
+  },
+  net::SocketAddr,
+  ops::{
+    Deref,
+    DerefMut,
+  },
+  sync::{
+    atomic::{
+      AtomicBool,
+      AtomicU64,
+      Ordering::Relaxed,
+    },
+    Arc,
+    Condvar,
+    Mutex,
+    MutexGuard,
+    PoisonError,
+    mpsc::{
+      channel,
+      Receiver,
+      RecvError,
+      RecvTimeoutError,
+      SendError,
+      Sender,
+      TryRecvError,
+      TrySendError,
+    },
+    RwLock,
+    TryLockError,
+  },
+  thread::{
+    self,
+    JoinHandle,
+  },
+  time::{
+    Duration,
+    Instant,
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+#[cfg(feature = "multi_session")]
+use std::collections::HashSet;
+use atomic::Atomic;
+use bytemuck::NoUninit;
+use oneshot::Sender as SendOnce;
+use crate::{
+  PresentationType,
+  primitive,
+};
+
+pub use crate::primitive::ConnectionMode;
+pub use crate::primitive::ConnectError;
+pub use crate::primitive::DisconnectReason;
+pub use crate::primitive::Keepalive;
+#[cfg(feature = "tls")]
+pub use crate::primitive::TlsSettings;
+
+mod client;
+mod message;
+mod params;
+mod reaction;
+mod router;
+mod state;
+
+pub use client::*;
+pub use message::*;
+pub use params::*;
+pub(crate) use reaction::*;
+pub use router::*;
+pub use state::*;
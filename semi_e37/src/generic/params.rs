@@ -0,0 +1,788 @@
+//! # PARAMETERS
+//! 
+//! Defines the [Parameter Settings] abided by the [Client], and the
+//! [Parameter Settings Builder] which validates them.
+//! 
+//! [Client]:                     Client
+//! [Parameter Settings]:         ParameterSettings
+//! [Parameter Settings Builder]: ParameterSettingsBuilder
+
+use super::*;
+
+/// ## PARAMETER SETTINGS
+/// **Based on SEMI E37-1109§10.2**
+/// 
+/// The required set of paramters which an [HSMS] implementation must provide,
+/// and which the [Client] will abide by.
+/// 
+/// [HSMS]:   crate
+/// [Client]: Client
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParameterSettings {
+  /// ### CONNECT MODE
+  /// 
+  /// Specifies the [Connection Mode] the [Client] will provide to
+  /// the [Primitive Client] to use when performing the [Connect Procedure]:
+  /// [PASSIVE] to wait for an incoming connection, or [ACTIVE] to initiate
+  /// an outgoing connection.
+  /// 
+  /// [Primitive Client]:  primitive::Client
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  /// [ACTIVE]:            ConnectionMode::Active
+  pub connect_mode: ConnectionMode,
+
+  /// ### T3: REPLY TIMEOUT
+  /// 
+  /// The maximum amount of time that the [Client] will wait after sending
+  /// a Primary [Data Message] to receive the appropriate Response
+  /// [Data Message] before it must initiate the [Disconnect Procedure].
+  /// 
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Data Message]:         MessageContents::DataMessage
+  pub t3: Duration,
+
+  /// ### T5: CONNECTION SEPARATION TIMEOUT
+  /// 
+  /// The minimum amount of time that the [Client] must wait between successive
+  /// attempts to initiate the [Connect Procedure] with a [Connect Mode] of
+  /// [ACTIVE].
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [Connect Mode]:      ParameterSettings::connect_mode
+  /// [ACTIVE]:            ConnectionMode::Active
+  pub t5: Duration,
+
+  /// ### T6: CONTROL TRANSACTION TIMEOUT
+  /// 
+  /// The maximum amount of time that the [Client] will wait after sending a
+  /// [Select Request], [Deselect Request], or [Linktest Request] to receive
+  /// the appropriate [Select Response], [Deselect Response], or
+  /// [Linktest Response] before it must initiate the [Disconnect Procedure].
+  /// 
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Select Request]:       MessageContents::SelectRequest
+  /// [Select Response]:      MessageContents::SelectResponse
+  /// [Deselect Request]:     MessageContents::DeselectRequest
+  /// [Deselect Response]:    MessageContents::DeselectResponse
+  /// [Linktest Request]:     MessageContents::LinktestRequest
+  /// [Linktest Response]:    MessageContents::LinktestResponse
+  pub t6: Duration,
+
+  /// ### T7: NOT SELECTED TIMEOUT
+  /// 
+  /// The maximum amount of time that the [Client] will wait after being
+  /// placed in the [NOT SELECTED] state before it must initiate the
+  /// [Disconnect Procedure].
+  /// 
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [NOT SELECTED]:         SelectionState::NotSelected
+  pub t7: Duration,
+
+  /// ### T8: NETWORK INTERCHARACTER TIMEOUT
+  /// 
+  /// The amount of time that the [Client] will provide to the
+  /// [Primitive Client] to use as the maximum amount of time it may wait while
+  /// sending or receiving data between successive characters in the same
+  /// [Primitive Message] before it must initiate the [Disconnect Procedure].
+  /// 
+  /// [Primitive Client]:     primitive::Client
+  /// [Disconnect Procedure]: primitive::Client::disconnect
+  /// [Primitive Message]:    primitive::Message
+  /// [Client]:               Client
+  pub t8: Duration,
+
+  /// ### LINKTEST INTERVAL
+  /// 
+  /// The amount of time that the [Client] will wait between successive
+  /// [Linktest Procedure]s initiated automatically while in the [CONNECTED]
+  /// state, initiating the [Disconnect Procedure] if any of them fail.
+  /// 
+  /// If [None], the [Linktest Procedure] is only initiated manually.
+  /// 
+  /// [CONNECTED]:            primitive::ConnectionState::Connected
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Linktest Procedure]:   Client::linktest
+  pub linktest_interval: Option<Duration>,
+
+  /// ### IDLE TIMEOUT
+  /// 
+  /// The maximum amount of time that the [Client] will remain in the
+  /// [SELECTED] state without transmitting or receiving a [Data Message]
+  /// before it initiates the [Disconnect Procedure].
+  /// 
+  /// Control messages, including those of the [Linktest Procedure], do not
+  /// count as activity. If [None], an idle [SELECTED] state is kept
+  /// indefinitely.
+  /// 
+  /// [SELECTED]:             SelectionState::Selected
+  /// [Client]:               Client
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Linktest Procedure]:   Client::linktest
+  /// [Data Message]:         MessageContents::DataMessage
+  pub idle_timeout: Option<Duration>,
+
+  /// ### MAXIMUM MESSAGE LENGTH
+  /// 
+  /// The largest Message Length, in bytes, that the [Client] will provide to
+  /// the [Primitive Client] to accept in a received [Primitive Message].
+  /// 
+  /// A [Primitive Message] which specifies a larger Message Length is
+  /// refused before any memory is allocated for it, and the
+  /// [Disconnect Procedure] is initiated.
+  /// 
+  /// [Client]:               Client
+  /// [Primitive Client]:     primitive::Client
+  /// [Primitive Message]:    primitive::Message
+  /// [Disconnect Procedure]: Client::disconnect
+  pub max_message_length: u32,
+
+  /// ### RESYNCHRONIZE
+  /// 
+  /// Whether the [Client] will ask the [Primitive Client] to recover from a
+  /// suspected framing error, such as a Message Length out of range or an
+  /// implausible [Message Header], by discarding received bytes until a
+  /// plausible Message Length and [Message Header] are found, rather than
+  /// initiating the [Disconnect Procedure].
+  /// 
+  /// HSMS itself has no means of detecting corruption, so this is only useful
+  /// when it is tunneled over a link which may lose or corrupt data, and
+  /// [Message]s lost while resynchronizing are not recovered.
+  /// 
+  /// [Client]:               Client
+  /// [Primitive Client]:     primitive::Client
+  /// [Message Header]:       primitive::MessageHeader
+  /// [Message]:              Message
+  /// [Disconnect Procedure]: Client::disconnect
+  pub resynchronize: bool,
+
+  /// ### AUTO REJECT
+  /// 
+  /// Whether the [Client] automatically responds to a received message
+  /// which cannot be interpreted as a [Message] by transmitting a
+  /// [Reject.req] message.
+  /// 
+  /// If false, no [Reject.req] is transmitted, and the message is instead
+  /// sent to each hook provided by the [Subscribe Malformed] function, which
+  /// is useful for passive monitoring and lenient gateways.
+  /// 
+  /// [Client]:              Client
+  /// [Subscribe Malformed]: Client::subscribe_malformed
+  /// [Message]:             Message
+  /// [Reject.req]:          MessageContents::RejectRequest
+  pub auto_reject: bool,
+
+  /// ### BACKLOG
+  /// 
+  /// The number of pending connections that the TCP listener bound by the
+  /// [Connect Procedure] with a [Connect Mode] of [PASSIVE] will queue
+  /// before refusing further connections.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connect Mode]:      ParameterSettings::connect_mode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  pub backlog: u32,
+
+  /// ### ACCEPT TIMEOUT
+  /// 
+  /// The maximum amount of time that the [Connect Procedure] with a
+  /// [Connect Mode] of [PASSIVE] will wait for the Remote Entity to connect
+  /// before failing with [Accept Timeout].
+  /// 
+  /// If [None], the [Connect Procedure] waits indefinitely.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connect Mode]:      ParameterSettings::connect_mode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  /// [Accept Timeout]:    ConnectError::AcceptTimeout
+  pub accept_timeout: Option<Duration>,
+
+  /// ### KEEPALIVE
+  /// 
+  /// The TCP [Keepalive] enabled on the TCP stream by the
+  /// [Connect Procedure], so that a Remote Entity which has silently gone
+  /// away is detected by the operating system even while nothing is being
+  /// transmitted.
+  /// 
+  /// If [None], TCP keepalive is not enabled.
+  /// 
+  /// [Keepalive]:         Keepalive
+  /// [Connect Procedure]: Client::connect
+  pub keepalive: Option<Keepalive>,
+
+  /// ### STRICT
+  /// 
+  /// Whether the [Client] treats a received [Select.rsp] or [Deselect.rsp]
+  /// whose status is not a known [Select Status] or [Deselect Status] as
+  /// malformed, as described by the [Check Status] function.
+  /// 
+  /// If true, such a message is rejected with a [Reject.req] like any other
+  /// malformed message, and the procedure waiting for it fails once its
+  /// timeout elapses. If false, the status is passed through unchanged to
+  /// the procedure waiting for it.
+  /// 
+  /// [Client]:          Client
+  /// [Reject.req]:      MessageContents::RejectRequest
+  /// [Select.rsp]:      MessageContents::SelectResponse
+  /// [Deselect.rsp]:    MessageContents::DeselectResponse
+  /// [Select Status]:   SelectStatus
+  /// [Deselect Status]: DeselectStatus
+  /// [Check Status]:    Message::check_status
+  pub strict: bool,
+
+  /// ### STRICT DECODE
+  /// 
+  /// Whether the [Client] treats a received Control Message with a non-zero
+  /// byte in a [Message Header] field reserved by its [Session Type] as
+  /// malformed.
+  /// 
+  /// If true, such a message is rejected with a [Reject.req] like any other
+  /// malformed message. If false, the reserved bytes are logged and ignored
+  /// as described by the [Lenient Decode] function, which accommodates
+  /// equipment which does not clear them.
+  /// 
+  /// [Client]:         Client
+  /// [Message Header]: primitive::MessageHeader
+  /// [Session Type]:   SessionType
+  /// [Reject.req]:     MessageContents::RejectRequest
+  /// [Lenient Decode]: Message::try_from_lenient
+  pub strict_decode: bool,
+
+  /// ### INBOUND CAPACITY
+  /// 
+  /// The number of Primary [Data Message]s which the hook provided by the
+  /// [Connect Procedure] holds before those received afterwards are handled
+  /// according to the [Inbound Overflow] parameter.
+  /// 
+  /// If [None], the hook holds any number of [Data Message]s, so that a
+  /// consumer which falls behind lets memory grow without limit.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Inbound Overflow]:  ParameterSettings::inbound_overflow
+  pub inbound_capacity: Option<usize>,
+
+  /// ### INBOUND OVERFLOW
+  /// 
+  /// The [Inbound Overflow] policy applied to a Primary [Data Message]
+  /// received while the hook provided by the [Connect Procedure] holds as
+  /// many as the [Inbound Capacity] parameter allows.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Data Message]:      MessageContents::DataMessage
+  /// [Inbound Overflow]:  InboundOverflow
+  /// [Inbound Capacity]:  ParameterSettings::inbound_capacity
+  pub inbound_overflow: InboundOverflow,
+}
+impl Default for ParameterSettings {
+  /// ### DEFAULT PARAMETER SETTINGS
+  /// **Based on SEMI E37-1109§10.2**
+  /// 
+  /// Provides [Parameter Settings] with these values, with timeouts as shown
+  /// in the 'typical values' column in Table 10.
+  /// 
+  /// - [Connect Mode] of [PASSIVE]
+  /// - [T3] of 45 seconds
+  /// - [T5] of 10 seconds
+  /// - [T6] of 5 seconds
+  /// - [T7] of 10 seconds
+  /// - [T8] of 5 seconds
+  /// - [Linktest Interval] of [None]
+  /// - [Idle Timeout] of [None]
+  /// - [Maximum Message Length] of 16 MiB
+  /// - [Resynchronize] of false
+  /// - [Auto Reject] of true
+  /// - [Backlog] of 128
+  /// - [Accept Timeout] of [None]
+  /// - [Keepalive] of [None]
+  /// - [Strict] of false
+  /// - [Strict Decode] of true
+  /// - [Inbound Capacity] of [None]
+  /// - [Inbound Overflow] of [BLOCK]
+  /// 
+  /// [Parameter Settings]:     ParameterSettings
+  /// [PASSIVE]:                ConnectionMode::Passive
+  /// [Connect Mode]:           ParameterSettings::connect_mode
+  /// [T3]:                     ParameterSettings::t3
+  /// [T5]:                     ParameterSettings::t5
+  /// [T6]:                     ParameterSettings::t6
+  /// [T7]:                     ParameterSettings::t7
+  /// [T8]:                     ParameterSettings::t8
+  /// [Linktest Interval]:      ParameterSettings::linktest_interval
+  /// [Idle Timeout]:           ParameterSettings::idle_timeout
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  /// [Resynchronize]:          ParameterSettings::resynchronize
+  /// [Auto Reject]:            ParameterSettings::auto_reject
+  /// [Backlog]:                ParameterSettings::backlog
+  /// [Accept Timeout]:         ParameterSettings::accept_timeout
+  /// [Keepalive]:              ParameterSettings::keepalive
+  /// [Strict]:                 ParameterSettings::strict
+  /// [Strict Decode]:          ParameterSettings::strict_decode
+  /// [Inbound Capacity]:       ParameterSettings::inbound_capacity
+  /// [Inbound Overflow]:       ParameterSettings::inbound_overflow
+  /// [BLOCK]:                  InboundOverflow::Block
+  fn default() -> Self {
+    Self {
+      connect_mode: ConnectionMode::default(),
+      t3: Duration::from_secs(45),
+      t5: Duration::from_secs(10),
+      t6: Duration::from_secs(5),
+      t7: Duration::from_secs(10),
+      t8: Duration::from_secs(5),
+      linktest_interval: None,
+      idle_timeout: None,
+      max_message_length: 0x0100_0000,
+      resynchronize: false,
+      auto_reject: true,
+      backlog: 128,
+      accept_timeout: None,
+      keepalive: None,
+      strict: false,
+      strict_decode: true,
+      inbound_capacity: None,
+      inbound_overflow: InboundOverflow::Block,
+    }
+  }
+}
+
+/// ## PARAMETER SETTINGS BUILDER
+/// **Based on SEMI E37-1109§10.2**
+/// 
+/// Builds [Parameter Settings] starting from the
+/// [Default Parameter Settings], validating them before they are provided to
+/// the [Client].
+/// 
+/// [Parameter Settings]:         ParameterSettings
+/// [Default Parameter Settings]: ParameterSettings::default
+/// [Client]:                     Client
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParameterSettingsBuilder {
+  parameter_settings: ParameterSettings,
+}
+impl ParameterSettingsBuilder {
+  /// ### NEW PARAMETER SETTINGS BUILDER
+  /// 
+  /// Creates a [Parameter Settings Builder] using the
+  /// [Default Parameter Settings].
+  /// 
+  /// [Parameter Settings Builder]: ParameterSettingsBuilder
+  /// [Default Parameter Settings]: ParameterSettings::default
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### ACTIVE
+  /// 
+  /// Creates a [Parameter Settings Builder] using the
+  /// [Default Parameter Settings], with a [Connect Mode] of [ACTIVE].
+  /// 
+  /// [Parameter Settings Builder]: ParameterSettingsBuilder
+  /// [Default Parameter Settings]: ParameterSettings::default
+  /// [Connect Mode]:               ParameterSettings::connect_mode
+  /// [ACTIVE]:                     ConnectionMode::Active
+  pub fn active() -> Self {
+    Self::new().connect_mode(ConnectionMode::Active)
+  }
+
+  /// ### PASSIVE
+  /// 
+  /// Creates a [Parameter Settings Builder] using the
+  /// [Default Parameter Settings], with a [Connect Mode] of [PASSIVE].
+  /// 
+  /// [Parameter Settings Builder]: ParameterSettingsBuilder
+  /// [Default Parameter Settings]: ParameterSettings::default
+  /// [Connect Mode]:               ParameterSettings::connect_mode
+  /// [PASSIVE]:                    ConnectionMode::Passive
+  pub fn passive() -> Self {
+    Self::new().connect_mode(ConnectionMode::Passive)
+  }
+
+  /// ### SET CONNECT MODE
+  /// 
+  /// Sets the [Connect Mode] parameter.
+  /// 
+  /// [Connect Mode]: ParameterSettings::connect_mode
+  pub fn connect_mode(mut self, connect_mode: ConnectionMode) -> Self {
+    self.parameter_settings.connect_mode = connect_mode;
+    self
+  }
+
+  /// ### SET T3
+  /// 
+  /// Sets the [T3] parameter.
+  /// 
+  /// [T3]: ParameterSettings::t3
+  pub fn t3(mut self, t3: Duration) -> Self {
+    self.parameter_settings.t3 = t3;
+    self
+  }
+
+  /// ### SET T5
+  /// 
+  /// Sets the [T5] parameter.
+  /// 
+  /// [T5]: ParameterSettings::t5
+  pub fn t5(mut self, t5: Duration) -> Self {
+    self.parameter_settings.t5 = t5;
+    self
+  }
+
+  /// ### SET T6
+  /// 
+  /// Sets the [T6] parameter.
+  /// 
+  /// [T6]: ParameterSettings::t6
+  pub fn t6(mut self, t6: Duration) -> Self {
+    self.parameter_settings.t6 = t6;
+    self
+  }
+
+  /// ### SET T7
+  /// 
+  /// Sets the [T7] parameter.
+  /// 
+  /// [T7]: ParameterSettings::t7
+  pub fn t7(mut self, t7: Duration) -> Self {
+    self.parameter_settings.t7 = t7;
+    self
+  }
+
+  /// ### SET T8
+  /// 
+  /// Sets the [T8] parameter.
+  /// 
+  /// [T8]: ParameterSettings::t8
+  pub fn t8(mut self, t8: Duration) -> Self {
+    self.parameter_settings.t8 = t8;
+    self
+  }
+
+  /// ### SET LINKTEST INTERVAL
+  /// 
+  /// Sets the [Linktest Interval] parameter.
+  /// 
+  /// [Linktest Interval]: ParameterSettings::linktest_interval
+  pub fn linktest_interval(mut self, linktest_interval: Option<Duration>) -> Self {
+    self.parameter_settings.linktest_interval = linktest_interval;
+    self
+  }
+
+  /// ### SET IDLE TIMEOUT
+  /// 
+  /// Sets the [Idle Timeout] parameter.
+  /// 
+  /// [Idle Timeout]: ParameterSettings::idle_timeout
+  pub fn idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+    self.parameter_settings.idle_timeout = idle_timeout;
+    self
+  }
+
+  /// ### SET MAXIMUM MESSAGE LENGTH
+  /// 
+  /// Sets the [Maximum Message Length] parameter.
+  /// 
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  pub fn max_message_length(mut self, max_message_length: u32) -> Self {
+    self.parameter_settings.max_message_length = max_message_length;
+    self
+  }
+
+  /// ### SET RESYNCHRONIZE
+  /// 
+  /// Sets the [Resynchronize] parameter.
+  /// 
+  /// [Resynchronize]: ParameterSettings::resynchronize
+  pub fn resynchronize(mut self, resynchronize: bool) -> Self {
+    self.parameter_settings.resynchronize = resynchronize;
+    self
+  }
+
+  /// ### SET AUTO REJECT
+  /// 
+  /// Sets the [Auto Reject] parameter.
+  /// 
+  /// [Auto Reject]: ParameterSettings::auto_reject
+  pub fn auto_reject(mut self, auto_reject: bool) -> Self {
+    self.parameter_settings.auto_reject = auto_reject;
+    self
+  }
+
+  /// ### SET BACKLOG
+  /// 
+  /// Sets the [Backlog] parameter.
+  /// 
+  /// [Backlog]: ParameterSettings::backlog
+  pub fn backlog(mut self, backlog: u32) -> Self {
+    self.parameter_settings.backlog = backlog;
+    self
+  }
+
+  /// ### SET ACCEPT TIMEOUT
+  /// 
+  /// Sets the [Accept Timeout] parameter.
+  /// 
+  /// [Accept Timeout]: ParameterSettings::accept_timeout
+  pub fn accept_timeout(mut self, accept_timeout: Option<Duration>) -> Self {
+    self.parameter_settings.accept_timeout = accept_timeout;
+    self
+  }
+
+  /// ### SET KEEPALIVE
+  /// 
+  /// Sets the [Keepalive] parameter.
+  /// 
+  /// [Keepalive]: ParameterSettings::keepalive
+  pub fn keepalive(mut self, keepalive: Option<Keepalive>) -> Self {
+    self.parameter_settings.keepalive = keepalive;
+    self
+  }
+
+  /// ### SET STRICT
+  /// 
+  /// Sets the [Strict] parameter.
+  /// 
+  /// [Strict]: ParameterSettings::strict
+  pub fn strict(mut self, strict: bool) -> Self {
+    self.parameter_settings.strict = strict;
+    self
+  }
+
+  /// ### SET STRICT DECODE
+  /// 
+  /// Sets the [Strict Decode] parameter.
+  /// 
+  /// [Strict Decode]: ParameterSettings::strict_decode
+  pub fn strict_decode(mut self, strict_decode: bool) -> Self {
+    self.parameter_settings.strict_decode = strict_decode;
+    self
+  }
+
+  /// ### SET INBOUND CAPACITY
+  /// 
+  /// Sets the [Inbound Capacity] parameter.
+  /// 
+  /// [Inbound Capacity]: ParameterSettings::inbound_capacity
+  pub fn inbound_capacity(mut self, inbound_capacity: Option<usize>) -> Self {
+    self.parameter_settings.inbound_capacity = inbound_capacity;
+    self
+  }
+
+  /// ### SET INBOUND OVERFLOW
+  /// 
+  /// Sets the [Inbound Overflow] parameter.
+  /// 
+  /// [Inbound Overflow]: ParameterSettings::inbound_overflow
+  pub fn inbound_overflow(mut self, inbound_overflow: InboundOverflow) -> Self {
+    self.parameter_settings.inbound_overflow = inbound_overflow;
+    self
+  }
+
+  /// ### BUILD
+  /// 
+  /// Provides the [Parameter Settings], or a [Parameter Error] if any of the
+  /// timeouts, the [Linktest Interval], the [Idle Timeout], the
+  /// [Accept Timeout], the idle time or interval of the [Keepalive], or the
+  /// [Inbound Capacity] is zero, or if the [Maximum Message Length] is too
+  /// short to hold a [Message Header].
+  /// 
+  /// [Parameter Settings]:     ParameterSettings
+  /// [Parameter Error]:        ParameterError
+  /// [Linktest Interval]:      ParameterSettings::linktest_interval
+  /// [Idle Timeout]:           ParameterSettings::idle_timeout
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  /// [Message Header]:         primitive::MessageHeader
+  /// [Accept Timeout]:         ParameterSettings::accept_timeout
+  /// [Keepalive]:              ParameterSettings::keepalive
+  /// [Inbound Capacity]:       ParameterSettings::inbound_capacity
+  pub fn build(self) -> Result<ParameterSettings, ParameterError> {
+    let parameter_settings = self.parameter_settings;
+    if parameter_settings.t3.is_zero() {return Err(ParameterError::T3)}
+    if parameter_settings.t5.is_zero() {return Err(ParameterError::T5)}
+    if parameter_settings.t6.is_zero() {return Err(ParameterError::T6)}
+    if parameter_settings.t7.is_zero() {return Err(ParameterError::T7)}
+    if parameter_settings.t8.is_zero() {return Err(ParameterError::T8)}
+    if let Some(linktest_interval) = parameter_settings.linktest_interval {
+      if linktest_interval.is_zero() {return Err(ParameterError::LinktestInterval)}
+    }
+    if let Some(idle_timeout) = parameter_settings.idle_timeout {
+      if idle_timeout.is_zero() {return Err(ParameterError::IdleTimeout)}
+    }
+    if parameter_settings.max_message_length < 10 {return Err(ParameterError::MaxMessageLength)}
+    if let Some(accept_timeout) = parameter_settings.accept_timeout {
+      if accept_timeout.is_zero() {return Err(ParameterError::AcceptTimeout)}
+    }
+    if let Some(keepalive) = parameter_settings.keepalive {
+      if keepalive.idle.is_zero() || keepalive.interval.is_zero() {return Err(ParameterError::Keepalive)}
+    }
+    if parameter_settings.inbound_capacity == Some(0) {return Err(ParameterError::InboundCapacity)}
+    Ok(parameter_settings)
+  }
+}
+
+/// ## PARAMETER ERROR
+/// 
+/// The parameter which caused the [Parameter Settings Builder] to reject the
+/// [Parameter Settings], due to having been provided an invalid value.
+/// 
+/// [Parameter Settings Builder]: ParameterSettingsBuilder
+/// [Parameter Settings]:         ParameterSettings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterError {
+  /// ### T3
+  /// 
+  /// The [T3] parameter was zero.
+  /// 
+  /// [T3]: ParameterSettings::t3
+  T3,
+
+  /// ### T5
+  /// 
+  /// The [T5] parameter was zero.
+  /// 
+  /// [T5]: ParameterSettings::t5
+  T5,
+
+  /// ### T6
+  /// 
+  /// The [T6] parameter was zero.
+  /// 
+  /// [T6]: ParameterSettings::t6
+  T6,
+
+  /// ### T7
+  /// 
+  /// The [T7] parameter was zero.
+  /// 
+  /// [T7]: ParameterSettings::t7
+  T7,
+
+  /// ### T8
+  /// 
+  /// The [T8] parameter was zero.
+  /// 
+  /// [T8]: ParameterSettings::t8
+  T8,
+
+  /// ### LINKTEST INTERVAL
+  /// 
+  /// The [Linktest Interval] parameter was zero.
+  /// 
+  /// [Linktest Interval]: ParameterSettings::linktest_interval
+  LinktestInterval,
+
+  /// ### IDLE TIMEOUT
+  /// 
+  /// The [Idle Timeout] parameter was zero.
+  /// 
+  /// [Idle Timeout]: ParameterSettings::idle_timeout
+  IdleTimeout,
+
+  /// ### MAXIMUM MESSAGE LENGTH
+  /// 
+  /// The [Maximum Message Length] parameter was less than the 10 bytes of a
+  /// [Message Header].
+  /// 
+  /// [Maximum Message Length]: ParameterSettings::max_message_length
+  /// [Message Header]:         primitive::MessageHeader
+  MaxMessageLength,
+
+  /// ### ACCEPT TIMEOUT
+  /// 
+  /// The [Accept Timeout] parameter was zero.
+  /// 
+  /// [Accept Timeout]: ParameterSettings::accept_timeout
+  AcceptTimeout,
+
+  /// ### KEEPALIVE
+  /// 
+  /// The idle time or interval of the [Keepalive] parameter was zero.
+  /// 
+  /// [Keepalive]: ParameterSettings::keepalive
+  Keepalive,
+
+  /// ### INBOUND CAPACITY
+  /// 
+  /// The [Inbound Capacity] parameter was zero.
+  /// 
+  /// [Inbound Capacity]: ParameterSettings::inbound_capacity
+  InboundCapacity,
+}
+impl Display for ParameterError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ParameterError::T3               => write!(f, "T3 must not be zero"),
+      ParameterError::T5               => write!(f, "T5 must not be zero"),
+      ParameterError::T6               => write!(f, "T6 must not be zero"),
+      ParameterError::T7               => write!(f, "T7 must not be zero"),
+      ParameterError::T8               => write!(f, "T8 must not be zero"),
+      ParameterError::LinktestInterval => write!(f, "linktest interval must not be zero"),
+      ParameterError::IdleTimeout      => write!(f, "idle timeout must not be zero"),
+      ParameterError::MaxMessageLength => write!(f, "maximum message length must be at least 10"),
+      ParameterError::AcceptTimeout    => write!(f, "accept timeout must not be zero"),
+      ParameterError::Keepalive        => write!(f, "keepalive idle time and interval must not be zero"),
+      ParameterError::InboundCapacity  => write!(f, "inbound capacity must not be zero"),
+    }
+  }
+}
+impl std::error::Error for ParameterError {}
+
+/// ## INBOUND OVERFLOW
+/// 
+/// What the [Client] does with a Primary [Data Message] received while the
+/// hook provided by the [Connect Procedure] is full, as set by the
+/// [Inbound Overflow] parameter.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Neither policy is free: blocking pushes back on the Remote Entity at the
+/// cost of stalling everything else received, and dropping keeps the
+/// connection responsive at the cost of the [Data Message]s dropped.
+/// 
+/// [Client]:            Client
+/// [Connect Procedure]: Client::connect
+/// [Data Message]:      MessageContents::DataMessage
+/// [Inbound Overflow]:  ParameterSettings::inbound_overflow
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InboundOverflow {
+  /// ### BLOCK
+  /// 
+  /// The [Client] stops receiving until the hook has room, so that the
+  /// Remote Entity is eventually prevented from transmitting by TCP/IP flow
+  /// control.
+  /// 
+  /// While blocked, nothing else is received either, including the replies
+  /// awaited by the [Client]'s own procedures and any [Linktest.req] from
+  /// the Remote Entity, so a consumer which stalls for longer than [T3] or
+  /// [T6], or long enough for the Remote Entity's own [T8] or [Linktest]
+  /// to elapse, will cause the connection to be lost.
+  /// 
+  /// [Client]:       Client
+  /// [Linktest.req]: MessageContents::LinktestRequest
+  /// [Linktest]:     ParameterSettings::linktest_interval
+  /// [T3]:           ParameterSettings::t3
+  /// [T6]:           ParameterSettings::t6
+  /// [T8]:           ParameterSettings::t8
+  #[default]
+  Block,
+
+  /// ### DROP
+  /// 
+  /// The [Data Message] is discarded and counted by the [Dropped Inbound]
+  /// function, and the [Client] carries on receiving.
+  /// 
+  /// No reply is transmitted for a dropped [Data Message], so if one was
+  /// expected, the Remote Entity's [T3] will eventually elapse.
+  /// 
+  /// [Client]:          Client
+  /// [Dropped Inbound]: Client::dropped_inbound
+  /// [Data Message]:    MessageContents::DataMessage
+  /// [T3]:              ParameterSettings::t3
+  Drop,
+}
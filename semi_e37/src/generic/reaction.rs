@@ -0,0 +1,379 @@
+//! # REACTION
+//! 
+//! Defines how a [Message] received while [CONNECTED] is reacted to, which
+//! is decided in the same manner by the [Generic Client] and the
+//! [Asynchronous Client] regardless of how each transmits and receives.
+//! 
+//! [Message]:             Message
+//! [CONNECTED]:           crate::primitive::ConnectionState::Connected
+//! [Generic Client]:      Client
+//! [Asynchronous Client]: crate::asynchronous::Client
+
+use super::*;
+
+/// ## SITUATION
+/// 
+/// The parts of a [Client]'s state consulted when deciding the [Reaction]
+/// to a received [Message].
+/// 
+/// [Client]:   Client
+/// [Reaction]: Reaction
+/// [Message]:  Message
+pub(crate) struct Situation {
+  /// ### SELECTION STATE
+  /// 
+  /// The [Selection State] at the time the [Message] was received.
+  /// 
+  /// [Selection State]: SelectionState
+  /// [Message]:         Message
+  pub selection_state: SelectionState,
+
+  /// ### SELECTED SESSION
+  /// 
+  /// Whether the [Session ID] of the [Message] is one of the sessions
+  /// currently selected.
+  /// 
+  /// [Session ID]: MessageID::session
+  /// [Message]:    Message
+  pub selected_session: bool,
+
+  /// ### SAME SESSION
+  /// 
+  /// Whether the [Session ID] of the [Message] is the one used by the most
+  /// recent [Select Procedure], whether or not it has completed.
+  /// 
+  /// [Session ID]:       MessageID::session
+  /// [Message]:          Message
+  /// [Select Procedure]: Client::select
+  pub same_session: bool,
+
+  /// ### BUSY
+  /// 
+  /// Whether the Local Entity's own [Select Procedure] or
+  /// [Deselect Procedure] is in progress, consulted only for a [Select.req]
+  /// or [Deselect.req].
+  /// 
+  /// [Select Procedure]:   Client::select
+  /// [Deselect Procedure]: Client::deselect
+  /// [Select.req]:         MessageContents::SelectRequest
+  /// [Deselect.req]:       MessageContents::DeselectRequest
+  pub busy: bool,
+
+  /// ### MULTI SESSION
+  /// 
+  /// Whether several sessions may be selected at once, in which case a
+  /// [Select.req] for another [Session ID] may be accepted while
+  /// [SELECTED].
+  /// 
+  /// [Select.req]: MessageContents::SelectRequest
+  /// [Session ID]: MessageID::session
+  /// [SELECTED]:   SelectionState::Selected
+  pub multi_session: bool,
+
+  /// ### RELEASABLE
+  /// 
+  /// Whether the session of the [Message] can be released while leaving
+  /// another session selected, which is only possible with
+  /// [Multi Session].
+  /// 
+  /// [Message]:       Message
+  /// [Multi Session]: Situation::multi_session
+  pub releasable: bool,
+}
+
+/// ## REACTION
+/// 
+/// The outcome of deciding how to react to a received [Message].
+/// 
+/// [Message]: Message
+pub(crate) enum Reaction {
+  /// ### DELIVER
+  /// 
+  /// The Primary [Data Message] is delivered to the Local Entity.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  Deliver(MessageID, semi_e5::Message),
+
+  /// ### COMPLETE
+  /// 
+  /// The reply completes the open transaction with the same [Message ID].
+  /// If there is none, the [Not Open] response is transmitted instead.
+  /// 
+  /// [Message ID]: MessageID
+  /// [Not Open]:   not_open
+  Complete(Message),
+
+  /// ### RESPOND
+  /// 
+  /// The response, if any, is transmitted, and the [Selection Change], if
+  /// any, is made. A change [Entering] a session is made only once the
+  /// response has been transmitted, and any other change is made before.
+  /// 
+  /// [Selection Change]: SelectionChange
+  /// [Entering]:         SelectionChange::is_entering
+  Respond(Option<Message>, Option<SelectionChange>),
+}
+
+/// ## SELECTION CHANGE
+/// 
+/// A change to the [Selection State], or to the set of selected sessions,
+/// made in reaction to a received [Message].
+/// 
+/// [Selection State]: SelectionState
+/// [Message]:         Message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SelectionChange {
+  /// ### SELECT
+  /// 
+  /// The [SELECTED] state is entered with the given [Session ID].
+  /// 
+  /// [SELECTED]:   SelectionState::Selected
+  /// [Session ID]: MessageID::session
+  Select(SessionID),
+
+  /// ### ADD SESSION
+  /// 
+  /// The given [Session ID] is selected alongside those already selected.
+  /// 
+  /// [Session ID]: MessageID::session
+  AddSession(SessionID),
+
+  /// ### RELEASE SESSION
+  /// 
+  /// The given [Session ID] is no longer selected, but another remains so.
+  /// 
+  /// [Session ID]: MessageID::session
+  ReleaseSession(SessionID),
+
+  /// ### DESELECT
+  /// 
+  /// The [NOT SELECTED] state is entered from the [SELECTED] state.
+  /// 
+  /// [NOT SELECTED]: SelectionState::NotSelected
+  /// [SELECTED]:     SelectionState::Selected
+  Deselect,
+
+  /// ### SEPARATE
+  /// 
+  /// The [NOT SELECTED] state is entered from any state.
+  /// 
+  /// [NOT SELECTED]: SelectionState::NotSelected
+  Separate,
+}
+impl SelectionChange {
+  /// ### IS ENTERING
+  /// 
+  /// Provides whether the change selects a session, and so must follow its
+  /// [Select.rsp] so that no [Data Message] can precede it.
+  /// 
+  /// [Select.rsp]:   MessageContents::SelectResponse
+  /// [Data Message]: MessageContents::DataMessage
+  pub(crate) fn is_entering(&self) -> bool {
+    matches!(self, SelectionChange::Select(_) | SelectionChange::AddSession(_))
+  }
+}
+
+/// ## REACT
+/// **Based on SEMI E37-1109§7**
+/// 
+/// Decides the [Reaction] to a received [Message] given the [Situation] of
+/// the [Client], consulting the [Select Policy] when a [Select.req] may be
+/// accepted.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// - [Data Message] - Delivered if Primary and completes a transaction if
+///   a reply, but is rejected with [Entity Not Selected] unless its session
+///   is selected.
+/// - [Select.req] - Answered by the [Select Policy] if [NOT SELECTED], or
+///   if [SELECTED] with another [Session ID] and [Multi Session]. While the
+///   Local Entity's own [Select Procedure] is in progress, it is answered
+///   with [Success] if it uses the same [Session ID] as a
+///   Simultaneous Select, and with [Not Ready] or [Already Active]
+///   otherwise.
+/// - [Deselect.req] - Answered with [Success] and leaves the session if it
+///   is selected, or with [Not Established] or [Busy] otherwise.
+/// - [Linktest.req] - Answered with a [Linktest.rsp].
+/// - [Separate.req] - Leaves the session.
+/// - [Select.rsp], [Deselect.rsp], [Linktest.rsp], [Reject.req] - Complete
+///   a transaction.
+/// 
+/// [Reaction]:             Reaction
+/// [Message]:              Message
+/// [Situation]:            Situation
+/// [Client]:               Client
+/// [Select Policy]:        SelectPolicy
+/// [Multi Session]:        Situation::multi_session
+/// [Select Procedure]:     Client::select
+/// [Session ID]:           MessageID::session
+/// [NOT SELECTED]:         SelectionState::NotSelected
+/// [SELECTED]:             SelectionState::Selected
+/// [Data Message]:         MessageContents::DataMessage
+/// [Select.req]:           MessageContents::SelectRequest
+/// [Select.rsp]:           MessageContents::SelectResponse
+/// [Deselect.req]:         MessageContents::DeselectRequest
+/// [Deselect.rsp]:         MessageContents::DeselectResponse
+/// [Linktest.req]:         MessageContents::LinktestRequest
+/// [Linktest.rsp]:         MessageContents::LinktestResponse
+/// [Reject.req]:           MessageContents::RejectRequest
+/// [Separate.req]:         MessageContents::SeparateRequest
+/// [Entity Not Selected]:  RejectReason::EntityNotSelected
+/// [Success]:              SelectStatus::Success
+/// [Not Ready]:            SelectStatus::NotReady
+/// [Already Active]:       SelectStatus::AlreadyActive
+/// [Not Established]:      DeselectStatus::NotEstablished
+/// [Busy]:                 DeselectStatus::Busy
+pub(crate) fn react(
+  message: Message,
+  situation: &Situation,
+  select_policy: &SelectPolicy,
+) -> Reaction {
+  let id: MessageID = message.id;
+  match message.contents {
+    // RX: Data Message
+    MessageContents::DataMessage(data) => match situation.selection_state {
+      // IS: SELECTED, DESELECT INITIATED
+      SelectionState::Selected | SelectionState::DeselectInitiated if situation.selected_session => {
+        // RX: Primary Data Message
+        if data.is_primary() {
+          Reaction::Deliver(id, data)
+        }
+        // RX: Response Data Message
+        else {
+          Reaction::Complete(Message {
+            id,
+            contents: MessageContents::DataMessage(data),
+          })
+        }
+      },
+      // IS: NOT SELECTED, Other Session ID
+      _ => Reaction::Respond(Some(Message {
+        id,
+        contents: MessageContents::RejectRequest(SessionType::DataMessage as u8, RejectReason::EntityNotSelected as u8),
+      }), None),
+    },
+    // RX: Select.req
+    MessageContents::SelectRequest => {
+      let (select_status, change) = match situation.selection_state {
+        // IS: NOT SELECTED
+        SelectionState::NotSelected if !situation.busy => {
+          // POLICY: Decide Select Status
+          let select_status: SelectStatus = select_policy(id.session);
+          (select_status, Some(SelectionChange::Select(id.session)))
+        },
+        // IS: SELECTED, Other Session ID
+        SelectionState::Selected if situation.multi_session && !situation.selected_session && !situation.busy => {
+          // POLICY: Decide Select Status
+          let select_status: SelectStatus = select_policy(id.session);
+          (select_status, Some(SelectionChange::AddSession(id.session)))
+        },
+        // IS: SELECT INITIATED, Simultaneous Select
+        // The Select Procedure completes once its own Select.rsp is received.
+        SelectionState::SelectInitiated if situation.same_session => (SelectStatus::Success, None),
+        // IS: SELECTED, Other Session ID, BUSY
+        SelectionState::Selected if situation.multi_session && !situation.selected_session => (SelectStatus::NotReady, None),
+        // IS: BUSY
+        SelectionState::NotSelected => (SelectStatus::NotReady, None),
+        // IS: SELECT INITIATED, Other Session, SELECTED, DESELECT INITIATED
+        SelectionState::SelectInitiated
+        | SelectionState::Selected
+        | SelectionState::DeselectInitiated => (SelectStatus::AlreadyActive, None),
+      };
+      // TX: Select.rsp
+      Reaction::Respond(Some(Message {
+        id,
+        contents: MessageContents::SelectResponse(select_status as u8),
+      }), change.filter(|_| select_status == SelectStatus::Success))
+    },
+    // RX: Deselect.req
+    MessageContents::DeselectRequest => {
+      let (deselect_status, change) = match situation.selection_state {
+        // IS: SELECTED, Other Sessions Remain
+        SelectionState::Selected if !situation.busy && situation.releasable => {
+          (DeselectStatus::Success, Some(SelectionChange::ReleaseSession(id.session)))
+        },
+        // IS: SELECTED
+        SelectionState::Selected if !situation.busy && situation.same_session => {
+          (DeselectStatus::Success, Some(SelectionChange::Deselect))
+        },
+        // IS: DESELECT INITIATED, Simultaneous Deselect
+        SelectionState::DeselectInitiated if situation.busy && situation.same_session => (DeselectStatus::Success, None),
+        // IS: BUSY
+        _ if situation.busy && situation.selection_state != SelectionState::DeselectInitiated => (DeselectStatus::Busy, None),
+        // IS: NOT SELECTED, Other Session ID
+        _ => (DeselectStatus::NotEstablished, None),
+      };
+      // TX: Deselect.rsp
+      Reaction::Respond(Some(Message {
+        id,
+        contents: MessageContents::DeselectResponse(deselect_status as u8),
+      }), change)
+    },
+    // RX: Linktest.req
+    MessageContents::LinktestRequest => Reaction::Respond(Some(Message {
+      id,
+      contents: MessageContents::LinktestResponse,
+    }), None),
+    // RX: Separate.req
+    MessageContents::SeparateRequest => {
+      // IS: SELECTED, Other Sessions Remain
+      if situation.selection_state == SelectionState::Selected && situation.releasable {
+        Reaction::Respond(None, Some(SelectionChange::ReleaseSession(id.session)))
+      } else {
+        Reaction::Respond(None, Some(SelectionChange::Separate))
+      }
+    },
+    // RX: Select.rsp, Deselect.rsp, Linktest.rsp, Reject.req
+    contents @ (MessageContents::SelectResponse(_)
+    | MessageContents::DeselectResponse(_)
+    | MessageContents::LinktestResponse
+    | MessageContents::RejectRequest(..)) => Reaction::Complete(Message {id, contents}),
+  }
+}
+
+/// ## NOT OPEN
+/// 
+/// Provides the [Reject.req] to transmit when a reply completes no open
+/// transaction, or [None] if the reply is itself a [Reject.req], which is
+/// ignored rather than answered.
+/// 
+/// [Reject.req]: MessageContents::RejectRequest
+pub(crate) fn not_open(
+  reply: &Message,
+) -> Option<Message> {
+  let session_type: SessionType = match reply.contents {
+    MessageContents::DataMessage(_)      => SessionType::DataMessage,
+    MessageContents::SelectResponse(_)   => SessionType::SelectResponse,
+    MessageContents::DeselectResponse(_) => SessionType::DeselectResponse,
+    MessageContents::LinktestResponse    => SessionType::LinktestResponse,
+    _ => return None,
+  };
+  Some(Message {
+    id: reply.id,
+    contents: MessageContents::RejectRequest(session_type as u8, RejectReason::TransactionNotOpen as u8),
+  })
+}
+
+/// ## MALFORMED
+/// 
+/// Provides the [Reject.req] to transmit in response to a [Message] which
+/// could not be decoded for the given [Reject Reason].
+/// 
+/// [Reject.req]:    MessageContents::RejectRequest
+/// [Message]:       primitive::Message
+/// [Reject Reason]: RejectReason
+pub(crate) fn malformed(
+  header: primitive::MessageHeader,
+  reject_reason: RejectReason,
+) -> Message {
+  Message {
+    id: MessageID {
+      session: header.session_id.into(),
+      system: header.system,
+    },
+    contents: MessageContents::RejectRequest(match reject_reason {
+      RejectReason::UnsupportedPresentationType => header.presentation_type,
+      _ => header.session_type,
+    }, reject_reason as u8),
+  }
+}
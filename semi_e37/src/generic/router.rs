@@ -0,0 +1,193 @@
+//! # ROUTER
+//! 
+//! Defines the [Message Router] used by the [Serve Procedure] to answer
+//! Primary [Data Message]s according to their [Stream] and [Function].
+//! 
+//! [Message Router]:  MessageRouter
+//! [Serve Procedure]: Client::serve
+//! [Data Message]:    MessageContents::DataMessage
+//! [Stream]:          semi_e5::Message::stream
+//! [Function]:        semi_e5::Message::function
+
+use super::*;
+use semi_e5::messages::s9;
+
+/// ## HANDLER
+/// 
+/// A callback registered with the [Message Router], which is invoked with a
+/// Primary [Data Message] and may provide a Response [Data Message].
+/// 
+/// [Message Router]: MessageRouter
+/// [Data Message]:   MessageContents::DataMessage
+pub type Handler = Box<dyn Fn(semi_e5::Message) -> Option<semi_e5::Message> + Send + Sync>;
+
+/// ## MESSAGE ROUTER
+/// 
+/// A table of [Handler]s keyed by [Stream] and [Function], used by the
+/// [Serve Procedure] to answer Primary [Data Message]s.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// A Primary [Data Message] whose [Stream] and [Function] have no [Handler]
+/// is answered according to the [Unhandled] action, which by default is to
+/// ignore it.
+/// 
+/// [Handler]:         Handler
+/// [Unhandled]:       Unhandled
+/// [Serve Procedure]: Client::serve
+/// [Data Message]:    MessageContents::DataMessage
+/// [Stream]:          semi_e5::Message::stream
+/// [Function]:        semi_e5::Message::function
+#[derive(Default)]
+pub struct MessageRouter {
+  handlers: HashMap<(u8, u8), Handler>,
+  unhandled: Unhandled,
+}
+impl MessageRouter {
+  /// ### NEW MESSAGE ROUTER
+  /// 
+  /// Creates a [Message Router] with no [Handler]s, which ignores every
+  /// Primary [Data Message].
+  /// 
+  /// [Message Router]: MessageRouter
+  /// [Handler]:        Handler
+  /// [Data Message]:   MessageContents::DataMessage
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### ROUTE
+  /// 
+  /// Registers a [Handler] for Primary [Data Message]s with the provided
+  /// [Stream] and [Function], replacing any [Handler] previously registered
+  /// for them.
+  /// 
+  /// [Handler]:      Handler
+  /// [Data Message]: MessageContents::DataMessage
+  /// [Stream]:       semi_e5::Message::stream
+  /// [Function]:     semi_e5::Message::function
+  pub fn route(
+    mut self,
+    stream: u8,
+    function: u8,
+    handler: impl Fn(semi_e5::Message) -> Option<semi_e5::Message> + Send + Sync + 'static,
+  ) -> Self {
+    self.handlers.insert((stream, function), Box::new(handler));
+    self
+  }
+
+  /// ### UNHANDLED
+  /// 
+  /// Sets the [Unhandled] action taken when a Primary [Data Message] has no
+  /// [Handler].
+  /// 
+  /// [Unhandled]:    Unhandled
+  /// [Handler]:      Handler
+  /// [Data Message]: MessageContents::DataMessage
+  pub fn unhandled(mut self, unhandled: Unhandled) -> Self {
+    self.unhandled = unhandled;
+    self
+  }
+
+  /// ### HANDLE
+  /// 
+  /// Invokes the [Handler] for a Primary [Data Message], or takes the
+  /// [Unhandled] action if there is none, and provides the resulting
+  /// [Routing].
+  /// 
+  /// [Handler]:      Handler
+  /// [Unhandled]:    Unhandled
+  /// [Routing]:      Routing
+  /// [Data Message]: MessageContents::DataMessage
+  pub(crate) fn handle(&self, message: semi_e5::Message) -> Routing {
+    match self.handlers.get(&(message.stream, message.function)) {
+      Some(handler) => match handler(message) {
+        Some(reply) => Routing::Reply(reply),
+        None => Routing::Ignore,
+      },
+      None => match self.unhandled {
+        Unhandled::Ignore => Routing::Ignore,
+        Unhandled::Abort  => Routing::Reply(semi_e5::Message::header_only(message.stream, 0, false)),
+        Unhandled::Error  => {
+          if self.handlers.keys().any(|(stream, _)| *stream == message.stream) {
+            Routing::Error(|header| s9::UnrecognizedFunctionType(header).into())
+          } else {
+            Routing::Error(|header| s9::UnrecognizedStreamType(header).into())
+          }
+        },
+      },
+    }
+  }
+}
+
+/// ## ROUTING
+/// 
+/// The outcome of the [Message Router] handling a Primary [Data Message].
+/// 
+/// [Message Router]: MessageRouter
+/// [Data Message]:   MessageContents::DataMessage
+pub(crate) enum Routing {
+  /// ### REPLY
+  /// 
+  /// The Response [Data Message] to transmit, if a reply was requested.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  Reply(semi_e5::Message),
+
+  /// ### IGNORE
+  /// 
+  /// Nothing is transmitted.
+  Ignore,
+
+  /// ### ERROR
+  /// 
+  /// A Stream 9 [Data Message] is transmitted as a new Primary
+  /// [Data Message], built from the [MHEAD] of the offending one.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  /// [MHEAD]:        semi_e5::items::MessageHeader
+  Error(fn(semi_e5::items::MessageHeader) -> semi_e5::Message),
+}
+
+/// ## UNHANDLED
+/// 
+/// The action taken by the [Message Router] when a Primary [Data Message]
+/// has no [Handler].
+/// 
+/// [Message Router]: MessageRouter
+/// [Handler]:        Handler
+/// [Data Message]:   MessageContents::DataMessage
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Unhandled {
+  /// ### IGNORE
+  /// 
+  /// No Response [Data Message] is transmitted. If a reply was requested,
+  /// the Remote Entity will eventually time out waiting for it.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  #[default]
+  Ignore,
+
+  /// ### ABORT
+  /// 
+  /// If a reply was requested, the Response [Data Message] is the
+  /// header-only Function 0 of the same [Stream], aborting the transaction.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  /// [Stream]:       semi_e5::Message::stream
+  Abort,
+
+  /// ### ERROR
+  /// 
+  /// An [S9F3] is transmitted if no [Handler] is registered for the
+  /// [Stream], and an [S9F5] is transmitted otherwise, each carrying the
+  /// header of the Primary [Data Message]. No Response [Data Message] is
+  /// transmitted, as the Stream 9 [Data Message] takes its place.
+  /// 
+  /// [S9F3]:         semi_e5::messages::s9::UnrecognizedStreamType
+  /// [S9F5]:         semi_e5::messages::s9::UnrecognizedFunctionType
+  /// [Handler]:      Handler
+  /// [Data Message]: MessageContents::DataMessage
+  /// [Stream]:       semi_e5::Message::stream
+  Error,
+}
@@ -0,0 +1,173 @@
+//! # STATE
+//! 
+//! Defines the [Selection State] and the [Connection State Transition]s
+//! reported by the [Client].
+//! 
+//! [Client]:                      Client
+//! [Selection State]:             SelectionState
+//! [Connection State Transition]: ConnectionStateTransition
+
+use super::*;
+
+/// ## SELECTION STATE
+/// **Based on SEMI E37-1109§5.5.2**
+/// 
+/// The [CONNECTED] state has two substates, [NOT SELECTED] and [SELECTED].
+/// 
+/// The [Client] moves between them based on whether it has established
+/// a session with another entity according to the [Select Procedure],
+/// [Deselect Procedure], and [Separate Procedure].
+/// 
+/// The [NOT SELECTED] state is further distinguished by [SELECT INITIATED]
+/// while the [Client] awaits the completion of its own [Select Procedure],
+/// and the [SELECTED] state is further distinguished by
+/// [DESELECT INITIATED] while the [Client] awaits the completion of its own
+/// [Deselect Procedure].
+/// 
+/// [CONNECTED]:          primitive::ConnectionState::Connected
+/// [NOT SELECTED]:       SelectionState::NotSelected
+/// [SELECTED]:           SelectionState::Selected
+/// [SELECT INITIATED]:   SelectionState::SelectInitiated
+/// [DESELECT INITIATED]: SelectionState::DeselectInitiated
+/// [Client]:             Client
+/// [Select Procedure]:   Client::select
+/// [Deselect Procedure]: Client::deselect
+/// [Separate Procedure]: Client::separate
+#[derive(Clone, Copy, Debug, PartialEq, NoUninit)]
+#[repr(u8)]
+pub enum SelectionState {
+  /// ### NOT SELECTED
+  /// **Based on SEMI E37-1109§5.5.2.1**
+  /// 
+  /// In this state, the [Client] is ready to initiate the [Select Procedure]
+  /// but has either not yet done so, or has terminated a previous session.
+  /// 
+  /// [Client]:           Client
+  /// [Select Procedure]: Client::select
+  NotSelected,
+
+  /// ### SELECTED
+  /// **Based on SEMI E37-1109§5.5.2.2**
+  /// 
+  /// In this state, the [Client] has successfully initiated the
+  /// [Select Procedure] and is able to send and receive [Data Message]s.
+  /// 
+  /// With the `multi_session` feature enabled, this state is kept while any
+  /// of several sessions remains selected.
+  /// 
+  /// [Client]:           Client
+  /// [Select Procedure]: Client::select
+  /// [Data Message]:     MessageContents::DataMessage
+  Selected,
+
+  /// ### SELECT INITIATED
+  /// **Based on SEMI E37-1109§7.4.3**
+  /// 
+  /// In this state, the [Client] remains [NOT SELECTED] but has initiated
+  /// the [Select Procedure] and is waiting for the [Select.rsp].
+  /// 
+  /// A [Select.req] received in this state for the same [Session ID] is
+  /// accepted as a Simultaneous Select, but the [SELECTED] state is only
+  /// entered once the [Select.rsp] is received. One for any other
+  /// [Session ID] is refused.
+  /// 
+  /// [Client]:           Client
+  /// [NOT SELECTED]:     SelectionState::NotSelected
+  /// [SELECTED]:         SelectionState::Selected
+  /// [Select Procedure]: Client::select
+  /// [Select.req]:       MessageContents::SelectRequest
+  /// [Select.rsp]:       MessageContents::SelectResponse
+  /// [Session ID]:       MessageID::session
+  SelectInitiated,
+
+  /// ### DESELECT INITIATED
+  /// **Based on SEMI E37-1109§7.7.3**
+  /// 
+  /// In this state, the [Client] remains [SELECTED] but has initiated the
+  /// [Deselect Procedure] and is waiting for the [Deselect.rsp].
+  /// 
+  /// A [Deselect.req] received in this state for the same [Session ID] is
+  /// accepted as a Simultaneous Deselect, and one for any other
+  /// [Session ID] is refused.
+  /// 
+  /// [Client]:             Client
+  /// [SELECTED]:           SelectionState::Selected
+  /// [Deselect Procedure]: Client::deselect
+  /// [Deselect.req]:       MessageContents::DeselectRequest
+  /// [Deselect.rsp]:       MessageContents::DeselectResponse
+  /// [Session ID]:         MessageID::session
+  DeselectInitiated,
+}
+impl Default for SelectionState {
+  /// ### DEFAULT SELECTION STATE
+  /// **Based on SEMI E37-1109§5.4**
+  /// 
+  /// Provides the [NOT SELECTED] state by default.
+  /// 
+  /// [NOT SELECTED]: SelectionState::NotSelected
+  fn default() -> Self {
+    SelectionState::NotSelected
+  }
+}
+
+/// ## CONNECTION STATE TRANSITION
+/// 
+/// A movement of the [Client] between the [Connection State]s and
+/// [Selection State]s, as provided to the hook returned by the
+/// [Subscribe Transitions] function.
+/// 
+/// [Client]:                Client
+/// [Subscribe Transitions]: Client::subscribe_transitions
+/// [Connection State]:      primitive::ConnectionState
+/// [Selection State]:       SelectionState
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionStateTransition {
+  /// ### NOT CONNECTED TO NOT SELECTED
+  /// 
+  /// The [Connect Procedure] has completed, entering the [CONNECTED] and
+  /// [NOT SELECTED] states.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [CONNECTED]:         primitive::ConnectionState::Connected
+  /// [NOT SELECTED]:      SelectionState::NotSelected
+  NotConnectedToNotSelected,
+
+  /// ### NOT SELECTED TO SELECTED
+  /// 
+  /// The [Select Procedure] has completed, entering the [SELECTED] state.
+  /// 
+  /// [Select Procedure]: Client::select
+  /// [SELECTED]:         SelectionState::Selected
+  NotSelectedToSelected,
+
+  /// ### SELECTED TO NOT SELECTED
+  /// 
+  /// The [Deselect Procedure] or [Separate Procedure] has completed,
+  /// entering the [NOT SELECTED] state.
+  /// 
+  /// [Deselect Procedure]: Client::deselect
+  /// [Separate Procedure]: Client::separate
+  /// [NOT SELECTED]:       SelectionState::NotSelected
+  SelectedToNotSelected,
+
+  /// ### CONNECTED TO NOT CONNECTED
+  /// 
+  /// The [Disconnect Procedure] has completed, whether initiated locally or
+  /// due to a communications failure, entering the [NOT CONNECTED] state.
+  /// Which of these occurred is provided by the [Disconnect Reason] function.
+  /// 
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [Disconnect Reason]:    Client::disconnect_reason
+  /// [NOT CONNECTED]:        primitive::ConnectionState::NotConnected
+  ConnectedToNotConnected,
+}
+impl Display for ConnectionStateTransition {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ConnectionStateTransition::NotConnectedToNotSelected => write!(f, "connected, not selected"),
+      ConnectionStateTransition::NotSelectedToSelected     => write!(f, "selected"),
+      ConnectionStateTransition::SelectedToNotSelected     => write!(f, "no longer selected"),
+      ConnectionStateTransition::ConnectedToNotConnected   => write!(f, "disconnected"),
+    }
+  }
+}
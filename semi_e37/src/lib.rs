@@ -48,22 +48,35 @@
 //! has been divided into a few subsets: 
 //! 
 //! - [Primitive Services] - Manages the TCP/IP connection and the sending of
-//!   messages with proper headers.
+//!   messages with proper headers, optionally encrypted with TLS when the
+//!   `tls` feature is enabled.
 //! - [Generic Services] - Manages the sending of messages of particular types
-//!   and at particular times as allowed by the protocol. 
+//!   and at particular times as allowed by the protocol. Message IDs may be
+//!   serialized along with [SECS-II] messages when the `serde` feature is
+//!   enabled, and several sessions may be selected at once over one
+//!   connection when the `multi_session` feature is enabled.
 //! - Single Selected Session Services - Manages the restriction of the
 //!   protocol to scenarios involving a single host/equipment pair in
 //!   communication.
 //!    - Not yet implemented.
+//! - [Asynchronous Services] - Provides the [Generic Services] to
+//!   asynchronous applications, enabled by the `tokio` feature. TLS is not
+//!   yet supported by these services.
+//! - [Role Services] - Wraps the [Generic Services] with the conventions of
+//!   the host and equipment roles described by [SEMI E30], such as which
+//!   side connects, selects, and establishes communications.
+//! - [Testing Services] - Provides loopback utilities for testing
+//!   applications, enabled by the `testing` feature.
+//! - [Pcap Export] - Records exchanged messages in the pcap capture format
+//!   for inspection with Wireshark.
+//! - [Frame Recording] - Records received messages exactly as they were
+//!   framed, so that they can be replayed against the decoder.
 //! 
 //! ---------------------------------------------------------------------------
 //! 
 //! ## TODO
 //! 
-//! - [Generic Services] - "Deselect Procedure"
 //! - [Generic Services] - "Reject Procedure"
-//! - [Generic Services] - "Simultaneous Select Procedure"
-//! - [Generic Services] - "Simultaneous Deselect Procedure"
 //! - Single Selected Session Services
 //! 
 //! [SEMI E4]:  https://store-us.semi.org/products/e00400-semi-e4-specification-for-semi-equipment-communications-standard-1-message-transfer-secs-i
@@ -71,12 +84,24 @@
 //! [SEMI E30]: https://store-us.semi.org/products/e03000-semi-e30-specification-for-the-generic-model-for-communications-and-control-of-manufacturing-equipment-gem
 //! [SEMI E37]: https://store-us.semi.org/products/e03700-semi-e37-high-speed-secs-message-services-hsms-generic-services
 //! 
-//! [SECS-II]:            semi_e5
-//! [Primitive Services]: primitive
-//! [Generic Services]:   generic
+//! [SECS-II]:               semi_e5
+//! [Primitive Services]:    primitive
+//! [Generic Services]:      generic
+//! [Asynchronous Services]: asynchronous
+//! [Role Services]:         role
+//! [Testing Services]:      testing
+//! [Pcap Export]:           pcap
+//! [Frame Recording]:       record
 
 pub mod primitive;
 pub mod generic;
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+pub mod role;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod pcap;
+pub mod record;
 
 /// ## PRESENTATION TYPE
 /// **Based on SEMI E37-1109§8.2.6.4**
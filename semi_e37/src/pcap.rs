@@ -0,0 +1,213 @@
+//! # PCAP EXPORT
+//! 
+//! Provides a means of recording [Message]s in the pcap capture format, so
+//! that they may be inspected with Wireshark's HSMS dissector when debugging
+//! interoperability with a Remote Entity.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Each [Message] is recorded exactly as it is framed on the wire, with its
+//! Message Length, [Message Header], and [Message Text], as the payload of a
+//! synthetic TCP/IPv4 segment. The Local Entity is given the address
+//! `127.0.0.1` and an ephemeral port, and the Remote Entity is given the
+//! address `127.0.0.2` and the port provided to the [Pcap Writer], which
+//! should be the port the HSMS dissector is configured to decode.
+//! 
+//! Timestamps are taken from the system clock when each [Message] is written,
+//! and TCP sequence numbers are continuous in each [Direction], but no
+//! handshake or acknowledgement segments are recorded.
+//! 
+//! To record every [Message] exchanged by a [Client], use the
+//! [Trace to Pcap] function.
+//! 
+//! [Message]:        Message
+//! [Message Header]: crate::primitive::MessageHeader
+//! [Message Text]:   Message::text
+//! [Direction]:      Direction
+//! [Client]:         crate::primitive::Client
+//! [Pcap Writer]:    PcapWriter
+//! [Trace to Pcap]:  crate::primitive::Client::trace_to_pcap
+
+use std::{
+  io::{
+    Error,
+    Write,
+  },
+  net::Ipv4Addr,
+  time::{
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+use crate::primitive::{
+  Client,
+  Direction,
+  Message,
+};
+
+/// ## LOCAL ADDRESS
+/// 
+/// The synthetic address recorded for the Local Entity.
+const LOCAL_ADDRESS: Ipv4Addr = Ipv4Addr::new(127, 0, 0, 1);
+
+/// ## LOCAL PORT
+/// 
+/// The synthetic port recorded for the Local Entity.
+const LOCAL_PORT: u16 = 49152;
+
+/// ## REMOTE ADDRESS
+/// 
+/// The synthetic address recorded for the Remote Entity.
+const REMOTE_ADDRESS: Ipv4Addr = Ipv4Addr::new(127, 0, 0, 2);
+
+/// ## MAXIMUM SEGMENT LENGTH
+/// 
+/// The largest TCP payload which fits in a single IPv4 packet alongside the
+/// IPv4 and TCP headers. Longer frames are split across several segments.
+const MAX_SEGMENT_LENGTH: usize = u16::MAX as usize - 40;
+
+/// ## PCAP WRITER
+/// 
+/// Writes [Message]s to an underlying writer in the pcap capture format.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The pcap global header is written when the [Pcap Writer] is created, and
+/// each [Message] is written as one or more records when provided to the
+/// [Write Message] function.
+/// 
+/// [Message]:       Message
+/// [Pcap Writer]:   PcapWriter
+/// [Write Message]: PcapWriter::write_message
+#[derive(Debug)]
+pub struct PcapWriter<W: Write> {
+  writer: W,
+  port: u16,
+  rx_sequence: u32,
+  tx_sequence: u32,
+}
+impl<W: Write> PcapWriter<W> {
+  /// ### NEW PCAP WRITER
+  /// 
+  /// Creates a [Pcap Writer] which records the Remote Entity with the
+  /// provided port, writing the pcap global header immediately.
+  /// 
+  /// [Pcap Writer]: PcapWriter
+  pub fn new(mut writer: W, port: u16) -> Result<Self, Error> {
+    // Magic Number
+    writer.write_all(&0xA1B2C3D4u32.to_le_bytes())?;
+    // Version 2.4
+    writer.write_all(&2u16.to_le_bytes())?;
+    writer.write_all(&4u16.to_le_bytes())?;
+    // Time Zone, Timestamp Accuracy
+    writer.write_all(&0i32.to_le_bytes())?;
+    writer.write_all(&0u32.to_le_bytes())?;
+    // Snapshot Length
+    writer.write_all(&(u16::MAX as u32).to_le_bytes())?;
+    // Link Type: Raw IP
+    writer.write_all(&101u32.to_le_bytes())?;
+    Ok(Self {
+      writer,
+      port,
+      rx_sequence: 1,
+      tx_sequence: 1,
+    })
+  }
+
+  /// ### WRITE MESSAGE
+  /// 
+  /// Records a [Message] as it would be framed on the wire, travelling in
+  /// the provided [Direction].
+  /// 
+  /// [Message]:   Message
+  /// [Direction]: Direction
+  pub fn write_message(
+    &mut self,
+    direction: Direction,
+    message: &Message,
+  ) -> Result<(), Error> {
+    let mut frame: Vec<u8> = vec![];
+    Client::tx(&mut frame, message)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    for segment in frame.chunks(MAX_SEGMENT_LENGTH) {
+      let packet: Vec<u8> = self.packet(direction, segment);
+      // Record Header
+      self.writer.write_all(&(timestamp.as_secs() as u32).to_le_bytes())?;
+      self.writer.write_all(&timestamp.subsec_micros().to_le_bytes())?;
+      self.writer.write_all(&(packet.len() as u32).to_le_bytes())?;
+      self.writer.write_all(&(packet.len() as u32).to_le_bytes())?;
+      // Record Data
+      self.writer.write_all(&packet)?;
+    }
+    self.writer.flush()
+  }
+
+  /// ### INTO INNER
+  /// 
+  /// Consumes the [Pcap Writer], returning the underlying writer.
+  /// 
+  /// [Pcap Writer]: PcapWriter
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+
+  /// ### PACKET
+  /// 
+  /// Wraps a TCP payload in TCP and IPv4 headers appropriate for its
+  /// [Direction], advancing the sequence number of that [Direction].
+  /// 
+  /// [Direction]: Direction
+  fn packet(
+    &mut self,
+    direction: Direction,
+    payload: &[u8],
+  ) -> Vec<u8> {
+    let (source, source_port, destination, destination_port, sequence, acknowledgement) = match direction {
+      Direction::Receive  => (REMOTE_ADDRESS, self.port, LOCAL_ADDRESS, LOCAL_PORT, &mut self.rx_sequence, self.tx_sequence),
+      Direction::Transmit => (LOCAL_ADDRESS, LOCAL_PORT, REMOTE_ADDRESS, self.port, &mut self.tx_sequence, self.rx_sequence),
+    };
+    let total_length: u16 = (40 + payload.len()) as u16;
+    let mut packet: Vec<u8> = Vec::with_capacity(total_length as usize);
+    // IPv4 Header [Bytes 0-19]
+    packet.extend_from_slice(&[0x45, 0x00]);
+    packet.extend_from_slice(&total_length.to_be_bytes());
+    packet.extend_from_slice(&[0x00, 0x00, 0x40, 0x00, 64, 6, 0x00, 0x00]);
+    packet.extend_from_slice(&source.octets());
+    packet.extend_from_slice(&destination.octets());
+    let ip_checksum: u16 = checksum(0, &packet[..20]);
+    packet[10..12].copy_from_slice(&ip_checksum.to_be_bytes());
+    // TCP Header [Bytes 20-39]
+    packet.extend_from_slice(&source_port.to_be_bytes());
+    packet.extend_from_slice(&destination_port.to_be_bytes());
+    packet.extend_from_slice(&sequence.to_be_bytes());
+    packet.extend_from_slice(&acknowledgement.to_be_bytes());
+    packet.extend_from_slice(&[0x50, 0x18, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00]);
+    // TCP Payload [Bytes 40+]
+    packet.extend_from_slice(payload);
+    // TCP Checksum: Includes the IPv4 pseudo-header.
+    let mut pseudo_header: u32 = 6 + (total_length as u32 - 20);
+    for address in [source, destination] {
+      let octets: [u8;4] = address.octets();
+      pseudo_header += u16::from_be_bytes([octets[0], octets[1]]) as u32;
+      pseudo_header += u16::from_be_bytes([octets[2], octets[3]]) as u32;
+    }
+    let tcp_checksum: u16 = checksum(pseudo_header, &packet[20..]);
+    packet[36..38].copy_from_slice(&tcp_checksum.to_be_bytes());
+    *sequence = sequence.wrapping_add(payload.len() as u32);
+    packet
+  }
+}
+
+/// ## CHECKSUM
+/// 
+/// Computes the Internet checksum of the provided bytes, beginning from a
+/// partial sum.
+fn checksum(mut sum: u32, bytes: &[u8]) -> u16 {
+  for chunk in bytes.chunks(2) {
+    sum += u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)]) as u32;
+  }
+  while sum > 0xFFFF {
+    sum = (sum & 0xFFFF) + (sum >> 16);
+  }
+  !(sum as u16)
+}
@@ -17,6 +17,10 @@
 //!   [Disconnect Procedure].
 //! - Receive [Message]s with the hook provided by the [Connect Procedure].
 //! - Transmit [Message]s with the [Transmit Procedure].
+//! - Observe every [Message] crossing the wire with the [Trace Hook].
+//! - Optionally detect a silently dead TCP/IP connection with [Keepalive].
+//! - Optionally encrypt the TCP/IP connection with [TLS Settings], enabled by
+//!   the `tls` feature.
 //! 
 //! [HSMS]:                 crate
 //! [Primitive Services]:   crate::primitive
@@ -25,12 +29,22 @@
 //! [Connect Procedure]:    Client::connect
 //! [Disconnect Procedure]: Client::disconnect
 //! [Transmit Procedure]:   Client::transmit
+//! [Trace Hook]:           Client::set_trace_hook
 //! [Message]:              Message
 //! [Message Header]:       MessageHeader
 //! [Connection State]:     ConnectionState
+//! [Keepalive]:            Client::set_keepalive
+//! [TLS Settings]:         Client::set_tls
 
 use std::{
+  fmt::{
+    self,
+    Display,
+    Formatter,
+  },
+  fs::File,
   io::{
+    BufWriter,
     Error,
     ErrorKind,
     Read,
@@ -47,17 +61,58 @@ use std::{
     Deref,
     DerefMut,
   },
+  path::Path,
   sync::{
     Arc,
+    atomic::{
+      AtomicU32,
+      AtomicU64,
+      Ordering::Relaxed,
+    },
     mpsc::{
       channel,
       Receiver,
+      SendError,
       Sender,
+      sync_channel,
+      SyncSender,
+      TrySendError,
     },
+    Mutex,
     RwLock,
   },
   thread,
-  time::Duration,
+  time::{
+    Duration,
+    Instant,
+  },
+};
+use socket2::{
+  Domain,
+  Protocol,
+  SockRef,
+  Socket,
+  TcpKeepalive,
+  Type,
+};
+#[cfg(feature = "tls")]
+use rustls::{
+  pki_types::{
+    CertificateDer,
+    PrivateKeyDer,
+    ServerName,
+  },
+  ClientConfig,
+  ClientConnection,
+  Connection,
+  RootCertStore,
+  ServerConfig,
+  ServerConnection,
+};
+use crate::{
+  pcap::PcapWriter,
+  PresentationType,
+  record::RecordWriter,
 };
 
 /// ## CLIENT
@@ -70,6 +125,7 @@ use std::{
 ///   [Disconnect Procedure].
 /// - Receive [Message]s with the hook provided by the [Connect Procedure].
 /// - Transmit [Message]s with the [Transmit Procedure].
+/// - Observe the traffic carried by the connection with the [Client Stats].
 /// 
 /// [HSMS]:                 crate
 /// [Primitive Services]:   crate::primitive
@@ -79,10 +135,59 @@ use std::{
 /// [Disconnect Procedure]: Client::disconnect
 /// [Transmit Procedure]:   Client::transmit
 /// [Connection State]:     ConnectionState
+/// [Client Stats]:         Client::stats
 pub struct Client {
   connection_state: RwLock<ConnectionState>,
+  connection_generation: AtomicU64,
+  disconnect_reason: Mutex<Option<DisconnectReason>>,
+  listener: Mutex<Option<TcpListener>>,
+  backlog: AtomicU32,
+  accept_timeout: RwLock<Option<Duration>>,
+  keepalive: RwLock<Option<Keepalive>>,
+  rx_capacity: RwLock<Option<usize>>,
+  transmit_mutex: Mutex<()>,
+  trace_hook: RwLock<Option<TraceHook>>,
+  rx_counters: Counters,
+  tx_counters: Counters,
+  #[cfg(feature = "tls")]
+  tls_settings: RwLock<Option<TlsSettings>>,
 }
 
+/// ## ACCEPT POLL INTERVAL
+/// 
+/// How often the [Connect Procedure] with a [Connection Mode] of [PASSIVE]
+/// checks for the Remote Entity to have connected when an accept timeout has
+/// been provided.
+/// 
+/// [Connect Procedure]: Client::connect
+/// [Connection Mode]:   ConnectionMode
+/// [PASSIVE]:           ConnectionMode::Passive
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// ## BIND RETRY INTERVAL
+/// 
+/// How long the [Connect Procedure] with a [Connection Mode] of [PASSIVE]
+/// waits before trying again to listen on a socket address of the Local
+/// Entity which is still in use, such as by a connection which has only
+/// just been closed.
+/// 
+/// [Connect Procedure]: Client::connect
+/// [Connection Mode]:   ConnectionMode
+/// [PASSIVE]:           ConnectionMode::Passive
+const BIND_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// ## TRACE HOOK
+/// 
+/// A callback provided to the [Set Trace Hook] function, which is invoked
+/// with the [Direction] and contents of every [Message] received or
+/// transmitted by the [Client].
+/// 
+/// [Client]:         Client
+/// [Set Trace Hook]: Client::set_trace_hook
+/// [Message]:        Message
+/// [Direction]:      Direction
+pub type TraceHook = Box<dyn Fn(Direction, &Message) + Send + Sync>;
+
 /// ## CONNECTION PROCEDURES
 /// **Based on SEMI E37-1109§6.3-6.5**
 /// 
@@ -92,11 +197,13 @@ pub struct Client {
 /// - [New Client]
 /// - [Connect Procedure]
 /// - [Disconnect Procedure]
+/// - [Disconnect Reason]
 /// 
 /// [Client]:               Client
 /// [New Client]:           Client::new
 /// [Connect Procedure]:    Client::connect
 /// [Disconnect Procedure]: Client::disconnect
+/// [Disconnect Reason]:    Client::disconnect_reason
 impl Client {
   /// ### NEW CLIENT
   /// 
@@ -108,7 +215,20 @@ impl Client {
   /// [NOT CONNECTED]:     ConnectionState::NotConnected
   pub fn new() -> Arc<Self> {
     Arc::new(Self {
-      connection_state: Default::default(),
+      connection_state:      Default::default(),
+      connection_generation: Default::default(),
+      disconnect_reason:     Default::default(),
+      listener:              Default::default(),
+      backlog:               AtomicU32::new(128),
+      accept_timeout:        Default::default(),
+      keepalive:             Default::default(),
+      rx_capacity:           Default::default(),
+      transmit_mutex:        Default::default(),
+      trace_hook:            Default::default(),
+      rx_counters:           Default::default(),
+      tx_counters:           Default::default(),
+      #[cfg(feature = "tls")]
+      tls_settings:          Default::default(),
     })
   }
 
@@ -127,35 +247,122 @@ impl Client {
   /// The [Connect Procedure] has two different behaviors based on the
   /// [Connection Mode] provided to it:
   /// - [PASSIVE] - The socket address of the Local Entity must be provided,
-  ///   and the [Client] listens for and accepts the [Connect Procedure] when
-  ///   initiated by the Remote Entity.
+  ///   and the [Client] binds to it, then listens for and accepts the
+  ///   [Connect Procedure] when initiated by the Remote Entity.
   /// - [ACTIVE] - The socket address of the Remote Entity must be provided,
   ///   and the [Client] initiates the [Connect Procedure] and waits up to the
-  ///   time specified by [T5] for the Remote Entity to respond.
+  ///   time specified by [T5] for the Remote Entity to respond, trying each
+  ///   address the socket address resolves to in turn until one succeeds.
+  /// 
+  /// When using the [PASSIVE] mode, the [Client] keeps listening on the
+  /// socket address of the Local Entity after the [Disconnect Procedure], so
+  /// that initiating the [Connect Procedure] again with the same socket
+  /// address simply waits for the Remote Entity to reconnect. If the socket
+  /// address is still in use when first listening on it, such as shortly
+  /// after a previous listener was closed, binding is retried for up to the
+  /// time specified by [T5]. The listener may also be bound beforehand with
+  /// the [Bind] function, and the wait may be limited with the
+  /// [Set Listen Options] function.
   /// 
   /// -------------------------------------------------------------------------
   /// 
   /// Upon completion of the [Connect Procedure], the [T8] parameter is set as
-  /// the TCP stream's read and write timeout, and the [CONNECTED] state is
-  /// entered.
+  /// the TCP stream's read and write timeout, [Keepalive] is enabled on the
+  /// TCP stream if it has been provided, the TLS handshake is performed
+  /// if [TLS Settings] have been provided, and the [CONNECTED] state is
+  /// entered. Received [Message]s whose Message Length exceeds the
+  /// [Maximum Message Length] are refused before being read, ending the
+  /// receipt of [Message]s, unless [Resynchronize] is enabled.
+  /// 
+  /// The returned [Receiver] provides each [Message] received, and is closed
+  /// when the receipt of [Message]s ends, whether due to the
+  /// [Disconnect Procedure] or a failure of the TCP/IP connection. The
+  /// [Client] keeps no record of transactions, so users which correlate
+  /// replies themselves must treat the [Receiver] being closed as the
+  /// abandonment of any transaction still outstanding.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the [Connect Procedure] fails, a [Connect Error] is returned which
+  /// describes the reason for the failure.
+  /// 
+  /// [Client]:                 Client
+  /// [Disconnect Procedure]:   Client::disconnect
+  /// [Connect Procedure]:      Client::connect
+  /// [Connection State]:       ConnectionState
+  /// [NOT CONNECTED]:          ConnectionState::NotConnected
+  /// [CONNECTED]:              ConnectionState::Connected
+  /// [Connection Mode]:        ConnectionMode
+  /// [PASSIVE]:                ConnectionMode::Passive
+  /// [ACTIVE]:                 ConnectionMode::Active
+  /// [T5]:                     crate::generic::ParameterSettings::t5
+  /// [T8]:                     crate::generic::ParameterSettings::t8
+  /// [Maximum Message Length]: crate::generic::ParameterSettings::max_message_length
+  /// [Resynchronize]:          crate::generic::ParameterSettings::resynchronize
+  /// [TLS Settings]:           Client::set_tls
+  /// [Keepalive]:              Client::set_keepalive
+  /// [Message]:                Message
+  /// [Receiver]:               Receiver
+  /// [Connect Error]:          ConnectError
+  /// [Bind]:                   Client::bind
+  /// [Set Listen Options]:     Client::set_listen_options
+  pub fn connect(
+    self: &Arc<Self>,
+    entity: &str,
+    connection_mode: ConnectionMode,
+    t5: Duration,
+    t8: Duration,
+    max_message_length: u32,
+    resynchronize: bool,
+  ) -> Result<(SocketAddr, Receiver<Message>), ConnectError> {
+    // Determine Sockets
+    let sockets: Vec<SocketAddr> = entity.to_socket_addrs().map_err(ConnectError::InvalidAddress)?.collect();
+    if sockets.is_empty() {
+      return Err(ConnectError::InvalidAddress(Error::from(ErrorKind::AddrNotAvailable)))
+    }
+    self.connect_sockets(&sockets, connection_mode, t5, t8, max_message_length, resynchronize)
+  }
+
+  /// ### CONNECT PROCEDURE (SOCKET ADDRESS)
+  /// **Based on SEMI E37-1109§6.3.4-6.3.7**
+  /// 
+  /// Connects the [Client] to the Remote Entity in the same manner as the
+  /// [Connect Procedure], using an already determined socket address rather
+  /// than one which must be resolved.
   /// 
   /// [Client]:            Client
   /// [Connect Procedure]: Client::connect
-  /// [Connection State]:  ConnectionState
-  /// [NOT CONNECTED]:     ConnectionState::NotConnected
-  /// [CONNECTED]:         ConnectionState::Connected
+  pub fn connect_addr(
+    self: &Arc<Self>,
+    socket: SocketAddr,
+    connection_mode: ConnectionMode,
+    t5: Duration,
+    t8: Duration,
+    max_message_length: u32,
+    resynchronize: bool,
+  ) -> Result<(SocketAddr, Receiver<Message>), ConnectError> {
+    self.connect_sockets(&[socket], connection_mode, t5, t8, max_message_length, resynchronize)
+  }
+
+  /// ### CONNECT SOCKETS
+  /// 
+  /// Performs the [Connect Procedure] with the candidate socket addresses,
+  /// listening on the first when the [Connection Mode] is [PASSIVE], or
+  /// trying each in turn when the [Connection Mode] is [ACTIVE].
+  /// 
+  /// [Connect Procedure]: Client::connect
   /// [Connection Mode]:   ConnectionMode
   /// [PASSIVE]:           ConnectionMode::Passive
   /// [ACTIVE]:            ConnectionMode::Active
-  /// [T5]:                crate::generic::ParameterSettings::t5
-  /// [T8]:                crate::generic::ParameterSettings::t8
-  pub fn connect(
+  fn connect_sockets(
     self: &Arc<Self>,
-    entity: &str,
+    sockets: &[SocketAddr],
     connection_mode: ConnectionMode,
     t5: Duration,
     t8: Duration,
-  ) -> Result<(SocketAddr, Receiver<Message>), Error> {
+    max_message_length: u32,
+    resynchronize: bool,
+  ) -> Result<(SocketAddr, Receiver<Message>), ConnectError> {
     // TCP: CONNECT
     let (stream, socket) = match self.connection_state.read().unwrap().deref() {
       // IS: NOT CONNECTED
@@ -163,40 +370,318 @@ impl Client {
         match connection_mode {
           // CONNECTION MODE: PASSIVE
           ConnectionMode::Passive => {
-            // Create Listener and Wait
-            let listener = TcpListener::bind(entity)?;
-            listener.accept()?
+            // Reuse or Create Listener
+            let mut listener_guard = self.listener.lock().unwrap();
+            self.bind_listener(&mut listener_guard, sockets[0], t5)?;
+            // Wait
+            let accept_timeout: Option<Duration> = *self.accept_timeout.read().unwrap().deref();
+            Self::accept(listener_guard.as_ref().unwrap(), accept_timeout)?
           },
           // CONNECTION MODE: ACTIVE
-          ConnectionMode::Active => {
-            // Determine Socket
-            let socket = entity.to_socket_addrs()?.next().ok_or(Error::from(ErrorKind::AddrNotAvailable))?;
-            // Connect with Timeout
-            let stream = TcpStream::connect_timeout(
-              &socket, 
-              t5,
-            )?;
-            (stream, socket)
+          ConnectionMode::Active => 'active: {
+            let mut last_error: Error = Error::from(ErrorKind::AddrNotAvailable);
+            for socket in sockets {
+              // Connect with Timeout
+              match TcpStream::connect_timeout(
+                socket, 
+                t5,
+              ) {
+                Ok(stream) => break 'active (stream, *socket),
+                Err(error) => last_error = error,
+              }
+            }
+            return Err(match last_error.kind() {
+              ErrorKind::TimedOut | ErrorKind::WouldBlock => ConnectError::Timeout(last_error),
+              _ => ConnectError::Io(last_error),
+            })
           },
         }
       },
       // IS: CONNECTED
-      _ => return Err(Error::from(ErrorKind::AlreadyExists)),
+      _ => return Err(ConnectError::AlreadyConnected),
     };
     // Set Read and Write Timeouts to T8
-    stream.set_read_timeout(Some(t8))?;
-    stream.set_write_timeout(Some(t8))?;
+    stream.set_read_timeout(Some(t8)).map_err(ConnectError::Io)?;
+    stream.set_write_timeout(Some(t8)).map_err(ConnectError::Io)?;
+    // Enable TCP Keepalive
+    if let Some(keepalive) = *self.keepalive.read().unwrap().deref() {
+      keepalive.apply(&stream).map_err(ConnectError::Io)?;
+    }
+    // TLS: HANDSHAKE
+    #[cfg(feature = "tls")]
+    let stream: Stream = match self.tls_settings.read().unwrap().deref() {
+      Some(tls_settings) => Stream::tls(stream, connection_mode, tls_settings).map_err(ConnectError::Io)?,
+      None => Stream::tcp(stream),
+    };
+    #[cfg(not(feature = "tls"))]
+    let stream: Stream = Stream::tcp(stream);
     // TO: CONNECTED
-    *self.connection_state.write().unwrap().deref_mut() = ConnectionState::Connected(stream);
+    let generation: u64 = {
+      let mut connection_state = self.connection_state.write().unwrap();
+      let generation: u64 = self.connection_generation.fetch_add(1, Relaxed) + 1;
+      *connection_state.deref_mut() = ConnectionState::Connected(stream);
+      *self.disconnect_reason.lock().unwrap().deref_mut() = None;
+      generation
+    };
+    log::debug!("primitive::Client::connect: CONNECTED {}", socket);
     // Create Channels
-    let (rx_sender, rx_receiver) = channel::<Message>();
+    let (rx_sender, rx_receiver) = HookSender::<Message>::channel(*self.rx_capacity.read().unwrap().deref());
     // Start RX Thread
     let rx_clone: Arc<Client> = self.clone();
-    thread::spawn(move || {rx_clone.receive(rx_sender.clone())});
+    thread::spawn(move || {rx_clone.receive(rx_sender, generation, max_message_length, resynchronize)});
     // Finish
     Ok((socket, rx_receiver))
   }
 
+  /// ### IS CONNECTED
+  /// 
+  /// Reports whether the [Connection State] is in the [CONNECTED] state.
+  /// 
+  /// [Connection State]: ConnectionState
+  /// [CONNECTED]:        ConnectionState::Connected
+  pub fn is_connected(
+    self: &Arc<Self>,
+  ) -> bool {
+    matches!(self.connection_state.read().unwrap().deref(), ConnectionState::Connected(_))
+  }
+
+  /// ### PEER ADDRESS
+  /// 
+  /// Provides the socket address of the Remote Entity, or [None] if the
+  /// [Connection State] is not in the [CONNECTED] state.
+  /// 
+  /// [Connection State]: ConnectionState
+  /// [CONNECTED]:        ConnectionState::Connected
+  pub fn peer_addr(
+    self: &Arc<Self>,
+  ) -> Option<SocketAddr> {
+    match self.connection_state.read().unwrap().deref() {
+      ConnectionState::Connected(stream) => stream.tcp_stream().peer_addr().ok(),
+      ConnectionState::NotConnected => None,
+    }
+  }
+
+  /// ### LOCAL ADDRESS
+  /// 
+  /// Provides the socket address of the Local Entity, or [None] if the
+  /// [Connection State] is not in the [CONNECTED] state.
+  /// 
+  /// [Connection State]: ConnectionState
+  /// [CONNECTED]:        ConnectionState::Connected
+  pub fn local_addr(
+    self: &Arc<Self>,
+  ) -> Option<SocketAddr> {
+    match self.connection_state.read().unwrap().deref() {
+      ConnectionState::Connected(stream) => stream.tcp_stream().local_addr().ok(),
+      ConnectionState::NotConnected => None,
+    }
+  }
+
+  /// ### SET TLS SETTINGS
+  /// 
+  /// Provides the [Client] with [TLS Settings] to be used by subsequent
+  /// initiations of the [Connect Procedure], replacing any previously
+  /// provided [TLS Settings], or returning to unencrypted connections if
+  /// [None] is provided.
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [TLS Settings]:      TlsSettings
+  #[cfg(feature = "tls")]
+  pub fn set_tls(
+    self: &Arc<Self>,
+    tls_settings: Option<TlsSettings>,
+  ) {
+    *self.tls_settings.write().unwrap().deref_mut() = tls_settings;
+  }
+
+  /// ### SET LISTEN OPTIONS
+  /// 
+  /// Sets the backlog of pending connections used when a TCP listener is
+  /// next bound, and the maximum amount of time the [Connect Procedure] with
+  /// a [Connection Mode] of [PASSIVE] will wait for the Remote Entity to
+  /// connect, or [None] to wait indefinitely.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  pub fn set_listen_options(
+    self: &Arc<Self>,
+    backlog: u32,
+    accept_timeout: Option<Duration>,
+  ) {
+    self.backlog.store(backlog, Relaxed);
+    *self.accept_timeout.write().unwrap().deref_mut() = accept_timeout;
+  }
+
+  /// ### SET KEEPALIVE
+  /// 
+  /// Sets the [Keepalive] enabled on the TCP stream by the
+  /// [Connect Procedure], or [None] to leave it disabled. It takes effect
+  /// from the next [Connect Procedure].
+  /// 
+  /// [Keepalive]:         Keepalive
+  /// [Connect Procedure]: Client::connect
+  pub fn set_keepalive(
+    self: &Arc<Self>,
+    keepalive: Option<Keepalive>,
+  ) {
+    *self.keepalive.write().unwrap().deref_mut() = keepalive;
+  }
+
+  /// ### SET RX CAPACITY
+  /// 
+  /// Sets the number of [Message]s the hook provided by the
+  /// [Connect Procedure] holds before the receiving thread waits for room,
+  /// so that the Remote Entity is eventually held back by TCP/IP flow
+  /// control, or [None] to hold any number. It takes effect from the next
+  /// [Connect Procedure].
+  /// 
+  /// [Message]:           Message
+  /// [Connect Procedure]: Client::connect
+  pub fn set_rx_capacity(
+    self: &Arc<Self>,
+    rx_capacity: Option<usize>,
+  ) {
+    *self.rx_capacity.write().unwrap().deref_mut() = rx_capacity;
+  }
+
+  /// ### BIND
+  /// 
+  /// Binds a TCP listener to the socket address of the Local Entity ahead of
+  /// the [Connect Procedure] with a [Connection Mode] of [PASSIVE], and
+  /// provides the socket address it was bound to.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// This allows a port of 0 to be provided, so that the operating system
+  /// chooses an ephemeral port, which can then be learned and given to the
+  /// Remote Entity before waiting for it to connect. The listener is reused
+  /// by the [Connect Procedure] when it is provided either the socket address
+  /// which was bound, or the same socket address with a port of 0.
+  /// 
+  /// Unlike the [Connect Procedure], a socket address which is still in use
+  /// is not waited on, failing immediately with [Address In Use].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Address In Use]:    ConnectError::AddressInUse
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  pub fn bind(
+    self: &Arc<Self>,
+    entity: &str,
+  ) -> Result<SocketAddr, ConnectError> {
+    let socket: SocketAddr = match entity.to_socket_addrs().map_err(ConnectError::InvalidAddress)?.next() {
+      Some(socket) => socket,
+      None => return Err(ConnectError::InvalidAddress(Error::from(ErrorKind::AddrNotAvailable))),
+    };
+    let mut listener_guard = self.listener.lock().unwrap();
+    self.bind_listener(&mut listener_guard, socket, Duration::ZERO)?;
+    listener_guard.as_ref().unwrap().local_addr().map_err(ConnectError::Bind)
+  }
+
+  /// ### BIND LISTENER
+  /// 
+  /// Ensures that the guarded TCP listener is bound to the socket address of
+  /// the Local Entity, keeping the existing listener if it matches as
+  /// described by the [Bind] function and otherwise replacing it.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// If the socket address is still in use, listening is tried again every
+  /// [Bind Retry Interval] until the provided amount of time has elapsed,
+  /// after which [Address In Use] is provided.
+  /// 
+  /// [Bind]:                Client::bind
+  /// [Bind Retry Interval]: BIND_RETRY_INTERVAL
+  /// [Address In Use]:      ConnectError::AddressInUse
+  fn bind_listener(
+    self: &Arc<Self>,
+    listener: &mut Option<TcpListener>,
+    socket: SocketAddr,
+    bind_timeout: Duration,
+  ) -> Result<(), ConnectError> {
+    if let Some(bound) = listener.as_ref().and_then(|listener| listener.local_addr().ok()) {
+      if bound == socket || (socket.port() == 0 && bound.ip() == socket.ip()) {
+        return Ok(())
+      }
+    }
+    *listener = None;
+    let deadline: Instant = Instant::now() + bind_timeout;
+    loop {
+      match Self::listen(socket, self.backlog.load(Relaxed)) {
+        Ok(bound) => {
+          *listener = Some(bound);
+          return Ok(())
+        },
+        Err(error) if error.kind() == ErrorKind::AddrInUse => {
+          let now: Instant = Instant::now();
+          if now >= deadline {return Err(ConnectError::AddressInUse(error))}
+          log::debug!("primitive::Client::bind_listener: ADDRESS IN USE {}", socket);
+          thread::sleep(BIND_RETRY_INTERVAL.min(deadline - now));
+        },
+        Err(error) => return Err(ConnectError::Bind(error)),
+      }
+    }
+  }
+
+  /// ### LISTEN
+  /// 
+  /// Binds a TCP listener to the socket address of the Local Entity, allowing
+  /// the socket address to be reused, for use by the [Connect Procedure] with
+  /// a [Connection Mode] of [PASSIVE].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  fn listen(
+    socket: SocketAddr,
+    backlog: u32,
+  ) -> Result<TcpListener, Error> {
+    let listener = Socket::new(Domain::for_address(socket), Type::STREAM, Some(Protocol::TCP))?;
+    listener.set_reuse_address(true)?;
+    listener.bind(&socket.into())?;
+    listener.listen(backlog.min(i32::MAX as u32) as i32)?;
+    Ok(listener.into())
+  }
+
+  /// ### ACCEPT
+  /// 
+  /// Waits for the Remote Entity to connect to a TCP listener, for at most
+  /// the provided amount of time if one is provided.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// With a timeout, the listener is polled without blocking, checking
+  /// every [Accept Poll Interval] until the timeout has elapsed.
+  /// 
+  /// [Accept Poll Interval]: ACCEPT_POLL_INTERVAL
+  fn accept(
+    listener: &TcpListener,
+    accept_timeout: Option<Duration>,
+  ) -> Result<(TcpStream, SocketAddr), ConnectError> {
+    let Some(accept_timeout) = accept_timeout else {
+      listener.set_nonblocking(false).map_err(ConnectError::Io)?;
+      return listener.accept().map_err(ConnectError::Io)
+    };
+    listener.set_nonblocking(true).map_err(ConnectError::Io)?;
+    let deadline: Instant = Instant::now() + accept_timeout;
+    loop {
+      match listener.accept() {
+        Ok((stream, socket)) => {
+          stream.set_nonblocking(false).map_err(ConnectError::Io)?;
+          return Ok((stream, socket))
+        },
+        Err(error) if error.kind() == ErrorKind::WouldBlock => {
+          let now: Instant = Instant::now();
+          if now >= deadline {return Err(ConnectError::AcceptTimeout)}
+          thread::sleep(ACCEPT_POLL_INTERVAL.min(deadline - now));
+        },
+        Err(error) => return Err(ConnectError::Io(error)),
+      }
+    }
+  }
+
   /// ### DISCONNECT PROCEDURE
   /// **Based on SEMI E37-1109§6.4-6.5**
   /// 
@@ -212,11 +697,25 @@ impl Client {
   /// Upon completion of the [Disconnect Procedure], the [NOT CONNECTED] state
   /// is entered.
   /// 
+  /// Shutting down the TCP stream ends the receipt of [Message]s, closing the
+  /// [Receiver] provided by the [Connect Procedure] so that any pending or
+  /// later receive on it returns an error once the [Message]s already
+  /// received have been taken. This is the only notification that
+  /// outstanding transactions have been abandoned.
+  /// 
+  /// Unless a failure of the TCP/IP connection has already been recorded,
+  /// the [Disconnect Reason] is recorded as [LOCAL].
+  /// 
   /// [Client]:               Client
+  /// [Connect Procedure]:    Client::connect
   /// [Disconnect Procedure]: Client::disconnect
   /// [Connection State]:     ConnectionState
   /// [NOT CONNECTED]:        ConnectionState::NotConnected
   /// [CONNECTED]:            ConnectionState::Connected
+  /// [Message]:              Message
+  /// [Receiver]:             Receiver
+  /// [Disconnect Reason]:    DisconnectReason
+  /// [LOCAL]:                DisconnectReason::Local
   pub fn disconnect(
     self: &Arc<Self>
   ) -> Result<(), Error> {
@@ -225,14 +724,66 @@ impl Client {
       ConnectionState::NotConnected => return Err(Error::from(ErrorKind::NotConnected)),
       // IS: CONNECTED
       ConnectionState::Connected(stream) => {
+        // Record Before Receipt Fails
+        self.disconnect_reason.lock().unwrap().get_or_insert(DisconnectReason::Local);
         // TCP: SHUTDOWN
-        let _ = stream.shutdown(Shutdown::Both);
+        stream.shutdown();
       },
     }
     // TO: NOT CONNECTED
-    *self.connection_state.write().unwrap().deref_mut() = ConnectionState::NotConnected;
+    let mut connection_state = self.connection_state.write().unwrap();
+    if let ConnectionState::NotConnected = connection_state.deref() {
+      // Disconnected Concurrently
+      return Err(Error::from(ErrorKind::NotConnected))
+    }
+    *connection_state.deref_mut() = ConnectionState::NotConnected;
+    log::debug!("primitive::Client::disconnect: NOT CONNECTED");
     Ok(())
   }
+
+  /// ### DISCONNECT REASON
+  /// 
+  /// Provides the [Disconnect Reason] for which the [Client] most recently
+  /// left the [CONNECTED] state, or [None] if it is in the [CONNECTED] state
+  /// or has never been connected.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Disconnect Reason] is recorded before the [Receiver] provided by
+  /// the [Connect Procedure] is closed, so it may be inspected as soon as
+  /// the [Receiver] reports that it has been closed.
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [CONNECTED]:         ConnectionState::Connected
+  /// [Disconnect Reason]: DisconnectReason
+  /// [Receiver]:          Receiver
+  pub fn disconnect_reason(
+    self: &Arc<Self>,
+  ) -> Option<DisconnectReason> {
+    *self.disconnect_reason.lock().unwrap().deref()
+  }
+
+  /// ### FAIL
+  /// 
+  /// Records the [Disconnect Reason] corresponding to a failure of the
+  /// TCP/IP connection, unless the connection it occurred on has already
+  /// been disconnected or replaced, or a [Disconnect Reason] has already
+  /// been recorded for it.
+  /// 
+  /// [Disconnect Reason]: DisconnectReason
+  fn fail(
+    &self,
+    generation: u64,
+    error: &Error,
+  ) {
+    let connection_state = self.connection_state.read().unwrap();
+    if let ConnectionState::Connected(_) = connection_state.deref() {
+      if self.connection_generation.load(Relaxed) == generation {
+        self.disconnect_reason.lock().unwrap().get_or_insert(DisconnectReason::from(error));
+      }
+    }
+  }
 }
 
 /// ## MESSAGE EXCHANGE PROCEDURES
@@ -242,132 +793,446 @@ impl Client {
 /// exchanging [Message]s.
 /// 
 /// - [Transmit Procedure] - Any [Message]
+/// - [Set Trace Hook] - Any [Message]
+/// - [Trace to Pcap] - Any [Message]
+/// - [Record To] - Any received [Message]
+/// - [Client Stats] - Any [Message]
+/// - [Rx] - Any [Message], from any stream
+/// - [Tx] - Any [Message], to any stream
 /// 
 /// [Client]:             Client
 /// [Transmit Procedure]: Client::transmit
+/// [Set Trace Hook]:     Client::set_trace_hook
+/// [Trace to Pcap]:      Client::trace_to_pcap
+/// [Record To]:          Client::record_to
+/// [Client Stats]:       Client::stats
+/// [Rx]:                 Client::rx
+/// [Tx]:                 Client::tx
 /// [Message]:            Message
 impl Client {
+  /// ### SET TRACE HOOK
+  /// 
+  /// Provides the [Client] with a [Trace Hook] to be invoked whenever a
+  /// [Message] is received or transmitted, replacing any previously provided
+  /// [Trace Hook], or removing it if [None] is provided.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Trace Hook] is invoked without the [Connection State] being locked,
+  /// but must not itself call the [Set Trace Hook] function.
+  /// 
+  /// [Client]:           Client
+  /// [Set Trace Hook]:   Client::set_trace_hook
+  /// [Connection State]: ConnectionState
+  /// [Message]:          Message
+  /// [Trace Hook]:       TraceHook
+  pub fn set_trace_hook(
+    self: &Arc<Self>,
+    trace_hook: Option<TraceHook>,
+  ) {
+    *self.trace_hook.write().unwrap().deref_mut() = trace_hook;
+  }
+
+  /// ### TRACE TO PCAP
+  /// 
+  /// Provides the [Client] with a [Trace Hook] which records every [Message]
+  /// received or transmitted to a new file at the provided path, in the pcap
+  /// capture format, replacing any previously provided [Trace Hook].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The Remote Entity is recorded with the provided port, which should be
+  /// the port the HSMS dissector is configured to decode. Failures to write
+  /// to the file are logged and otherwise ignored.
+  /// 
+  /// [Client]:     Client
+  /// [Message]:    Message
+  /// [Trace Hook]: TraceHook
+  pub fn trace_to_pcap(
+    self: &Arc<Self>,
+    path: impl AsRef<Path>,
+    port: u16,
+  ) -> Result<(), Error> {
+    let writer: Mutex<PcapWriter<BufWriter<File>>> = Mutex::new(PcapWriter::new(BufWriter::new(File::create(path)?), port)?);
+    self.set_trace_hook(Some(Box::new(move |direction, message| {
+      if let Err(error) = writer.lock().unwrap().write_message(direction, message) {
+        log::debug!("primitive::Client::trace_to_pcap: {}", error);
+      }
+    })));
+    Ok(())
+  }
+
+  /// ### RECORD TO
+  /// 
+  /// Provides the [Client] with a [Trace Hook] which appends every [Message]
+  /// received to the file at the provided path, creating it if necessary,
+  /// as described by the [Frame Recording], replacing any previously
+  /// provided [Trace Hook].
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Transmitted [Message]s are not recorded. Failures to write to the file
+  /// are logged and otherwise ignored.
+  /// 
+  /// [Client]:          Client
+  /// [Message]:         Message
+  /// [Trace Hook]:      TraceHook
+  /// [Frame Recording]: crate::record
+  pub fn record_to(
+    self: &Arc<Self>,
+    path: impl AsRef<Path>,
+  ) -> Result<(), Error> {
+    let file: File = File::options().create(true).append(true).open(path)?;
+    let writer: Mutex<RecordWriter<BufWriter<File>>> = Mutex::new(RecordWriter::new(BufWriter::new(file)));
+    self.set_trace_hook(Some(Box::new(move |direction, message| {
+      if direction != Direction::Receive {return}
+      if let Err(error) = writer.lock().unwrap().write_message(message) {
+        log::debug!("primitive::Client::record_to: {}", error);
+      }
+    })));
+    Ok(())
+  }
+
+  /// ### TRACE
+  /// 
+  /// Invokes the [Trace Hook], if one has been provided.
+  /// 
+  /// [Trace Hook]: TraceHook
+  fn trace(
+    &self,
+    direction: Direction,
+    message: &Message,
+  ) {
+    if let Some(trace_hook) = self.trace_hook.read().unwrap().deref() {
+      trace_hook(direction, message);
+    }
+  }
+
+  /// ### CLIENT STATS
+  /// 
+  /// Provides the number of [Message]s and bytes received and transmitted by
+  /// the [Client] since it was created, across all connections.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The counters are updated without locking, so the [Client Stats] taken
+  /// while [Message]s are being exchanged may not be mutually consistent.
+  /// 
+  /// [Client]:       Client
+  /// [Message]:      Message
+  /// [Client Stats]: ClientStats
+  pub fn stats(
+    self: &Arc<Self>,
+  ) -> ClientStats {
+    ClientStats {
+      rx: self.rx_counters.load(),
+      tx: self.tx_counters.load(),
+    }
+  }
+
+  /// ### COUNT
+  /// 
+  /// Adds a [Message] to the counters for its [Direction].
+  /// 
+  /// [Message]:   Message
+  /// [Direction]: Direction
+  fn count(
+    &self,
+    direction: Direction,
+    message: &Message,
+  ) {
+    match direction {
+      Direction::Receive  => self.rx_counters.add(message),
+      Direction::Transmit => self.tx_counters.add(message),
+    }
+  }
+
   /// ### RECEIVE PROCEDURE
   /// 
   /// A [Client] in the [CONNECTED] state will automatically receive
   /// [Message]s, and send them to the hook provided by the
   /// [Connect Procedure].
   /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The procedure ends, dropping the hook, when the [Connection State]
+  /// leaves the [CONNECTED] state or the connection it was started for has
+  /// been replaced by a later one, so that a hook is never kept open by a
+  /// [Client] which has reconnected. If it ends due to a failure of the
+  /// TCP/IP connection, the [Disconnect Reason] is recorded first.
+  /// 
   /// [Message]:           Message
   /// [Client]:            Client
   /// [Connect Procedure]: Client::connect
+  /// [Connection State]:  ConnectionState
   /// [CONNECTED]:         ConnectionState::Connected
+  /// [Disconnect Reason]: DisconnectReason
   fn receive(
     self: Arc<Self>,
-    rx_sender: Sender<Message>,
+    rx_sender: HookSender<Message>,
+    generation: u64,
+    max_message_length: u32,
+    resynchronize: bool,
   ) {
-    while let ConnectionState::Connected(stream_immutable) = self.connection_state.read().unwrap().deref() {
-      let res: Result<Option<Message>, Error> = 'rx: {
-        let mut stream: &TcpStream = stream_immutable;
-        // Length [Bytes 0-3]
-        let mut length_buffer: [u8;4] = [0;4];
-        let length_bytes: usize = match stream.read(&mut length_buffer) {
-          Ok(l) => l,
-          Err(error) => match error.kind() {
-            ErrorKind::TimedOut => {
-              break 'rx Ok(None)
-            },
-            _ => {
-              break 'rx Err(error)
-            },
-          }
-        };
-        if length_bytes != 4 {
-          break 'rx Err(Error::from(ErrorKind::TimedOut))
-        }
-        let length: u32 = u32::from_be_bytes(length_buffer);
-        if length < 10 {
-          break 'rx Err(Error::from(ErrorKind::InvalidData))
-        }
-        // Header + Data [Bytes 4+]
-        let mut message_buffer: Vec<u8> = vec![0; length as usize];
-        let message_bytes: usize = match stream.read(&mut message_buffer) {
-          Ok(message_bytes) => message_bytes,
-          Err(error) => break 'rx Err(error),
-        };
-        if message_bytes != length as usize {
-          break 'rx Err(Error::from(ErrorKind::TimedOut))
-        }
-        // Diagnostic
-        /*println!(
-          "rx {: >4X} {: >3}{} {: >3} {: >2X} {: >2X} {: >8X} {:?}",
-          u16::from_be_bytes(message_buffer[0..2].try_into().unwrap()),
-          &message_buffer[2] & 0b0111_1111,
-          if (&message_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
-          &message_buffer[3],
-          &message_buffer[4],
-          &message_buffer[5],
-          u32::from_be_bytes(message_buffer[6..10].try_into().unwrap()),
-          &message_buffer[10..],
-        );// */
-        // Finish
-        match Message::try_from(message_buffer) {
-          Ok(message) => Ok(Some(message)),
-          Err(_) => break 'rx Err(Error::from(ErrorKind::InvalidData)),
-        }
+    loop {
+      let res: Result<Option<Message>, Error> = match self.connection_state.read().unwrap().deref() {
+        // IS: NOT CONNECTED
+        ConnectionState::NotConnected => break,
+        // IS: CONNECTED, Replaced
+        ConnectionState::Connected(_) if self.connection_generation.load(Relaxed) != generation => break,
+        // IS: CONNECTED
+        ConnectionState::Connected(stream_immutable) => {
+          let mut stream: &Stream = stream_immutable;
+          Self::rx(&mut stream, max_message_length, resynchronize)
+        },
       };
       match res {
         // RX: SUCCESS
         Ok(optional_rx_message) => if let Some(rx_message) = optional_rx_message {
+          self.count(Direction::Receive, &rx_message);
+          self.trace(Direction::Receive, &rx_message);
           if rx_sender.send(rx_message).is_err() {break}
         },
         // RX: FAILURE
-        Err(_error) => break,
+        Err(error) => {
+          log::debug!("primitive::Client::receive: {}", error);
+          self.fail(generation, &error);
+          break
+        },
       }
     }
     //let _ = self.disconnect();
   }
 
-  /// ### TRANSMIT PROCEDURE
-  /// **Based on SEMI E37-1109§7.2**
+  /// ### RX
+  /// 
+  /// Reads a single [Message] from a stream, including its Message Length,
+  /// returning [None] if the stream's read timeout elapses before any part of
+  /// the [Message] arrives.
   /// 
-  /// Serializes a [Message] and transmits it over the TCP/IP connection.
+  /// When [Resynchronize] is enabled, the Message Length and
+  /// [Message Header] are read together and, if they could not begin a valid
+  /// [Message], received bytes are discarded until they could. They could if
+  /// the Message Length is at least the size of the [Message Header] and no
+  /// more than the [Maximum Message Length], the [Presentation Type] is
+  /// [SECS-II], the [Session Type] is defined, and a control message carries
+  /// no [Message Text].
   /// 
   /// -------------------------------------------------------------------------
   /// 
-  /// The [Connection State] must be in the [CONNECTED] state to use this
-  /// procedure.
+  /// Only the first read is permitted to time out without error; a timeout
+  /// while the rest of the [Message] is being read results in an error.
+  /// A timeout is recognized by an error of kind [TimedOut] or [WouldBlock],
+  /// as produced by a TCP stream with a read timeout. Readers which are not
+  /// sockets, such as in-memory buffers, will not produce these errors, and so
+  /// will never yield [None].
   /// 
-  /// [Message]:          Message
-  /// [Connection State]: ConnectionState
+  /// Fails if the stream is closed, if the Message Length is less than the
+  /// size of the [Message Header] or greater than the
+  /// [Maximum Message Length], or if the [Message] cannot be decoded.
+  /// 
+  /// [Message]:                Message
+  /// [Message Header]:         MessageHeader
+  /// [Resynchronize]:          crate::generic::ParameterSettings::resynchronize
+  /// [Presentation Type]:      PresentationType
+  /// [SECS-II]:                PresentationType::SecsII
+  /// [Session Type]:           MessageHeader::session_type
+  /// [Message Text]:           Message::text
+  /// [TimedOut]:               ErrorKind::TimedOut
+  /// [WouldBlock]:             ErrorKind::WouldBlock
+  /// [Maximum Message Length]: crate::generic::ParameterSettings::max_message_length
+  pub fn rx<S: Read>(
+    stream: &mut S,
+    max_message_length: u32,
+    resynchronize: bool,
+  ) -> Result<Option<Message>, Error> {
+    // Length [Bytes 0-3]
+    let mut length_buffer: [u8;4] = [0;4];
+    let length_bytes: usize = match stream.read(&mut length_buffer) {
+      Ok(l) => l,
+      Err(error) => match error.kind() {
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => {
+          return Ok(None)
+        },
+        _ => {
+          return Err(error)
+        },
+      }
+    };
+    if length_bytes == 0 {
+      return Err(Error::from(ErrorKind::UnexpectedEof))
+    }
+    // TO: Remainder of the length must arrive within T8.
+    stream.read_exact(&mut length_buffer[length_bytes..])?;
+    let mut length: u32 = u32::from_be_bytes(length_buffer);
+    let mut header_buffer: Option<[u8;10]> = None;
+    if resynchronize {
+      // Length + Header [Bytes 0-13]
+      let mut window: [u8;14] = [0;14];
+      window[..4].copy_from_slice(&length_buffer);
+      stream.read_exact(&mut window[4..])?;
+      // RX: Suspected Framing Error
+      if !Self::plausible(&window, max_message_length) {
+        log::debug!("primitive::Client::rx: RESYNCHRONIZING");
+        Self::resynchronize(stream, &mut window, max_message_length)?;
+      }
+      length = u32::from_be_bytes([window[0], window[1], window[2], window[3]]);
+      let mut header: [u8;10] = [0;10];
+      header.copy_from_slice(&window[4..]);
+      header_buffer = Some(header);
+    } else {
+      if length < 10 {
+        return Err(Error::from(ErrorKind::InvalidData))
+      }
+      if length > max_message_length {
+        return Err(Error::new(ErrorKind::InvalidData, "message length exceeds maximum"))
+      }
+    }
+    // Header + Data [Bytes 4+]
+    // TO: Each read of the message must complete within T8.
+    let mut message_buffer: Vec<u8> = vec![0; length as usize];
+    let mut unread: &mut [u8] = &mut message_buffer;
+    if let Some(header) = header_buffer {
+      let (header_slice, text_slice) = unread.split_at_mut(10);
+      header_slice.copy_from_slice(&header);
+      unread = text_slice;
+    }
+    stream.read_exact(unread)?;
+    let message: Message = Message::try_from(message_buffer).map_err(|_| Error::from(ErrorKind::InvalidData))?;
+    // Diagnostic
+    message.log_trace("rx");
+    // Finish
+    Ok(Some(message))
+  }
+
+  /// ### PLAUSIBLE FRAME
+  /// 
+  /// Reports whether a Message Length and [Message Header] could begin a
+  /// valid [Message]: the Message Length is at least the size of the
+  /// [Message Header] and no more than the [Maximum Message Length], the
+  /// [Presentation Type] is [SECS-II], the [Session Type] is defined, and a
+  /// control message carries no [Message Text].
+  /// 
+  /// [Message]:                Message
+  /// [Message Header]:         MessageHeader
+  /// [Message Text]:           Message::text
+  /// [Presentation Type]:      PresentationType
+  /// [SECS-II]:                PresentationType::SecsII
+  /// [Session Type]:           MessageHeader::session_type
+  /// [Maximum Message Length]: crate::generic::ParameterSettings::max_message_length
+  fn plausible(
+    window: &[u8;14],
+    max_message_length: u32,
+  ) -> bool {
+    let length: u32 = u32::from_be_bytes([window[0], window[1], window[2], window[3]]);
+    if length < 10 || length > max_message_length {return false}
+    if window[8] != PresentationType::SecsII as u8 {return false}
+    match window[9] {
+      0 => true,
+      1..=7 | 9 => length == 10,
+      _ => false,
+    }
+  }
+
+  /// ### RESYNCHRONIZE
+  /// 
+  /// Following a suspected framing error, discards received bytes one at a
+  /// time until the window holds a [Plausible Frame], leaving its Message
+  /// Length and [Message Header] in the window.
+  /// 
+  /// Fails if the TCP/IP connection is closed, or if no byte arrives within
+  /// the time specified by [T8].
+  /// 
+  /// [Plausible Frame]: Client::plausible
+  /// [Message Header]:  MessageHeader
+  /// [T8]:              crate::generic::ParameterSettings::t8
+  fn resynchronize(
+    stream: &mut impl Read,
+    window: &mut [u8;14],
+    max_message_length: u32,
+  ) -> Result<(), Error> {
+    let mut byte: [u8;1] = [0;1];
+    while !Self::plausible(window, max_message_length) {
+      if stream.read(&mut byte)? == 0 {
+        return Err(Error::from(ErrorKind::UnexpectedEof))
+      }
+      window.copy_within(1.., 0);
+      window[13] = byte[0];
+    }
+    Ok(())
+  }
+
+  /// ### TX
+  /// 
+  /// Serializes a [Message] and writes it to a stream, preceded by its
+  /// Message Length, in a single write.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Message] is borrowed and serialized directly into the frame, so
+  /// its [Message Text] is copied only once.
+  /// 
+  /// Callers sharing a stream between threads must ensure that concurrent
+  /// writes do not interleave.
+  /// 
+  /// [Message]:      Message
+  /// [Message Text]: Message::text
+  pub fn tx<S: Write>(
+    stream: &mut S,
+    message: &Message,
+  ) -> Result<(), Error> {
+    // Length [Bytes 0-3]
+    let length: u32 = 10 + message.text.len() as u32;
+    let mut frame_buffer: Vec<u8> = Vec::with_capacity(4 + length as usize);
+    frame_buffer.extend_from_slice(&length.to_be_bytes());
+    // Header + Data [Bytes 4+]
+    message.serialize(&mut frame_buffer);
+    // Diagnostic
+    message.log_trace("tx");
+    // Write
+    stream.write_all(&frame_buffer)
+  }
+
+  /// ### TRANSMIT PROCEDURE
+  /// **Based on SEMI E37-1109§7.2**
+  /// 
+  /// Serializes a [Message] and transmits it over the TCP/IP connection,
+  /// encrypted if [TLS Settings] were provided for the connection.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The [Connection State] must be in the [CONNECTED] state to use this
+  /// procedure.
+  /// 
+  /// [Message]:          Message
+  /// [Connection State]: ConnectionState
   /// [CONNECTED]:        ConnectionState::Connected
+  /// [TLS Settings]:     Client::set_tls
   pub fn transmit(
     self: &Arc<Self>,
     message: Message,
   ) -> Result<(), Error> {
-    match self.connection_state.read().unwrap().deref() {
-      ConnectionState::Connected(stream_immutable) => 'disconnect: {
-        let mut stream: &TcpStream = stream_immutable;
-        // Header + Data [Bytes 4+]
-        let message_buffer: Vec<u8> = (&message).into();
-        // Length [Bytes 0-3]
-        let length: u32 = message_buffer.len() as u32;
-        let length_buffer: [u8; 4] = length.to_be_bytes();
-        // Diagnostic
-        /*println!(
-          "tx {: >4X} {: >3}{} {: >3} {: >2X} {: >2X} {: >8X} {:?}",
-          u16::from_be_bytes(message_buffer[0..2].try_into().unwrap()),
-          &message_buffer[2] & 0b0111_1111,
-          if (&message_buffer[2] & 0b1000_0000) > 0 {'W'} else {' '},
-          &message_buffer[3],
-          &message_buffer[4],
-          &message_buffer[5],
-          u32::from_be_bytes(message_buffer[6..10].try_into().unwrap()),
-          &message_buffer[10..],
-        );// */
-        // Write
-        if stream.write_all(&length_buffer).is_err() {break 'disconnect};
-        if stream.write_all(&message_buffer).is_err() {break 'disconnect};
-        // Finish
-        return Ok(())
+    let transmitted: bool = match self.connection_state.read().unwrap().deref() {
+      ConnectionState::Connected(stream_immutable) => {
+        let mut stream: &Stream = stream_immutable;
+        // TX: Frames from concurrent transmissions must not interleave.
+        let _guard = self.transmit_mutex.lock().unwrap();
+        match Self::tx(&mut stream, &message) {
+          Ok(()) => true,
+          Err(error) => {
+            self.disconnect_reason.lock().unwrap().get_or_insert(DisconnectReason::from(&error));
+            false
+          },
+        }
       },
       ConnectionState::NotConnected => return Err(Error::from(ErrorKind::NotConnected)),
     };
+    // Finish
+    if transmitted {
+      self.count(Direction::Transmit, &message);
+      self.trace(Direction::Transmit, &message);
+      return Ok(())
+    }
     self.disconnect()?;
     Err(Error::from(ErrorKind::ConnectionAborted))
   }
@@ -403,11 +1268,13 @@ pub enum ConnectionState {
   /// **Based on SEMI E37-1109§5.5.2**
   /// 
   /// In this state, the [Client] has successfully initiated the
-  /// [Connect Procedure] and is able to send and receive data.
+  /// [Connect Procedure] and is able to send and receive data over the
+  /// [Stream].
   /// 
   /// [Client]:            Client
   /// [Connect Procedure]: Client::connect
-  Connected(TcpStream)
+  /// [Stream]:            Stream
+  Connected(Stream)
 }
 impl Default for ConnectionState {
   /// ### DEFAULT CONNECTION STATE
@@ -421,6 +1288,490 @@ impl Default for ConnectionState {
   }
 }
 
+/// ## STREAM
+/// 
+/// The TCP/IP connection held by the [Client] while in the [CONNECTED] state,
+/// which is encrypted by a TLS session if [TLS Settings] were provided prior
+/// to the [Connect Procedure].
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The [Stream] is shared between the thread receiving [Message]s and any
+/// threads transmitting [Message]s, so [Read] and [Write] are implemented for
+/// a shared reference, as with a TCP stream. When encrypted, the TLS session is only locked
+/// while ciphertext is being processed, so that waiting for incoming data
+/// does not block transmission.
+/// 
+/// [Client]:            Client
+/// [Connect Procedure]: Client::connect
+/// [CONNECTED]:         ConnectionState::Connected
+/// [TLS Settings]:      Client::set_tls
+/// [Stream]:            Stream
+/// [Message]:           Message
+/// [Read]:              Read
+/// [Write]:             Write
+pub struct Stream {
+  tcp: TcpStream,
+  #[cfg(feature = "tls")]
+  tls: Option<Box<Mutex<Connection>>>,
+}
+impl Stream {
+  /// ### NEW UNENCRYPTED STREAM
+  /// 
+  /// Wraps a TCP stream which carries data without encryption.
+  fn tcp(tcp: TcpStream) -> Self {
+    Self {
+      tcp,
+      #[cfg(feature = "tls")]
+      tls: None,
+    }
+  }
+
+  /// ### NEW ENCRYPTED STREAM
+  /// 
+  /// Wraps a TCP stream in a TLS session appropriate for the
+  /// [Connection Mode] and completes the TLS handshake, subject to the read
+  /// and write timeouts already set on the TCP stream.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// Fails if the [TLS Settings] do not match the [Connection Mode], or if
+  /// the handshake cannot be completed.
+  /// 
+  /// [Connection Mode]: ConnectionMode
+  /// [TLS Settings]:    TlsSettings
+  #[cfg(feature = "tls")]
+  fn tls(
+    mut tcp: TcpStream,
+    connection_mode: ConnectionMode,
+    tls_settings: &TlsSettings,
+  ) -> Result<Self, Error> {
+    let mut connection: Connection = match (connection_mode, tls_settings) {
+      (ConnectionMode::Active, TlsSettings::Active{config, server_name}) => {
+        ClientConnection::new(config.clone(), server_name.clone())
+          .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+          .into()
+      },
+      (ConnectionMode::Passive, TlsSettings::Passive{config}) => {
+        ServerConnection::new(config.clone())
+          .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+          .into()
+      },
+      _ => return Err(Error::new(ErrorKind::InvalidInput, "TLS settings do not match connection mode")),
+    };
+    while connection.is_handshaking() {
+      connection.complete_io(&mut tcp)?;
+    }
+    log::debug!("primitive::Stream::tls: HANDSHAKE COMPLETE");
+    Ok(Self {
+      tcp,
+      tls: Some(Box::new(Mutex::new(connection))),
+    })
+  }
+
+  /// ### TCP STREAM
+  /// 
+  /// Provides the underlying TCP stream, for inspecting the addresses of the
+  /// connection or its socket options.
+  /// 
+  /// Data must not be read from or written to the TCP stream directly, as
+  /// this would corrupt an encrypted [Stream].
+  /// 
+  /// [Stream]: Stream
+  pub fn tcp_stream(&self) -> &TcpStream {
+    &self.tcp
+  }
+
+  /// ### IS ENCRYPTED
+  /// 
+  /// Whether the [Stream] is encrypted by a TLS session.
+  /// 
+  /// [Stream]: Stream
+  pub fn is_encrypted(&self) -> bool {
+    #[cfg(feature = "tls")]
+    return self.tls.is_some();
+    #[cfg(not(feature = "tls"))]
+    return false;
+  }
+
+  /// ### SHUTDOWN
+  /// 
+  /// Notifies the Remote Entity that the TLS session is closing, if
+  /// encrypted, and shuts down both halves of the TCP stream.
+  fn shutdown(&self) {
+    #[cfg(feature = "tls")]
+    if let Some(tls) = &self.tls {
+      let mut connection = tls.lock().unwrap();
+      connection.send_close_notify();
+      while connection.wants_write() {
+        if connection.write_tls(&mut &self.tcp).is_err() {break}
+      }
+    }
+    let _ = self.tcp.shutdown(Shutdown::Both);
+  }
+}
+impl std::fmt::Debug for Stream {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Stream")
+      .field("tcp", &self.tcp)
+      .field("encrypted", &self.is_encrypted())
+      .finish()
+  }
+}
+impl Read for &Stream {
+  /// ### READ
+  /// 
+  /// Reads plaintext from the [Stream], decrypting it if necessary.
+  /// 
+  /// Errors produced by the TCP stream, including those caused by its read
+  /// timeout, are passed through unchanged. Invalid data received during a
+  /// TLS session results in an error of kind [InvalidData].
+  /// 
+  /// [Stream]:      Stream
+  /// [InvalidData]: ErrorKind::InvalidData
+  fn read(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
+    #[cfg(feature = "tls")]
+    if let Some(tls) = &self.tls {
+      let mut ciphertext: [u8;4096] = [0;4096];
+      loop {
+        // Plaintext
+        match tls.lock().unwrap().reader().read(buffer) {
+          Err(error) if error.kind() == ErrorKind::WouldBlock => {},
+          result => return result,
+        }
+        // Ciphertext: The session is not locked while waiting.
+        let length: usize = (&self.tcp).read(&mut ciphertext)?;
+        if length == 0 {
+          return Ok(0)
+        }
+        let mut connection = tls.lock().unwrap();
+        let mut slice: &[u8] = &ciphertext[..length];
+        while !slice.is_empty() {
+          connection.read_tls(&mut slice)?;
+          connection.process_new_packets().map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        }
+        // Alerts and Key Updates
+        while connection.wants_write() {
+          connection.write_tls(&mut &self.tcp)?;
+        }
+      }
+    }
+    (&self.tcp).read(buffer)
+  }
+}
+impl Write for &Stream {
+  /// ### WRITE
+  /// 
+  /// Writes plaintext to the [Stream], encrypting it first if necessary.
+  /// 
+  /// [Stream]: Stream
+  fn write(&mut self, buffer: &[u8]) -> Result<usize, Error> {
+    #[cfg(feature = "tls")]
+    if let Some(tls) = &self.tls {
+      let mut connection = tls.lock().unwrap();
+      let length: usize = connection.writer().write(buffer)?;
+      while connection.wants_write() {
+        connection.write_tls(&mut &self.tcp)?;
+      }
+      return Ok(length)
+    }
+    (&self.tcp).write(buffer)
+  }
+
+  fn flush(&mut self) -> Result<(), Error> {
+    #[cfg(feature = "tls")]
+    if let Some(tls) = &self.tls {
+      let mut connection = tls.lock().unwrap();
+      while connection.wants_write() {
+        connection.write_tls(&mut &self.tcp)?;
+      }
+      return Ok(())
+    }
+    (&self.tcp).flush()
+  }
+}
+
+/// ## CONNECT ERROR
+/// 
+/// The reason for which the [Connect Procedure] has failed, wrapping the
+/// underlying [Error] where one exists.
+/// 
+/// [Connect Procedure]: Client::connect
+/// [Error]:             Error
+#[derive(Debug)]
+pub enum ConnectError {
+  /// ### ALREADY CONNECTED
+  /// 
+  /// The [Connect Procedure] was initiated while the [Client] was already in
+  /// the [CONNECTED] state.
+  /// 
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect
+  /// [CONNECTED]:         ConnectionState::Connected
+  AlreadyConnected,
+
+  /// ### INVALID ADDRESS
+  /// 
+  /// The socket address provided to the [Connect Procedure] could not be
+  /// determined.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  InvalidAddress(Error),
+
+  /// ### TIMEOUT
+  /// 
+  /// The Remote Entity did not respond to the [Connect Procedure] with a
+  /// [Connection Mode] of [ACTIVE] within the time specified by [T5].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [ACTIVE]:            ConnectionMode::Active
+  /// [T5]:                crate::generic::ParameterSettings::t5
+  Timeout(Error),
+
+  /// ### BIND
+  /// 
+  /// The socket address of the Local Entity provided to the
+  /// [Connect Procedure] with a [Connection Mode] of [PASSIVE] could not be
+  /// listened on.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  Bind(Error),
+
+  /// ### ADDRESS IN USE
+  /// 
+  /// The socket address of the Local Entity provided to the
+  /// [Connect Procedure] with a [Connection Mode] of [PASSIVE] remained in
+  /// use for the time specified by [T5], such as by another process or by a
+  /// connection which the operating system has not yet released.
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Connection Mode]:   ConnectionMode
+  /// [PASSIVE]:           ConnectionMode::Passive
+  /// [T5]:                crate::generic::ParameterSettings::t5
+  AddressInUse(Error),
+
+  /// ### IO
+  /// 
+  /// Any other failure of the TCP/IP connection during the
+  /// [Connect Procedure].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  Io(Error),
+
+  /// ### ACCEPT TIMEOUT
+  /// 
+  /// No Remote Entity connected during the [Connect Procedure] with a
+  /// [Connection Mode] of [PASSIVE] within the accept timeout provided to
+  /// the [Set Listen Options] function.
+  /// 
+  /// [Connect Procedure]:  Client::connect
+  /// [Connection Mode]:    ConnectionMode
+  /// [PASSIVE]:            ConnectionMode::Passive
+  /// [Set Listen Options]: Client::set_listen_options
+  AcceptTimeout,
+}
+impl Display for ConnectError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ConnectError::AlreadyConnected      => write!(f, "already connected"),
+      ConnectError::InvalidAddress(error) => write!(f, "invalid address: {}", error),
+      ConnectError::Timeout(error)        => write!(f, "connect timed out: {}", error),
+      ConnectError::Bind(error)           => write!(f, "bind failed: {}", error),
+      ConnectError::AddressInUse(error)   => write!(f, "address still in use: {}", error),
+      ConnectError::Io(error)             => write!(f, "{}", error),
+      ConnectError::AcceptTimeout         => write!(f, "accept timed out"),
+    }
+  }
+}
+impl std::error::Error for ConnectError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ConnectError::AlreadyConnected      => None,
+      ConnectError::InvalidAddress(error) => Some(error),
+      ConnectError::Timeout(error)        => Some(error),
+      ConnectError::Bind(error)           => Some(error),
+      ConnectError::AddressInUse(error)   => Some(error),
+      ConnectError::Io(error)             => Some(error),
+      ConnectError::AcceptTimeout         => None,
+    }
+  }
+}
+impl From<ConnectError> for Error {
+  /// ### ERROR FROM CONNECT ERROR
+  /// 
+  /// Provides compatibility with callers expecting an [Error], preserving the
+  /// underlying [Error] where one exists.
+  /// 
+  /// [Error]: Error
+  fn from(connect_error: ConnectError) -> Self {
+    match connect_error {
+      ConnectError::AlreadyConnected      => Error::from(ErrorKind::AlreadyExists),
+      ConnectError::InvalidAddress(error) => error,
+      ConnectError::Timeout(error)        => error,
+      ConnectError::Bind(error)           => error,
+      ConnectError::AddressInUse(error)   => error,
+      ConnectError::Io(error)             => error,
+      ConnectError::AcceptTimeout         => Error::from(ErrorKind::TimedOut),
+    }
+  }
+}
+
+/// ## DISCONNECT REASON
+/// 
+/// The reason for which the [Client] most recently left the [CONNECTED]
+/// state, as provided by the [Disconnect Reason] function.
+/// 
+/// [Client]:            Client
+/// [CONNECTED]:         ConnectionState::Connected
+/// [Disconnect Reason]: Client::disconnect_reason
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisconnectReason {
+  /// ### LOCAL
+  /// 
+  /// The [Disconnect Procedure] was initiated by the Local Entity, including
+  /// by a timer such as [T7] or the [Linktest Heartbeat].
+  /// 
+  /// [Disconnect Procedure]: Client::disconnect
+  /// [T7]:                   crate::generic::ParameterSettings::t7
+  /// [Linktest Heartbeat]:   crate::generic::ParameterSettings::linktest_interval
+  Local,
+
+  /// ### PEER CLOSED
+  /// 
+  /// The Remote Entity closed or reset the TCP/IP connection.
+  PeerClosed,
+
+  /// ### T8 TIMEOUT
+  /// 
+  /// A [Message] was only partially received or transmitted within the time
+  /// specified by [T8].
+  /// 
+  /// [Message]: Message
+  /// [T8]:      crate::generic::ParameterSettings::t8
+  T8Timeout,
+
+  /// ### DECODE
+  /// 
+  /// A received [Message] could not be decoded, or its Message Length was
+  /// outside of the range permitted by the [Maximum Message Length].
+  /// 
+  /// [Message]:                Message
+  /// [Maximum Message Length]: crate::generic::ParameterSettings::max_message_length
+  Decode,
+
+  /// ### IO
+  /// 
+  /// Any other failure of the TCP/IP connection, described by the kind of
+  /// the underlying [Error].
+  /// 
+  /// [Error]: Error
+  Io(ErrorKind),
+}
+impl From<&Error> for DisconnectReason {
+  /// ### DISCONNECT REASON FROM ERROR
+  /// 
+  /// Classifies the [Error] which ended the receipt or transmission of
+  /// [Message]s by its kind.
+  /// 
+  /// [Error]:   Error
+  /// [Message]: Message
+  fn from(error: &Error) -> Self {
+    match error.kind() {
+      ErrorKind::UnexpectedEof
+      | ErrorKind::ConnectionReset
+      | ErrorKind::ConnectionAborted
+      | ErrorKind::BrokenPipe => DisconnectReason::PeerClosed,
+      ErrorKind::TimedOut
+      | ErrorKind::WouldBlock => DisconnectReason::T8Timeout,
+      ErrorKind::InvalidData => DisconnectReason::Decode,
+      kind => DisconnectReason::Io(kind),
+    }
+  }
+}
+impl Display for DisconnectReason {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      DisconnectReason::Local      => write!(f, "disconnected locally"),
+      DisconnectReason::PeerClosed => write!(f, "connection closed by peer"),
+      DisconnectReason::T8Timeout  => write!(f, "t8 timed out"),
+      DisconnectReason::Decode     => write!(f, "message could not be decoded"),
+      DisconnectReason::Io(kind)   => write!(f, "{}", kind),
+    }
+  }
+}
+
+/// ## KEEPALIVE
+/// 
+/// The TCP keepalive probes (SO_KEEPALIVE) enabled on the TCP stream by the
+/// [Connect Procedure], so that the operating system detects a Remote Entity
+/// which has silently gone away, such as behind a NAT which has dropped the
+/// connection, and the TCP/IP connection fails.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// This complements the [Linktest Heartbeat], which detects the same at the
+/// [HSMS] level but only while the [Client] is able to transmit.
+/// 
+/// The [Interval] and [Retries] are only applied on platforms which expose
+/// them, and are otherwise left to the operating system's defaults.
+/// 
+/// [HSMS]:               crate
+/// [Client]:             Client
+/// [Connect Procedure]:  Client::connect
+/// [Interval]:           Keepalive::interval
+/// [Retries]:            Keepalive::retries
+/// [Linktest Heartbeat]: crate::generic::ParameterSettings::linktest_interval
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Keepalive {
+  /// ### IDLE
+  /// 
+  /// How long the TCP stream must be idle before the first probe is sent.
+  pub idle: Duration,
+
+  /// ### INTERVAL
+  /// 
+  /// How long to wait between unanswered probes.
+  pub interval: Duration,
+
+  /// ### RETRIES
+  /// 
+  /// How many unanswered probes are sent before the TCP/IP connection fails.
+  pub retries: u32,
+}
+impl Keepalive {
+  /// ### APPLY
+  /// 
+  /// Enables the [Keepalive] on a TCP stream.
+  /// 
+  /// [Keepalive]: Keepalive
+  fn apply(&self, stream: &TcpStream) -> Result<(), Error> {
+    let keepalive: TcpKeepalive = TcpKeepalive::new().with_time(self.idle);
+    #[cfg(any(
+      target_os = "android",
+      target_os = "freebsd",
+      target_os = "ios",
+      target_os = "linux",
+      target_os = "macos",
+      target_os = "netbsd",
+      target_os = "windows",
+    ))]
+    let keepalive: TcpKeepalive = keepalive.with_interval(self.interval);
+    #[cfg(any(
+      target_os = "android",
+      target_os = "freebsd",
+      target_os = "ios",
+      target_os = "linux",
+      target_os = "macos",
+      target_os = "netbsd",
+    ))]
+    let keepalive: TcpKeepalive = keepalive.with_retries(self.retries);
+    SockRef::from(stream).set_tcp_keepalive(&keepalive)
+  }
+}
+
 /// ## CONNECTION MODE
 /// **Based on SEMI E37-1109§6.3.2**
 /// 
@@ -467,6 +1818,270 @@ impl Default for ConnectionMode {
   }
 }
 
+/// ## TLS SETTINGS
+/// 
+/// The configuration of the TLS session used to encrypt the TCP/IP
+/// connection, provided to the [Client] with the [Set TLS Settings]
+/// procedure.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The variant used must match the [Connection Mode] of the
+/// [Connect Procedure], with the [ACTIVE] entity acting as the TLS client and
+/// the [PASSIVE] entity acting as the TLS server.
+/// 
+/// [Client]:            Client
+/// [Set TLS Settings]:  Client::set_tls
+/// [Connect Procedure]: Client::connect
+/// [Connection Mode]:   ConnectionMode
+/// [PASSIVE]:           ConnectionMode::Passive
+/// [ACTIVE]:            ConnectionMode::Active
+#[cfg(feature = "tls")]
+#[derive(Clone, Debug)]
+pub enum TlsSettings {
+  /// ### ACTIVE
+  /// 
+  /// Settings for the [Connection Mode] of [ACTIVE], verifying the Remote
+  /// Entity's certificate against the provided server name.
+  /// 
+  /// [Connection Mode]: ConnectionMode
+  /// [ACTIVE]:          ConnectionMode::Active
+  Active {
+    config: Arc<ClientConfig>,
+    server_name: ServerName<'static>,
+  },
+
+  /// ### PASSIVE
+  /// 
+  /// Settings for the [Connection Mode] of [PASSIVE], presenting a
+  /// certificate to the Remote Entity.
+  /// 
+  /// [Connection Mode]: ConnectionMode
+  /// [PASSIVE]:         ConnectionMode::Passive
+  Passive {
+    config: Arc<ServerConfig>,
+  },
+}
+#[cfg(feature = "tls")]
+impl TlsSettings {
+  /// ### NEW ACTIVE TLS SETTINGS
+  /// 
+  /// Builds [ACTIVE] settings which trust the provided root certificates and
+  /// expect the Remote Entity to present a certificate for the provided
+  /// server name, using the default cipher suites and protocol versions.
+  /// 
+  /// For other configurations, such as client authentication, the [ACTIVE]
+  /// variant may be constructed directly.
+  /// 
+  /// [ACTIVE]: TlsSettings::Active
+  pub fn active(
+    roots: Vec<CertificateDer<'static>>,
+    server_name: &str,
+  ) -> Result<Self, Error> {
+    let mut root_store: RootCertStore = RootCertStore::empty();
+    for root in roots {
+      root_store.add(root).map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+    }
+    let config: ClientConfig = ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+      .with_safe_default_protocol_versions()
+      .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+      .with_root_certificates(root_store)
+      .with_no_client_auth();
+    let server_name: ServerName<'static> = ServerName::try_from(server_name.to_string())
+      .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+    Ok(TlsSettings::Active {
+      config: Arc::new(config),
+      server_name,
+    })
+  }
+
+  /// ### NEW PASSIVE TLS SETTINGS
+  /// 
+  /// Builds [PASSIVE] settings which present the provided certificate chain
+  /// and private key, using the default cipher suites and protocol versions.
+  /// 
+  /// For other configurations, such as client authentication, the [PASSIVE]
+  /// variant may be constructed directly.
+  /// 
+  /// [PASSIVE]: TlsSettings::Passive
+  pub fn passive(
+    certificates: Vec<CertificateDer<'static>>,
+    private_key: PrivateKeyDer<'static>,
+  ) -> Result<Self, Error> {
+    let config: ServerConfig = ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+      .with_safe_default_protocol_versions()
+      .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?
+      .with_no_client_auth()
+      .with_single_cert(certificates, private_key)
+      .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+    Ok(TlsSettings::Passive {
+      config: Arc::new(config),
+    })
+  }
+}
+
+/// ## DIRECTION
+/// 
+/// Whether a [Message] provided to the [Trace Hook] was received or
+/// transmitted by the [Client].
+/// 
+/// [Client]:     Client
+/// [Message]:    Message
+/// [Trace Hook]: TraceHook
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+  /// ### RECEIVE
+  /// 
+  /// The [Message] was received from the Remote Entity.
+  /// 
+  /// [Message]: Message
+  Receive,
+
+  /// ### TRANSMIT
+  /// 
+  /// The [Message] was transmitted to the Remote Entity.
+  /// 
+  /// [Message]: Message
+  Transmit,
+}
+
+/// ## CLIENT STATS
+/// 
+/// A snapshot of the traffic carried by the [Client], provided by the
+/// [Client Stats] function.
+/// 
+/// [Client]:       Client
+/// [Client Stats]: Client::stats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientStats {
+  /// ### RECEIVED
+  /// 
+  /// Traffic received from the Remote Entity.
+  pub rx: DirectionStats,
+
+  /// ### TRANSMITTED
+  /// 
+  /// Traffic transmitted to the Remote Entity.
+  pub tx: DirectionStats,
+}
+
+/// ## DIRECTION STATS
+/// 
+/// The traffic carried by the [Client] in a single [Direction].
+/// 
+/// [Client]:    Client
+/// [Direction]: Direction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DirectionStats {
+  /// ### MESSAGES
+  /// 
+  /// The number of [Message]s.
+  /// 
+  /// [Message]: Message
+  pub messages: u64,
+
+  /// ### BYTES
+  /// 
+  /// The number of bytes, including the Message Length preceding each
+  /// [Message]. Bytes discarded while resynchronizing are not included.
+  /// 
+  /// [Message]: Message
+  pub bytes: u64,
+
+  /// ### SESSION TYPES
+  /// 
+  /// The number of [Message]s of each [Session Type], indexed by its value.
+  /// [Message]s with a [Session Type] above 9, which is undefined by the
+  /// standard, are counted only in the total.
+  /// 
+  /// [Message]:      Message
+  /// [Session Type]: MessageHeader::session_type
+  pub session_types: [u64; 10],
+}
+
+/// ## COUNTERS
+/// 
+/// The lock-free counters underlying the [Direction Stats].
+/// 
+/// [Direction Stats]: DirectionStats
+#[derive(Debug, Default)]
+struct Counters {
+  messages: AtomicU64,
+  bytes: AtomicU64,
+  session_types: [AtomicU64; 10],
+}
+impl Counters {
+  fn add(&self, message: &Message) {
+    self.messages.fetch_add(1, Relaxed);
+    self.bytes.fetch_add(14 + message.text.len() as u64, Relaxed);
+    if let Some(counter) = self.session_types.get(message.header.session_type as usize) {
+      counter.fetch_add(1, Relaxed);
+    }
+  }
+
+  fn load(&self) -> DirectionStats {
+    DirectionStats {
+      messages:      self.messages.load(Relaxed),
+      bytes:         self.bytes.load(Relaxed),
+      session_types: std::array::from_fn(|i| self.session_types[i].load(Relaxed)),
+    }
+  }
+}
+
+/// ## HOOK SENDER
+/// 
+/// The sending half of a hook, which holds any number of items, or waits
+/// for room once it holds as many as its capacity.
+pub(crate) enum HookSender<T> {
+  Unbounded(Sender<T>),
+  Bounded(SyncSender<T>),
+}
+impl<T> HookSender<T> {
+  /// ### CHANNEL
+  /// 
+  /// Creates a hook which is bounded if a capacity is provided.
+  pub(crate) fn channel(capacity: Option<usize>) -> (Self, Receiver<T>) {
+    match capacity {
+      None => {
+        let (sender, receiver) = channel::<T>();
+        (Self::Unbounded(sender), receiver)
+      },
+      Some(capacity) => {
+        let (sender, receiver) = sync_channel::<T>(capacity);
+        (Self::Bounded(sender), receiver)
+      },
+    }
+  }
+
+  /// ### SEND
+  /// 
+  /// Sends an item, waiting for room if the hook is full.
+  pub(crate) fn send(&self, item: T) -> Result<(), SendError<T>> {
+    match self {
+      Self::Unbounded(sender) => sender.send(item),
+      Self::Bounded(sender)   => sender.send(item),
+    }
+  }
+
+  /// ### TRY SEND
+  /// 
+  /// Sends an item, or gives it back without waiting if the hook is full.
+  pub(crate) fn try_send(&self, item: T) -> Result<(), TrySendError<T>> {
+    match self {
+      Self::Unbounded(sender) => sender.send(item).map_err(|SendError(item)| TrySendError::Disconnected(item)),
+      Self::Bounded(sender)   => sender.try_send(item),
+    }
+  }
+}
+impl<T> Clone for HookSender<T> {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Unbounded(sender) => Self::Unbounded(sender.clone()),
+      Self::Bounded(sender)   => Self::Bounded(sender.clone()),
+    }
+  }
+}
+
 /// ## MESSAGE
 /// **Based on SEMI E37-1109§8.2**
 /// 
@@ -479,7 +2094,7 @@ impl Default for ConnectionMode {
 /// 
 /// [HSMS]:   crate
 /// [Client]: Client
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Message {
   /// ### MESSAGE HEADER
   /// 
@@ -499,6 +2114,47 @@ pub struct Message {
   /// [Session Type]:      MessageHeader::session_type
   pub text: Vec<u8>,
 }
+impl Message {
+  /// ### SERIALIZE
+  /// 
+  /// Appends the raw bytes of the [Message Header] and [Message Text] to a
+  /// buffer, borrowing the [Message] rather than consuming it, so that a
+  /// caller framing the [Message] copies the [Message Text] only once.
+  /// 
+  /// [Message]:        Message
+  /// [Message Header]: MessageHeader
+  /// [Message Text]:   Message::text
+  pub fn serialize(&self, buffer: &mut Vec<u8>) {
+    let header_bytes: [u8;10] = self.header.into();
+    buffer.reserve(10 + self.text.len());
+    buffer.extend_from_slice(&header_bytes);
+    buffer.extend_from_slice(&self.text);
+  }
+
+  /// ### LOG TRACE
+  ///
+  /// Writes the [Message Header] and [Message Text] of a fully received or
+  /// serialized [Message] to the trace log, reading only from its decoded
+  /// fields so that no raw buffer is indexed.
+  ///
+  /// [Message]:        Message
+  /// [Message Header]: MessageHeader
+  /// [Message Text]:   Message::text
+  fn log_trace(&self, direction: &str) {
+    log::trace!(
+      "{} {: >4X} {: >3}{} {: >3} {: >2X} {: >2X} {: >8X} {}",
+      direction,
+      self.header.session_id,
+      self.header.byte_2 & 0b0111_1111,
+      if (self.header.byte_2 & 0b1000_0000) > 0 {'W'} else {' '},
+      self.header.byte_3,
+      self.header.presentation_type,
+      self.header.session_type,
+      self.header.system,
+      TextDisplay {presentation_type: self.header.presentation_type, text: &self.text},
+    );
+  }
+}
 impl From<&Message> for Vec<u8> {
   /// ### SERIALIZE MESSAGE
   /// 
@@ -506,10 +2162,8 @@ impl From<&Message> for Vec<u8> {
   /// 
   /// [Message]: Message
   fn from(val: &Message) -> Self {
-    let mut vec: Vec<u8> = vec![];
-    let header_bytes: [u8;10] = val.header.into();
-    vec.extend(header_bytes.iter());
-    vec.extend(&val.text);
+    let mut vec: Vec<u8> = Vec::with_capacity(10 + val.text.len());
+    val.serialize(&mut vec);
     vec
   }
 }
@@ -522,21 +2176,62 @@ impl TryFrom<Vec<u8>> for Message {
   /// 
   /// [Message]: Message
   fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-    if bytes.len() < 10 {return Err(())}
+    let (header, text) = bytes.split_first_chunk::<10>().ok_or(())?;
     Ok(Self {
-      header: MessageHeader::from(<[u8;10]>::try_from(&bytes[0..10]).map_err(|_| ())?),
-      text: bytes[10..].to_vec(),
+      header: MessageHeader::from(*header),
+      text: text.to_vec(),
     })
   }
 }
 
+/// ## TEXT DISPLAY
+/// 
+/// Formats the text of a [Message] for diagnostics.
+/// 
+/// Text with a [Presentation Type] of [SECS-II] is rendered as an indented
+/// tree of [Item]s. Any other text, or text which cannot be decoded, is
+/// rendered as raw bytes.
+/// 
+/// [Message]:           Message
+/// [Presentation Type]: MessageHeader::presentation_type
+/// [SECS-II]:           PresentationType::SecsII
+/// [Item]:              semi_e5::Item
+struct TextDisplay<'a> {
+  presentation_type: u8,
+  text: &'a [u8],
+}
+impl Display for TextDisplay<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    if self.presentation_type == PresentationType::SecsII as u8 && !self.text.is_empty() {
+      if let Ok(item) = semi_e5::Item::try_from(self.text.to_vec()) {
+        return write!(f, "\n{}", item)
+      }
+    }
+    RawTextDisplay(self.text).fmt(f)
+  }
+}
+
+/// ## RAW TEXT DISPLAY
+/// 
+/// Formats the text of a [Message] as raw bytes, regardless of its
+/// [Presentation Type].
+/// 
+/// [Message]:           Message
+/// [Presentation Type]: MessageHeader::presentation_type
+struct RawTextDisplay<'a>(&'a [u8]);
+impl Display for RawTextDisplay<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{:?}", self.0)
+  }
+}
+
 /// ## MESSAGE HEADER
 /// **Based on SEMI E37-1109§8.2.5-8.2.6**
 /// 
 /// A 10 byte field describing the contents of a [Message].
 /// 
 /// [Message]: Message
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MessageHeader {
   /// ### SESSION ID
   /// **Based on SEMI E37-1109§8.2.6.1**
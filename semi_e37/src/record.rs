@@ -0,0 +1,166 @@
+//! # FRAME RECORDING
+//! 
+//! Provides a means of recording the [Message]s received from a Remote
+//! Entity exactly as they were framed on the wire, and of reading them back,
+//! so that a field issue can be reproduced against the decoder without the
+//! Remote Entity.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A recording is a sequence of records with no global header. Each record
+//! is the time at which the [Message] was received, as 8 bytes of
+//! microseconds since the Unix epoch in network byte order, followed by the
+//! frame itself: the 4 byte Message Length, [Message Header], and
+//! [Message Text].
+//! 
+//! To record every [Message] received by a [Client], use the [Record To]
+//! function. To feed a recording through the decode path of a
+//! [Generic Client], use the `replay` function of the testing services,
+//! enabled by the `testing` feature.
+//! 
+//! [Message]:        Message
+//! [Message Header]: crate::primitive::MessageHeader
+//! [Message Text]:   Message::text
+//! [Client]:         crate::primitive::Client
+//! [Generic Client]: crate::generic::Client
+//! [Record To]:      crate::primitive::Client::record_to
+
+use std::{
+  io::{
+    Error,
+    ErrorKind,
+    Read,
+    Write,
+  },
+  time::{
+    Duration,
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+use crate::primitive::{
+  Client,
+  Message,
+};
+
+/// ## RECORD WRITER
+/// 
+/// Writes [Message]s to an underlying writer as records of a recording.
+/// 
+/// [Message]: Message
+#[derive(Debug)]
+pub struct RecordWriter<W: Write> {
+  writer: W,
+}
+impl<W: Write> RecordWriter<W> {
+  /// ### NEW RECORD WRITER
+  /// 
+  /// Creates a [Record Writer] which appends records to the provided writer.
+  /// 
+  /// [Record Writer]: RecordWriter
+  pub fn new(writer: W) -> Self {
+    Self {writer}
+  }
+
+  /// ### WRITE MESSAGE
+  /// 
+  /// Records a [Message] as it would be framed on the wire, timestamped with
+  /// the current time.
+  /// 
+  /// [Message]: Message
+  pub fn write_message(
+    &mut self,
+    message: &Message,
+  ) -> Result<(), Error> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let mut record: Vec<u8> = Vec::with_capacity(22 + message.text.len());
+    // Timestamp [Bytes 0-7]
+    record.extend_from_slice(&(timestamp.as_micros() as u64).to_be_bytes());
+    // Length [Bytes 8-11]
+    record.extend_from_slice(&(10 + message.text.len() as u32).to_be_bytes());
+    // Header + Data [Bytes 12+]
+    message.serialize(&mut record);
+    self.writer.write_all(&record)?;
+    self.writer.flush()
+  }
+
+  /// ### INTO INNER
+  /// 
+  /// Consumes the [Record Writer], returning the underlying writer.
+  /// 
+  /// [Record Writer]: RecordWriter
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+}
+
+/// ## RECORD READER
+/// 
+/// Reads the records of a recording from an underlying reader, providing
+/// each [Message] along with the time at which it was received.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// Each frame is read with the [Rx] function, so a frame whose Message
+/// Length is shorter than a [Message Header] or longer than the maximum
+/// provided fails just as it would have on the wire.
+/// 
+/// [Message]:        Message
+/// [Message Header]: crate::primitive::MessageHeader
+/// [Rx]:             Client::rx
+#[derive(Debug)]
+pub struct RecordReader<R: Read> {
+  reader: R,
+  max_message_length: u32,
+}
+impl<R: Read> RecordReader<R> {
+  /// ### NEW RECORD READER
+  /// 
+  /// Creates a [Record Reader] which reads records from the provided reader,
+  /// accepting frames up to the provided Message Length.
+  /// 
+  /// [Record Reader]: RecordReader
+  pub fn new(reader: R, max_message_length: u32) -> Self {
+    Self {reader, max_message_length}
+  }
+
+  /// ### READ MESSAGE
+  /// 
+  /// Reads the next record, providing the time at which its [Message] was
+  /// received and the [Message] itself, or [None] if the recording has
+  /// ended.
+  /// 
+  /// Fails if the recording ends partway through a record, or if the frame
+  /// cannot be read.
+  /// 
+  /// [Message]: Message
+  pub fn read_message(&mut self) -> Result<Option<(SystemTime, Message)>, Error> {
+    // Timestamp [Bytes 0-7]
+    let mut timestamp_buffer: [u8;8] = [0;8];
+    let timestamp_bytes: usize = self.reader.read(&mut timestamp_buffer)?;
+    if timestamp_bytes == 0 {return Ok(None)}
+    self.reader.read_exact(&mut timestamp_buffer[timestamp_bytes..])?;
+    let timestamp: SystemTime = UNIX_EPOCH + Duration::from_micros(u64::from_be_bytes(timestamp_buffer));
+    // Frame [Bytes 8+]
+    match Client::rx(&mut self.reader, self.max_message_length, false)? {
+      Some(message) => Ok(Some((timestamp, message))),
+      None => Err(Error::from(ErrorKind::UnexpectedEof)),
+    }
+  }
+
+  /// ### INTO INNER
+  /// 
+  /// Consumes the [Record Reader], returning the underlying reader.
+  /// 
+  /// [Record Reader]: RecordReader
+  pub fn into_inner(self) -> R {
+    self.reader
+  }
+}
+impl<R: Read> Iterator for RecordReader<R> {
+  type Item = Result<(SystemTime, Message), Error>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.read_message().transpose()
+  }
+}
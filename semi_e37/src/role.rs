@@ -0,0 +1,351 @@
+//! # ROLE SERVICES
+//! 
+//! Provides thin wrappers around the [Generic Client] which encode the
+//! asymmetry between the host and the equipment described by [GEM], so that
+//! each side of a link can be established without repeating it by hand.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! - The [Equipment Client] uses the [PASSIVE] [Connection Mode], waits for
+//!   the host to initiate the [Select Procedure], and answers the
+//!   Establish Communications Request ([S1F13]) while serving [Data Message]s.
+//! - The [Host Client] uses the [ACTIVE] [Connection Mode], initiates the
+//!   [Select Procedure], and initiates the Establish Communications Request
+//!   ([S1F13]).
+//! 
+//! Every other procedure remains available through the [Generic Client]
+//! each role wraps.
+//! 
+//! [GEM]:              https://store-us.semi.org/products/e03000-semi-e30-specification-for-the-generic-model-for-communications-and-control-of-manufacturing-equipment-gem
+//! [Generic Client]:   Client
+//! [Equipment Client]: EquipmentClient
+//! [Host Client]:      HostClient
+//! [Connection Mode]:  crate::primitive::ConnectionMode
+//! [PASSIVE]:          crate::primitive::ConnectionMode::Passive
+//! [ACTIVE]:           crate::primitive::ConnectionMode::Active
+//! [Select Procedure]: Client::select
+//! [Data Message]:     crate::generic::MessageContents::DataMessage
+//! [S1F13]:            s1::HostCR
+
+use std::{
+  fmt::{
+    self,
+    Display,
+    Formatter,
+  },
+  net::SocketAddr,
+  ops::{
+    Deref,
+    DerefMut,
+  },
+  sync::{
+    Arc,
+    mpsc::Receiver,
+    RwLock,
+  },
+};
+use semi_e5::{
+  items::{
+    CommAck,
+    ModelName,
+    SoftwareRevision,
+  },
+  messages::s1,
+};
+use crate::generic::{
+  Client,
+  ConnectionMode,
+  ConnectSelectError,
+  Inbound,
+  MessageID,
+  MessageRouter,
+  ParameterSettings,
+  SessionID,
+  TransactError,
+};
+
+/// ## ESTABLISH POLICY
+/// 
+/// A callback provided to the [On Establish] function, which is invoked each
+/// time the host sends an Establish Communications Request ([S1F13]), and
+/// decides the [COMMACK] to respond with.
+/// 
+/// [On Establish]: EquipmentClient::on_establish
+/// [S1F13]:        s1::HostCR
+/// [COMMACK]:      CommAck
+pub type EstablishPolicy = Box<dyn Fn() -> CommAck + Send + Sync>;
+
+/// ## EQUIPMENT CLIENT
+/// 
+/// A [Generic Client] taking the role of the equipment.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The [Generic Client] always uses the [PASSIVE] [Connection Mode],
+/// regardless of the [Parameter Settings] provided, and answers the
+/// Establish Communications Request ([S1F13]) with its [MDLN] and
+/// [SOFTREV] while the [Serve Procedure] is running.
+/// 
+/// [Generic Client]:     Client
+/// [Connection Mode]:    ConnectionMode
+/// [PASSIVE]:            ConnectionMode::Passive
+/// [Parameter Settings]: ParameterSettings
+/// [Serve Procedure]:    EquipmentClient::serve
+/// [S1F13]:              s1::HostCR
+/// [MDLN]:               ModelName
+/// [SOFTREV]:            SoftwareRevision
+pub struct EquipmentClient {
+  client: Arc<Client>,
+  model_name: ModelName,
+  software_revision: SoftwareRevision,
+  establish_policy: Arc<RwLock<EstablishPolicy>>,
+}
+impl EquipmentClient {
+  /// ### NEW EQUIPMENT CLIENT
+  /// 
+  /// Creates an [Equipment Client] identified by the provided [MDLN] and
+  /// [SOFTREV], which accepts every Establish Communications Request
+  /// ([S1F13]) until the [On Establish] function is used.
+  /// 
+  /// [Equipment Client]: EquipmentClient
+  /// [On Establish]:     EquipmentClient::on_establish
+  /// [S1F13]:            s1::HostCR
+  /// [MDLN]:             ModelName
+  /// [SOFTREV]:          SoftwareRevision
+  pub fn new(
+    parameter_settings: ParameterSettings,
+    model_name: ModelName,
+    software_revision: SoftwareRevision,
+  ) -> Self {
+    Self {
+      client: Client::new(ParameterSettings {
+        connect_mode: ConnectionMode::Passive,
+        ..parameter_settings
+      }),
+      model_name,
+      software_revision,
+      establish_policy: Arc::new(RwLock::new(Box::new(|| CommAck::Accepted))),
+    }
+  }
+
+  /// ### CLIENT
+  /// 
+  /// Provides the [Generic Client] wrapped by the [Equipment Client].
+  /// 
+  /// [Generic Client]:   Client
+  /// [Equipment Client]: EquipmentClient
+  pub fn client(&self) -> &Arc<Client> {
+    &self.client
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// 
+  /// Listens on the provided socket address of the Local Entity and waits
+  /// for the host to connect and initiate the [Select Procedure], as
+  /// described by the [Connect and Select Procedure].
+  /// 
+  /// [Select Procedure]:             Client::select
+  /// [Connect and Select Procedure]: Client::connect_and_select
+  pub fn connect(
+    &self,
+    entity: &str,
+  ) -> Result<(SocketAddr, Receiver<Inbound>), ConnectSelectError> {
+    // Session ID: Not Used by PASSIVE
+    self.client.connect_and_select(entity, SessionID(0))
+  }
+
+  /// ### ON ESTABLISH
+  /// 
+  /// Provides the [Equipment Client] with an [Establish Policy] to be
+  /// consulted whenever the host sends an Establish Communications Request
+  /// ([S1F13]), replacing the previously provided [Establish Policy].
+  /// 
+  /// [Equipment Client]: EquipmentClient
+  /// [Establish Policy]: EstablishPolicy
+  /// [S1F13]:            s1::HostCR
+  pub fn on_establish(
+    &self,
+    callback: impl Fn() -> CommAck + Send + Sync + 'static,
+  ) {
+    *self.establish_policy.write().unwrap().deref_mut() = Box::new(callback);
+  }
+
+  /// ### SERVE PROCEDURE
+  /// 
+  /// Answers each Primary [Data Message] received from the hook provided by
+  /// the [Connect Procedure] as described by the [Generic Serve Procedure],
+  /// with the Establish Communications Request ([S1F13]) routed to the
+  /// [Establish Policy] in place of any [Handler] the [Message Router]
+  /// provides for it.
+  /// 
+  /// [Data Message]:            crate::generic::MessageContents::DataMessage
+  /// [Connect Procedure]:       EquipmentClient::connect
+  /// [Generic Serve Procedure]: Client::serve
+  /// [Establish Policy]:        EstablishPolicy
+  /// [Handler]:                 crate::generic::Handler
+  /// [Message Router]:          MessageRouter
+  /// [S1F13]:                   s1::HostCR
+  pub fn serve(
+    &self,
+    rx_message: Receiver<Inbound>,
+    router: MessageRouter,
+  ) {
+    let model_name: ModelName = self.model_name.clone();
+    let software_revision: SoftwareRevision = self.software_revision.clone();
+    let establish_policy: Arc<RwLock<EstablishPolicy>> = self.establish_policy.clone();
+    let router: MessageRouter = router.route(1, 13, move |_request| {
+      let comm_ack: CommAck = (establish_policy.read().unwrap().deref())();
+      Some(s1::EquipmentCRA((comm_ack, (model_name.clone(), software_revision.clone()))).into())
+    });
+    self.client.serve(rx_message, &router);
+  }
+}
+
+/// ## HOST CLIENT
+/// 
+/// A [Generic Client] taking the role of the host.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The [Generic Client] always uses the [ACTIVE] [Connection Mode],
+/// regardless of the [Parameter Settings] provided, and initiates the
+/// [Select Procedure] and the Establish Communications Request ([S1F13])
+/// with the [Session ID] provided to the [New Host Client] function.
+/// 
+/// [Generic Client]:     Client
+/// [Connection Mode]:    ConnectionMode
+/// [ACTIVE]:             ConnectionMode::Active
+/// [Parameter Settings]: ParameterSettings
+/// [Select Procedure]:   Client::select
+/// [Session ID]:         MessageID::session
+/// [New Host Client]:    HostClient::new
+/// [S1F13]:              s1::HostCR
+pub struct HostClient {
+  client: Arc<Client>,
+  session: SessionID,
+}
+impl HostClient {
+  /// ### NEW HOST CLIENT
+  /// 
+  /// Creates a [Host Client] which uses the provided [Session ID].
+  /// 
+  /// [Host Client]: HostClient
+  /// [Session ID]:  MessageID::session
+  pub fn new(
+    parameter_settings: ParameterSettings,
+    session: SessionID,
+  ) -> Self {
+    Self {
+      client: Client::new(ParameterSettings {
+        connect_mode: ConnectionMode::Active,
+        ..parameter_settings
+      }),
+      session,
+    }
+  }
+
+  /// ### CLIENT
+  /// 
+  /// Provides the [Generic Client] wrapped by the [Host Client].
+  /// 
+  /// [Generic Client]: Client
+  /// [Host Client]:    HostClient
+  pub fn client(&self) -> &Arc<Client> {
+    &self.client
+  }
+
+  /// ### CONNECT PROCEDURE
+  /// 
+  /// Connects to the equipment at the provided socket address and initiates
+  /// the [Select Procedure], as described by the
+  /// [Connect and Select Procedure].
+  /// 
+  /// [Select Procedure]:             Client::select
+  /// [Connect and Select Procedure]: Client::connect_and_select
+  pub fn connect(
+    &self,
+    entity: &str,
+  ) -> Result<(SocketAddr, Receiver<Inbound>), ConnectSelectError> {
+    self.client.connect_and_select(entity, self.session)
+  }
+
+  /// ### ESTABLISH COMMUNICATIONS
+  /// **Based on SEMI E30**
+  /// 
+  /// Sends the Establish Communications Request ([S1F13]) and waits for the
+  /// Establish Communications Acknowledge ([S1F14]), providing the equipment's
+  /// [MDLN] and [SOFTREV] if it was accepted.
+  /// 
+  /// -------------------------------------------------------------------------
+  /// 
+  /// The standard recommends that a failed attempt is repeated at
+  /// programmable intervals until one is accepted, which is left to the
+  /// caller.
+  /// 
+  /// [S1F13]:   s1::HostCR
+  /// [S1F14]:   s1::EquipmentCRA
+  /// [MDLN]:    ModelName
+  /// [SOFTREV]: SoftwareRevision
+  pub fn establish_communications(
+    &self,
+  ) -> Result<(ModelName, SoftwareRevision), EstablishError> {
+    let id: MessageID = MessageID {
+      session: self.session,
+      system: self.client.next_system(),
+    };
+    let reply: semi_e5::Message = self.client.transact(id, s1::HostCR(())).map_err(EstablishError::Transact)?;
+    match s1::EquipmentCRA::try_from(reply) {
+      Ok(s1::EquipmentCRA((CommAck::Accepted, online_data))) => Ok(online_data),
+      Ok(s1::EquipmentCRA((CommAck::Denied, _))) => Err(EstablishError::Denied),
+      Err(_) => Err(EstablishError::InvalidReply),
+    }
+  }
+}
+
+/// ## ESTABLISH ERROR
+/// 
+/// The reason the [Establish Communications] function did not establish
+/// communications with the equipment.
+/// 
+/// [Establish Communications]: HostClient::establish_communications
+#[derive(Debug)]
+pub enum EstablishError {
+  /// ### TRANSACT
+  /// 
+  /// The [Transact] function failed to provide a reply.
+  /// 
+  /// [Transact]: Client::transact
+  Transact(TransactError),
+
+  /// ### DENIED
+  /// 
+  /// The equipment replied with a [COMMACK] denying the request.
+  /// 
+  /// [COMMACK]: CommAck
+  Denied,
+
+  /// ### INVALID REPLY
+  /// 
+  /// The equipment replied with something other than an Establish
+  /// Communications Acknowledge ([S1F14]).
+  /// 
+  /// [S1F14]: s1::EquipmentCRA
+  InvalidReply,
+}
+impl Display for EstablishError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      EstablishError::Transact(error) => write!(f, "{}", error),
+      EstablishError::Denied          => write!(f, "establish communications denied"),
+      EstablishError::InvalidReply    => write!(f, "invalid establish communications acknowledge"),
+    }
+  }
+}
+impl std::error::Error for EstablishError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      EstablishError::Transact(error) => Some(error),
+      _ => None,
+    }
+  }
+}
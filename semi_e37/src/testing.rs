@@ -0,0 +1,496 @@
+//! # TESTING SERVICES
+//! 
+//! Provides utilities for testing applications built on the
+//! [Generic Services] without a real Remote Entity, enabled by the `testing`
+//! feature.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! The [Pair] function stands up a [PASSIVE] and an [ACTIVE] [Client] on the
+//! loopback interface, connects them over an ephemeral port, and completes
+//! the [Select Procedure], so that a test can begin exchanging
+//! [Data Message]s immediately.
+//! 
+//! The [Mock Peer] stands in for the Remote Entity, responding to the
+//! [Message]s it receives from a [Client] according to a script, and
+//! recording them so that a test can make assertions about them.
+//! 
+//! The [Replay] function feeds a recording made by the [Frame Recording]
+//! through the same decode path as a [Client], so that a capture attached
+//! to a bug report can be run as a test.
+//! 
+//! [Generic Services]: crate::generic
+//! [Client]:           Client
+//! [Select Procedure]: Client::select
+//! [PASSIVE]:          crate::primitive::ConnectionMode::Passive
+//! [ACTIVE]:           crate::primitive::ConnectionMode::Active
+//! [Data Message]:     crate::generic::MessageContents::DataMessage
+//! [Message]:          Message
+//! [Pair]:             pair
+//! [Mock Peer]:        MockPeer
+//! [Replay]:           replay
+//! [Frame Recording]:  crate::record
+
+use std::{
+  collections::HashMap,
+  fs,
+  io::{
+    Cursor,
+    Error,
+    ErrorKind,
+    Read,
+    Write,
+  },
+  net::{
+    Ipv4Addr,
+    Shutdown,
+    SocketAddr,
+    TcpListener,
+    TcpStream,
+  },
+  ops::{
+    Deref,
+    DerefMut,
+  },
+  path::Path,
+  sync::{
+    Arc,
+    mpsc::Receiver,
+    Mutex,
+  },
+  thread::{
+    self,
+    JoinHandle,
+  },
+  time::{
+    Duration,
+    Instant,
+  },
+};
+use crate::{
+  generic::{
+    decode_frame,
+    Client,
+    ConnectError,
+    ConnectionMode,
+    DeselectStatus,
+    Inbound,
+    Message,
+    MessageContents,
+    MessageID,
+    ParameterSettings,
+    RejectReason,
+    SelectStatus,
+    SessionID,
+  },
+  primitive,
+  record::RecordReader,
+};
+
+/// ## PAIR
+/// 
+/// A [PASSIVE] and an [ACTIVE] [Client] connected to one another and in the
+/// [SELECTED] state, as provided by the [Pair] function.
+/// 
+/// The hooks returned by each [Connect Procedure] are kept here, and must not
+/// be dropped while [Data Message]s are exchanged, as a [Client] whose hook
+/// has been dropped initiates the [Disconnect Procedure] upon receiving one.
+/// 
+/// [Client]:               Client
+/// [Connect Procedure]:    Client::connect
+/// [Disconnect Procedure]: Client::disconnect
+/// [SELECTED]:             crate::generic::SelectionState::Selected
+/// [PASSIVE]:              crate::primitive::ConnectionMode::Passive
+/// [ACTIVE]:               crate::primitive::ConnectionMode::Active
+/// [Data Message]:         crate::generic::MessageContents::DataMessage
+/// [Pair]:                 pair
+pub struct Pair {
+  /// ### PASSIVE CLIENT
+  /// 
+  /// The [Client] which listened on the ephemeral port.
+  /// 
+  /// [Client]: Client
+  pub passive: Arc<Client>,
+
+  /// ### PASSIVE HOOK
+  /// 
+  /// The hook provided by the [Connect Procedure] of the [Passive Client].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Passive Client]:    Pair::passive
+  pub passive_rx: Receiver<Inbound>,
+
+  /// ### ACTIVE CLIENT
+  /// 
+  /// The [Client] which connected to the ephemeral port and initiated the
+  /// [Select Procedure].
+  /// 
+  /// [Client]:           Client
+  /// [Select Procedure]: Client::select
+  pub active: Arc<Client>,
+
+  /// ### ACTIVE HOOK
+  /// 
+  /// The hook provided by the [Connect Procedure] of the [Active Client].
+  /// 
+  /// [Connect Procedure]: Client::connect
+  /// [Active Client]:     Pair::active
+  pub active_rx: Receiver<Inbound>,
+
+  /// ### PORT
+  /// 
+  /// The ephemeral port on which the [Passive Client] listened.
+  /// 
+  /// [Passive Client]: Pair::passive
+  pub port: u16,
+
+  /// ### SESSION ID
+  /// 
+  /// The [Session ID] with which the [Select Procedure] was completed.
+  /// 
+  /// [Session ID]:       MessageID::session
+  /// [Select Procedure]: Client::select
+  pub session: SessionID,
+}
+
+/// ## PAIR CLIENTS
+/// 
+/// Provides a [Pair] using the [Default Parameter Settings].
+/// 
+/// [Pair]:                       Pair
+/// [Default Parameter Settings]: ParameterSettings::default
+pub fn pair() -> Result<Pair, Error> {
+  pair_with(ParameterSettings::default())
+}
+
+/// ## PAIR CLIENTS WITH PARAMETER SETTINGS
+/// 
+/// Provides a [Pair] whose [Client]s both use the given
+/// [Parameter Settings], apart from their [Connect Mode].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The [PASSIVE] [Client] listens on an ephemeral port on the loopback
+/// interface, chosen by providing port 0 to the [Bind] function. The
+/// [ACTIVE] [Client] retries its [Connect Procedure] for up to the time
+/// specified by [T5] while the [PASSIVE] [Client] begins waiting, and then
+/// initiates the [Select Procedure] with a [Session ID] of 0.
+/// 
+/// [Pair]:               Pair
+/// [Client]:             Client
+/// [Connect Procedure]:  Client::connect
+/// [Bind]:               Client::bind
+/// [Select Procedure]:   Client::select
+/// [Parameter Settings]: ParameterSettings
+/// [Connect Mode]:       ParameterSettings::connect_mode
+/// [T5]:                 ParameterSettings::t5
+/// [Session ID]:         MessageID::session
+/// [PASSIVE]:            crate::primitive::ConnectionMode::Passive
+/// [ACTIVE]:             crate::primitive::ConnectionMode::Active
+pub fn pair_with(
+  parameter_settings: ParameterSettings,
+) -> Result<Pair, Error> {
+  // PASSIVE: Listen on Ephemeral Port
+  let passive: Arc<Client> = Client::new(ParameterSettings {
+    connect_mode: ConnectionMode::Passive,
+    ..parameter_settings
+  });
+  let socket: SocketAddr = passive.bind(&SocketAddr::from((Ipv4Addr::LOCALHOST, 0)).to_string())?;
+  let passive_clone: Arc<Client> = passive.clone();
+  let passive_thread: JoinHandle<Result<Receiver<Inbound>, ConnectError>> = thread::spawn(move || {
+    passive_clone.connect_addr(socket).map(|(_, rx)| rx)
+  });
+  // ACTIVE: Connect
+  let active: Arc<Client> = Client::new(ParameterSettings {
+    connect_mode: ConnectionMode::Active,
+    ..parameter_settings
+  });
+  let start: Instant = Instant::now();
+  let active_rx: Receiver<Inbound> = loop {
+    match active.connect_addr(socket) {
+      Ok((_, rx)) => break rx,
+      Err(error) => {
+        if start.elapsed() >= parameter_settings.t5 {return Err(error.into())}
+        thread::sleep(Duration::from_millis(10));
+      },
+    }
+  };
+  let passive_rx: Receiver<Inbound> = passive_thread.join().map_err(|_| Error::other("passive connect panicked"))??;
+  // ACTIVE: Select
+  let session: SessionID = SessionID(0);
+  active.select(MessageID {session, system: active.next_system()}).join().map_err(|_| Error::other("select panicked"))??;
+  // Finish
+  Ok(Pair {
+    passive,
+    passive_rx,
+    active,
+    active_rx,
+    port: socket.port(),
+    session,
+  })
+}
+
+/// ## REPLAY
+/// 
+/// Reads every record of the recording at the provided path, made by the
+/// [Frame Recording], and decodes each [Message] as the provided [Client]
+/// would have upon receiving it, without a TCP/IP connection.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The recording is read into memory and each frame is read from it with
+/// the [Rx] function, subject to the [Maximum Message Length] of the
+/// [Client]. Each frame then provides either the [Message] or the
+/// [Reject Reason] with which the [Client] would have rejected it,
+/// according to its [Strict Decode] and [Strict] parameters.
+/// 
+/// Fails if the recording cannot be read, or if any frame in it cannot be.
+/// 
+/// [Client]:                 Client
+/// [Message]:                Message
+/// [Reject Reason]:          RejectReason
+/// [Frame Recording]:        crate::record
+/// [Rx]:                     crate::primitive::Client::rx
+/// [Maximum Message Length]: ParameterSettings::max_message_length
+/// [Strict Decode]:          ParameterSettings::strict_decode
+/// [Strict]:                 ParameterSettings::strict
+pub fn replay(
+  path: impl AsRef<Path>,
+  client: &Arc<Client>,
+) -> Result<Vec<Result<Message, RejectReason>>, Error> {
+  let parameter_settings: ParameterSettings = client.parameter_settings();
+  let reader = RecordReader::new(Cursor::new(fs::read(path)?), parameter_settings.max_message_length);
+  let mut decoded: Vec<Result<Message, RejectReason>> = vec![];
+  for record in reader {
+    let (_timestamp, primitive_message) = record?;
+    decoded.push(Message::decode(primitive_message, &parameter_settings));
+  }
+  Ok(decoded)
+}
+
+/// ## MOCK PEER
+/// 
+/// A scripted Remote Entity, which listens on an ephemeral port of the
+/// loopback interface, accepts a single connection from a [Client], and
+/// responds to the [Message]s it receives:
+/// 
+/// - [Select.req] - With a [Select.rsp] containing the scripted
+///   [Select Status], [Success] by default.
+/// - [Deselect.req] - With a [Deselect.rsp] containing the scripted
+///   [Deselect Status], [Success] by default.
+/// - [Linktest.req] - With a [Linktest.rsp].
+/// - Primary [Data Message] - With the Response [Data Message] scripted for
+///   its Stream and Function, if any, echoing its [Message ID].
+/// 
+/// Every [Message] received is recorded, in order.
+/// 
+/// [Client]:          Client
+/// [Message]:         Message
+/// [Message ID]:      MessageID
+/// [Select.req]:      MessageContents::SelectRequest
+/// [Select.rsp]:      MessageContents::SelectResponse
+/// [Deselect.req]:    MessageContents::DeselectRequest
+/// [Deselect.rsp]:    MessageContents::DeselectResponse
+/// [Linktest.req]:    MessageContents::LinktestRequest
+/// [Linktest.rsp]:    MessageContents::LinktestResponse
+/// [Data Message]:    MessageContents::DataMessage
+/// [Select Status]:   SelectStatus
+/// [Success]:         SelectStatus::Success
+/// [Deselect Status]: DeselectStatus
+pub struct MockPeer {
+  port: u16,
+  stream: Arc<Mutex<Option<TcpStream>>>,
+  received: Arc<Mutex<Vec<Message>>>,
+  thread: JoinHandle<()>,
+}
+
+/// ## MOCK PEER SCRIPT
+/// 
+/// Builds the responses of a [Mock Peer] before it is started.
+/// 
+/// [Mock Peer]: MockPeer
+#[derive(Clone, Debug)]
+pub struct MockPeerScript {
+  select_status: SelectStatus,
+  deselect_status: DeselectStatus,
+  replies: HashMap<(u8, u8), semi_e5::Message>,
+}
+impl Default for MockPeerScript {
+  /// ### DEFAULT MOCK PEER SCRIPT
+  /// 
+  /// Provides a [Mock Peer Script] which answers with a [Select Status] and
+  /// [Deselect Status] of [Success], and replies to no [Data Message]s.
+  /// 
+  /// [Mock Peer Script]: MockPeerScript
+  /// [Select Status]:    SelectStatus
+  /// [Deselect Status]:  DeselectStatus
+  /// [Success]:          SelectStatus::Success
+  /// [Data Message]:     MessageContents::DataMessage
+  fn default() -> Self {
+    Self {
+      select_status: SelectStatus::Success,
+      deselect_status: DeselectStatus::Success,
+      replies: HashMap::new(),
+    }
+  }
+}
+impl MockPeerScript {
+  /// ### NEW MOCK PEER SCRIPT
+  /// 
+  /// Creates a [Mock Peer Script] using the [Default Mock Peer Script].
+  /// 
+  /// [Mock Peer Script]:         MockPeerScript
+  /// [Default Mock Peer Script]: MockPeerScript::default
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// ### ON SELECT
+  /// 
+  /// Sets the [Select Status] with which every [Select.req] is answered.
+  /// 
+  /// [Select Status]: SelectStatus
+  /// [Select.req]:    MessageContents::SelectRequest
+  pub fn on_select(mut self, select_status: SelectStatus) -> Self {
+    self.select_status = select_status;
+    self
+  }
+
+  /// ### ON DESELECT
+  /// 
+  /// Sets the [Deselect Status] with which every [Deselect.req] is answered.
+  /// 
+  /// [Deselect Status]: DeselectStatus
+  /// [Deselect.req]:    MessageContents::DeselectRequest
+  pub fn on_deselect(mut self, deselect_status: DeselectStatus) -> Self {
+    self.deselect_status = deselect_status;
+    self
+  }
+
+  /// ### ON DATA
+  /// 
+  /// Sets the Response [Data Message] with which every Primary
+  /// [Data Message] of the given Stream and Function is answered.
+  /// 
+  /// [Data Message]: MessageContents::DataMessage
+  pub fn on_data(mut self, stream: u8, function: u8, reply: semi_e5::Message) -> Self {
+    self.replies.insert((stream, function), reply);
+    self
+  }
+
+  /// ### START
+  /// 
+  /// Binds an ephemeral port on the loopback interface and starts the
+  /// [Mock Peer] on a background thread, waiting for a [Client] to connect.
+  /// 
+  /// [Mock Peer]: MockPeer
+  /// [Client]:    Client
+  pub fn start(self) -> Result<MockPeer, Error> {
+    let listener: TcpListener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+    let port: u16 = listener.local_addr()?.port();
+    let stream: Arc<Mutex<Option<TcpStream>>> = Default::default();
+    let received: Arc<Mutex<Vec<Message>>> = Default::default();
+    let stream_clone = stream.clone();
+    let received_clone = received.clone();
+    let thread: JoinHandle<()> = thread::spawn(move || {
+      let Ok((tcp_stream, _)) = listener.accept() else {return};
+      let Ok(tcp_stream_clone) = tcp_stream.try_clone() else {return};
+      *stream_clone.lock().unwrap().deref_mut() = Some(tcp_stream_clone);
+      let _ = self.serve(tcp_stream, &received_clone);
+    });
+    Ok(MockPeer {
+      port,
+      stream,
+      received,
+      thread,
+    })
+  }
+
+  /// ### SERVE
+  /// 
+  /// Receives [Message]s until the connection is closed, recording and
+  /// responding to each according to the script.
+  /// 
+  /// [Message]: Message
+  fn serve(
+    &self,
+    mut stream: TcpStream,
+    received: &Mutex<Vec<Message>>,
+  ) -> Result<(), Error> {
+    loop {
+      // RX: Length + Header + Data
+      let mut frame: Vec<u8> = vec![0; 4];
+      stream.read_exact(&mut frame)?;
+      let length: usize = u32::from_be_bytes([frame[0], frame[1], frame[2], frame[3]]) as usize;
+      frame.resize(4 + length, 0);
+      stream.read_exact(&mut frame[4..])?;
+      let message: Message = decode_frame(&frame).map_err(|_| Error::from(ErrorKind::InvalidData))?;
+      received.lock().unwrap().deref_mut().push(message.clone());
+      // TX: Scripted Response
+      let contents: Option<MessageContents> = match message.contents {
+        MessageContents::SelectRequest   => Some(MessageContents::SelectResponse(self.select_status as u8)),
+        MessageContents::DeselectRequest => Some(MessageContents::DeselectResponse(self.deselect_status as u8)),
+        MessageContents::LinktestRequest => Some(MessageContents::LinktestResponse),
+        MessageContents::DataMessage(data) => self.replies.get(&(data.stream, data.function)).cloned().map(MessageContents::DataMessage),
+        _ => None,
+      };
+      if let Some(contents) = contents {
+        let primitive_message = primitive::Message::try_from(Message {id: message.id, contents}).map_err(|_| Error::from(ErrorKind::InvalidInput))?;
+        let bytes: Vec<u8> = (&primitive_message).into();
+        stream.write_all(&(bytes.len() as u32).to_be_bytes())?;
+        stream.write_all(&bytes)?;
+      }
+    }
+  }
+}
+impl MockPeer {
+  /// ### PORT
+  /// 
+  /// Provides the ephemeral port on which the [Mock Peer] is listening.
+  /// 
+  /// [Mock Peer]: MockPeer
+  pub fn port(&self) -> u16 {
+    self.port
+  }
+
+  /// ### SOCKET ADDRESS
+  /// 
+  /// Provides the socket address on which the [Mock Peer] is listening, for
+  /// use with the [Connect Procedure] of a [Client] with a
+  /// [Connection Mode] of [ACTIVE].
+  /// 
+  /// [Mock Peer]:         MockPeer
+  /// [Client]:            Client
+  /// [Connect Procedure]: Client::connect_addr
+  /// [Connection Mode]:   ConnectionMode
+  /// [ACTIVE]:            ConnectionMode::Active
+  pub fn addr(&self) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::LOCALHOST, self.port))
+  }
+
+  /// ### RECEIVED
+  /// 
+  /// Provides every [Message] received so far, in order.
+  /// 
+  /// [Message]: Message
+  pub fn received(&self) -> Vec<Message> {
+    self.received.lock().unwrap().deref().clone()
+  }
+
+  /// ### STOP
+  /// 
+  /// Closes the connection, waits for the [Mock Peer] to finish, and
+  /// provides every [Message] it received, in order.
+  /// 
+  /// [Mock Peer]: MockPeer
+  /// [Message]:   Message
+  pub fn stop(self) -> Vec<Message> {
+    match self.stream.lock().unwrap().deref() {
+      Some(stream) => {let _ = stream.shutdown(Shutdown::Both);},
+      // Unblock a Mock Peer still waiting to accept a connection.
+      None => {let _ = TcpStream::connect(self.addr());},
+    }
+    let _ = self.thread.join();
+    self.received.lock().unwrap().deref().clone()
+  }
+}
@@ -0,0 +1,46 @@
+//! # DISCONNECT GRACEFUL
+//! 
+//! Waits for open transactions to complete before disconnecting.
+
+use std::{
+  thread,
+  time::{
+    Duration,
+    Instant,
+  },
+};
+use semi_e37::{
+  generic::*,
+  testing,
+};
+
+#[test]
+fn completes_open_transaction() {
+  let pair = testing::pair().unwrap();
+  // DATA: Open Transaction
+  let active = pair.active.clone();
+  let id = MessageID {session: pair.session, system: active.next_system()};
+  let reply = thread::spawn(move || active.data(id, semi_e5::Message {stream: 1, function: 1, w: true, text: None}));
+  let inbound: Inbound = pair.passive_rx.recv_timeout(Duration::from_secs(3)).unwrap();
+  // DISCONNECT: Wakes When Reply Arrives
+  let active = pair.active.clone();
+  let start: Instant = Instant::now();
+  let graceful = thread::spawn(move || active.disconnect_graceful(Duration::from_secs(10)));
+  thread::sleep(Duration::from_millis(100));
+  inbound.reply(semi_e5::Message {stream: 1, function: 2, w: false, text: None}).unwrap();
+  assert_eq!(graceful.join().unwrap().unwrap(), 0);
+  assert!(start.elapsed() < Duration::from_secs(3));
+  assert_eq!(reply.join().unwrap().unwrap().unwrap().function, 2);
+  assert!(!pair.active.is_connected());
+}
+
+#[test]
+fn abandons_after_timeout() {
+  let pair = testing::pair().unwrap();
+  let active = pair.active.clone();
+  let id = MessageID {session: pair.session, system: active.next_system()};
+  let reply = thread::spawn(move || active.data(id, semi_e5::Message {stream: 1, function: 1, w: true, text: None}));
+  let _inbound: Inbound = pair.passive_rx.recv_timeout(Duration::from_secs(3)).unwrap();
+  assert_eq!(pair.active.disconnect_graceful(Duration::from_millis(200)).unwrap(), 1);
+  assert!(reply.join().unwrap().is_err());
+}
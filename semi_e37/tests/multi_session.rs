@@ -0,0 +1,121 @@
+//! # MULTI SESSION
+//! 
+//! Selects several sessions over one connection and routes data to each.
+
+use std::{
+  io::{
+    Read,
+    Write,
+  },
+  net::{
+    TcpListener,
+    TcpStream,
+  },
+  thread,
+  time::{
+    Duration,
+    Instant,
+  },
+};
+use semi_e37::{
+  generic::*,
+  primitive,
+  testing,
+};
+
+fn primary(stream: u8, function: u8) -> semi_e5::Message {
+  semi_e5::Message {stream, function, w: true, text: None}
+}
+
+#[test]
+fn two_sessions_route_data() {
+  let pair = testing::pair().unwrap();
+  let first: SessionID = pair.session;
+  let second: SessionID = SessionID(1);
+  pair.active.select(MessageID {session: second, system: pair.active.next_system()}).join().unwrap().unwrap();
+  assert_eq!(pair.passive.selected_sessions().len(), 2);
+  assert_eq!(pair.active.selected_sessions().len(), 2);
+  // HOOKS: One per Session
+  let first_rx = pair.passive.subscribe_session(first);
+  let second_rx = pair.passive.subscribe_session(second);
+  for (session, rx, function) in [(first, first_rx, 1), (second, second_rx, 3)] {
+    let active = pair.active.clone();
+    let system: u32 = active.next_system();
+    let reply = thread::spawn(move || active.data(MessageID {session, system}, primary(1, function)));
+    let inbound: Inbound = rx.recv_timeout(Duration::from_secs(3)).unwrap();
+    assert_eq!(inbound.id.session, session);
+    assert_eq!(inbound.message.function, function);
+    inbound.reply(semi_e5::Message {stream: 1, function: function + 1, w: false, text: None}).unwrap();
+    assert_eq!(reply.join().unwrap().unwrap().unwrap().function, function + 1);
+  }
+  // DESELECT: One Session Remains
+  pair.active.deselect(MessageID {session: second, system: pair.active.next_system()}).join().unwrap().unwrap();
+  assert_eq!(pair.active.selected_sessions(), vec![first]);
+  assert_eq!(pair.passive.selection_state(), SelectionState::Selected);
+}
+
+fn write_frame(stream: &mut TcpStream, message: Message) {
+  let primitive_message = primitive::Message::try_from(message).unwrap();
+  let bytes: Vec<u8> = Vec::from(&primitive_message);
+  stream.write_all(&(bytes.len() as u32).to_be_bytes()).unwrap();
+  stream.write_all(&bytes).unwrap();
+}
+
+fn read_frame(stream: &mut TcpStream) -> Message {
+  let mut length: [u8; 4] = [0; 4];
+  stream.read_exact(&mut length).unwrap();
+  let mut frame: Vec<u8> = length.to_vec();
+  frame.resize(4 + u32::from_be_bytes(length) as usize, 0);
+  stream.read_exact(&mut frame[4..]).unwrap();
+  decode_frame(&frame).unwrap()
+}
+
+#[test]
+fn separate_while_selecting_another_session() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let socket = listener.local_addr().unwrap();
+  let client = Client::new(ParameterSettings {
+    connect_mode: ConnectionMode::Active,
+    ..Default::default()
+  });
+  let _rx = client.connect(&socket.to_string()).unwrap();
+  let (mut peer, _) = listener.accept().unwrap();
+  // SELECT: Session 1
+  let select = client.select(MessageID {session: SessionID(1), system: 1});
+  let request: Message = read_frame(&mut peer);
+  write_frame(&mut peer, Message {id: request.id, contents: MessageContents::SelectResponse(SelectStatus::Success as u8)});
+  select.join().unwrap().unwrap();
+  // SELECT: Session 2, Answered After Separating Session 1
+  let start: Instant = Instant::now();
+  let select = client.select(MessageID {session: SessionID(2), system: 2});
+  let request: Message = read_frame(&mut peer);
+  assert_eq!(request.contents, MessageContents::SelectRequest);
+  write_frame(&mut peer, Message {id: MessageID {session: SessionID(1), system: 3}, contents: MessageContents::SeparateRequest});
+  write_frame(&mut peer, Message {id: request.id, contents: MessageContents::SelectResponse(SelectStatus::Success as u8)});
+  select.join().unwrap().unwrap();
+  assert!(start.elapsed() < ParameterSettings::default().t6 / 2);
+  assert_eq!(client.selection_state(), SelectionState::Selected);
+  assert_eq!(client.selected_sessions(), vec![SessionID(2)]);
+}
+
+#[test]
+fn session_hook_applies_inbound_overflow() {
+  let pair = testing::pair_with(ParameterSettings {
+    inbound_capacity: Some(1),
+    inbound_overflow: InboundOverflow::Drop,
+    ..Default::default()
+  }).unwrap();
+  let rx = pair.passive.subscribe_session(pair.session);
+  for _ in 0..3 {
+    let system: u32 = pair.active.next_system();
+    pair.active.data(MessageID {session: pair.session, system}, semi_e5::Message {stream: 1, function: 1, w: false, text: None}).unwrap();
+  }
+  // HOOK: One Held, Two Dropped
+  let start: Instant = Instant::now();
+  while pair.passive.dropped_inbound() < 2 && start.elapsed() < Duration::from_secs(3) {
+    thread::sleep(Duration::from_millis(10));
+  }
+  assert_eq!(pair.passive.dropped_inbound(), 2);
+  assert!(rx.try_recv().is_ok());
+  assert!(rx.try_recv().is_err());
+}
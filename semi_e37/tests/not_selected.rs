@@ -0,0 +1,54 @@
+//! # NOT SELECTED
+//! 
+//! Enforces T7 while a connection remains NOT SELECTED, including after
+//! leaving the SELECTED state.
+
+use std::{
+  net::TcpListener,
+  sync::mpsc::RecvTimeoutError,
+  thread,
+  time::{
+    Duration,
+    Instant,
+  },
+};
+use semi_e37::{
+  generic::*,
+  testing,
+};
+
+const T7: Duration = Duration::from_millis(300);
+
+#[test]
+fn disconnects_when_never_selected() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let socket = listener.local_addr().unwrap();
+  let client = Client::new(ParameterSettings {
+    connect_mode: ConnectionMode::Active,
+    t7: T7,
+    ..Default::default()
+  });
+  let start: Instant = Instant::now();
+  let (_, rx) = client.connect(&socket.to_string()).unwrap();
+  let _peer = listener.accept().unwrap();
+  assert_eq!(rx.recv_timeout(Duration::from_secs(3)).unwrap_err(), RecvTimeoutError::Disconnected);
+  assert!(start.elapsed() >= T7);
+  assert!(!client.is_connected());
+}
+
+#[test]
+fn restarts_after_separate() {
+  let pair = testing::pair_with(ParameterSettings {
+    t7: T7,
+    ..Default::default()
+  }).unwrap();
+  // SELECTED: No Timeout
+  thread::sleep(T7 * 2);
+  assert!(pair.active.is_connected());
+  assert!(pair.passive.is_connected());
+  // NOT SELECTED: Timeout
+  let start: Instant = Instant::now();
+  pair.active.separate(MessageID {session: pair.session, system: pair.active.next_system()}).join().unwrap().unwrap();
+  assert_eq!(pair.passive_rx.recv_timeout(Duration::from_secs(3)).unwrap_err(), RecvTimeoutError::Disconnected);
+  assert!(start.elapsed() >= T7);
+}
@@ -0,0 +1,45 @@
+//! # COUNT SCALARS EXAMPLE
+//! 
+//! Builds a large wafer map style frame, then counts the scalar items within
+//! it using the item reader, without building the item tree in memory.
+//! 
+//! Run with `cargo run --example count_scalars`.
+
+use semi_e5::{
+  Item,
+  streaming::{
+    Event,
+    ItemReader,
+    ItemWriter,
+  },
+};
+
+fn main() {
+  // WRITE: 1000 rows of 1000 bins, one row at a time
+  let mut writer = ItemWriter::new(vec![]);
+  writer.write(&Event::ListStart(1000)).unwrap();
+  for row in 0..1000u32 {
+    writer.write(&Event::ListStart(1000)).unwrap();
+    for column in 0..1000u32 {
+      writer.write(&Event::Scalar(Item::u1((row ^ column) as u8))).unwrap();
+    }
+    writer.write(&Event::ListEnd).unwrap();
+  }
+  writer.write(&Event::ListEnd).unwrap();
+  assert!(writer.is_complete());
+  let frame = writer.into_inner();
+  println!("frame length : {} bytes", frame.len());
+
+  // READ: count scalars without building the tree
+  let mut scalars = 0usize;
+  let mut lists = 0usize;
+  for event in ItemReader::new(frame.as_slice()) {
+    match event.unwrap() {
+      Event::ListStart(_) => lists += 1,
+      Event::ListEnd => {},
+      Event::Scalar(_) => scalars += 1,
+    }
+  }
+  println!("lists        : {}", lists);
+  println!("scalars      : {}", scalars);
+}
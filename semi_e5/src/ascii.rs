@@ -0,0 +1,156 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # ASCII CHARACTERS
+//! **Based on ANSI X3.4-1977**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! Provides the [Char] type used by [ASCII] formatted [Item]s, along with
+//! conversions between slices of [Char]s and Rust's byte slices and strings.
+//! 
+//! By default, [Char] is a type defined by this crate, allowing the crate to
+//! be built with a stable toolchain. With the `nightly` feature enabled,
+//! [Char] is instead the standard library's unstable `std::ascii::Char`, so
+//! that [Item]s may be shared directly with other code using that type.
+//! 
+//! [Item]:  crate::Item
+//! [ASCII]: crate::Item::Ascii
+//! [Char]:  Char
+
+#[cfg(feature = "nightly")]
+pub use std::ascii::Char;
+
+/// ## ASCII CHARACTER
+/// 
+/// A single byte which is guaranteed to be in the range 0x00 to 0x7F.
+#[cfg(not(feature = "nightly"))]
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Char(u8);
+#[cfg(not(feature = "nightly"))]
+impl Char {
+  /// ### FROM BYTE
+  /// 
+  /// Creates a [Char] from a byte, or [None] if the byte is not ASCII.
+  /// 
+  /// [Char]: Char
+  pub const fn from_u8(byte: u8) -> Option<Self> {
+    if byte.is_ascii() {
+      Some(Self(byte))
+    } else {
+      None
+    }
+  }
+
+  /// ### TO BYTE
+  /// 
+  /// Provides the byte value of the [Char].
+  /// 
+  /// [Char]: Char
+  pub const fn to_u8(self) -> u8 {
+    self.0
+  }
+
+  /// ### TO CHARACTER
+  /// 
+  /// Provides the [Char] as a Rust [char].
+  /// 
+  /// [Char]: Char
+  pub const fn to_char(self) -> char {
+    self.0 as char
+  }
+}
+#[cfg(not(feature = "nightly"))]
+impl std::fmt::Display for Char {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    std::fmt::Display::fmt(&self.to_char(), f)
+  }
+}
+#[cfg(not(feature = "nightly"))]
+impl std::fmt::Debug for Char {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    std::fmt::Debug::fmt(&self.to_char(), f)
+  }
+}
+
+/// ## FROM BYTES
+/// 
+/// Views a byte slice as a slice of [Char]s, or [None] if any byte is not
+/// ASCII.
+/// 
+/// [Char]: Char
+pub fn from_bytes(bytes: &[u8]) -> Option<&[Char]> {
+  #[cfg(feature = "nightly")]
+  {
+    bytes.as_ascii()
+  }
+  #[cfg(not(feature = "nightly"))]
+  {
+    if !bytes.is_ascii() {return None}
+    // SAFETY: Char is a transparent wrapper around a byte, and every byte has
+    // been verified to be ASCII.
+    Some(unsafe {std::slice::from_raw_parts(bytes.as_ptr() as *const Char, bytes.len())})
+  }
+}
+
+/// ## FROM STRING
+/// 
+/// Views a string as a slice of [Char]s, or [None] if any character is not
+/// ASCII.
+/// 
+/// [Char]: Char
+pub fn from_str(string: &str) -> Option<&[Char]> {
+  from_bytes(string.as_bytes())
+}
+
+/// ## AS BYTES
+/// 
+/// Views a slice of [Char]s as a byte slice.
+/// 
+/// [Char]: Char
+pub fn as_bytes(chars: &[Char]) -> &[u8] {
+  #[cfg(feature = "nightly")]
+  {
+    chars.as_bytes()
+  }
+  #[cfg(not(feature = "nightly"))]
+  {
+    // SAFETY: Char is a transparent wrapper around a byte.
+    unsafe {std::slice::from_raw_parts(chars.as_ptr() as *const u8, chars.len())}
+  }
+}
+
+/// ## AS STRING
+/// 
+/// Views a slice of [Char]s as a string.
+/// 
+/// [Char]: Char
+pub fn as_str(chars: &[Char]) -> &str {
+  #[cfg(feature = "nightly")]
+  {
+    chars.as_str()
+  }
+  #[cfg(not(feature = "nightly"))]
+  {
+    // SAFETY: ASCII is a subset of UTF-8.
+    unsafe {std::str::from_utf8_unchecked(as_bytes(chars))}
+  }
+}
@@ -53,8 +53,10 @@
 
 use crate::Item;
 use crate::Error::{self, *};
-use std::ascii::Char;
-use num_enum::{IntoPrimitive, TryFromPrimitive};
+use crate::ascii::{self, Char};
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use num_enum::{FromPrimitive, IntoPrimitive, TryFromPrimitive};
 
 /// ## OPTIONAL ITEM
 /// 
@@ -487,6 +489,43 @@ macro_rules! singleformat_vec {
   }
 }
 
+/// ## DATA ITEM MACRO: ASCII STRING
+/// 
+/// #### Arguments:
+/// - **$name**: Name of struct, which must use the Ascii format with a
+///   limited length.
+/// - **$range**: Range expression limiting string length.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Expansion:
+/// 
+/// - TryFrom\<&str\> for $name
+/// - Display for $name
+macro_rules! ascii_str {
+  (
+    $name:ident,
+    $range:expr
+  ) => {
+    impl TryFrom<&str> for $name {
+      type Error = Error;
+
+      fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars = ascii::from_str(value).ok_or(WrongFormat)?;
+        if !$range.contains(&chars.len()) {
+          return Err(WrongFormat)
+        }
+        Ok(Self(chars.to_vec()))
+      }
+    }
+    impl Display for $name {
+      fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(ascii::as_str(&self.0))
+      }
+    }
+  }
+}
+
 /// ## DATA ITEM MACRO: SINGLE FORMAT, ENUM
 /// 
 /// #### Arguments
@@ -801,20 +840,160 @@ singleformat!{AcknowledgeAny, Bool}
 // TODO: ACKC3
 // How to deal with 1-63 being reserved but the rest being open for user values?
 
-// TODO: ACKC5
-// How to deal with 1-63 being reserved but the rest being open for user values?
+/// ## ACKC5
+/// 
+/// **Acknowledge Code, Stream 5**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte value.
+/// 
+/// - 0 - Accepted
+/// - \>0 - Error, not accepted
+///   - 1-63 - Reserved
+/// 
+/// Values above 63 are left open for user definition, so any value without a
+/// named variant is kept as [Other].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S5F2], S5F4
+/// 
+/// [S5F2]:  crate::messages::s5::AlarmReportAcknowledge
+/// [Other]: AcknowledgeCode5::Other
+#[derive(Clone, Copy, Debug, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode5 {
+  Accepted = 0,
+  #[num_enum(catch_all)]
+  Other(u8),
+}
+singleformat_enum!{AcknowledgeCode5, Bin}
 
-// TODO: ACKC6
-// How to deal with 1-63 being reserved but the rest being open for user values?
+/// ## ACKC6
+/// 
+/// **Acknowledge Code, Stream 6**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte value.
+/// 
+/// - 0 - Accepted
+/// - \>0 - Error, not accepted
+///   - 1-63 - Reserved
+/// 
+/// Values above 63 are left open for user definition, so any value without a
+/// named variant is kept as [Other].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - S6F2, S6F4, S6F10, [S6F12], S6F14
+/// 
+/// [S6F12]: crate::messages::s6::EventReportAcknowledge
+/// [Other]: AcknowledgeCode6::Other
+#[derive(Clone, Copy, Debug, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode6 {
+  Accepted = 0,
+  #[num_enum(catch_all)]
+  Other(u8),
+}
+singleformat_enum!{AcknowledgeCode6, Bin}
 
-// TODO: ACKC7
-// How to deal with 7-63 being reserved but the rest being open for user values?
+/// ## ACKC7
+/// 
+/// **Acknowledge Code, Stream 7**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte enumerated value.
+/// 
+/// - 0 - Accepted
+/// - 1 - Permission not granted
+/// - 2 - Length error
+/// - 3 - Matrix overflow
+/// - 4 - PPID not found
+/// - 5 - Mode unsupported
+/// - 6 - Performed later
+/// - 7-63 - Reserved
+/// 
+/// Values above 63 are left open for user definition, so any value without a
+/// named variant is kept as [Other].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F4]
+/// - S7F12, S7F14, S7F16, S7F18, S7F24, S7F32, S7F38, S7F40, S7F42, S7F44
+/// 
+/// [S7F4]:  crate::messages::s7::ProcessProgramAcknowledge
+/// [Other]: AcknowledgeCode7::Other
+#[derive(Clone, Copy, Debug, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode7 {
+  Accepted = 0,
+  PermissionNotGranted = 1,
+  LengthError = 2,
+  MatrixOverflow = 3,
+  ProcessProgramIDNotFound = 4,
+  ModeUnsupported = 5,
+  PerformedLater = 6,
+  #[num_enum(catch_all)]
+  Other(u8),
+}
+singleformat_enum!{AcknowledgeCode7, Bin}
 
 // TODO: ACKC7A
 // How to deal with 6-63 being reserved but the rest being open for user values?
 
-// TODO: ACKC10
-// How to deal with 3-63 being reserved but the rest being open for user values?
+/// ## ACKC10
+/// 
+/// **Acknowledge Code, Stream 10**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte enumerated value.
+/// 
+/// - 0 - Accepted for display
+/// - 1 - Message will not be displayed
+/// - 2 - Terminal not available
+/// - 3-63 - Reserved
+/// 
+/// Values above 63 are left open for user definition, so any value without a
+/// named variant is kept as [Other].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S10F2], [S10F4], S10F6, S10F10
+/// 
+/// [S10F2]: crate::messages::s10::TerminalRequestAcknowledge
+/// [S10F4]: crate::messages::s10::TerminalDisplaySingleAcknowledge
+/// [Other]: AcknowledgeCode10::Other
+#[derive(Clone, Copy, Debug, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum AcknowledgeCode10 {
+  AcceptedForDisplay = 0,
+  WillNotBeDisplayed = 1,
+  TerminalNotAvailable = 2,
+  #[num_enum(catch_all)]
+  Other(u8),
+}
+singleformat_enum!{AcknowledgeCode10, Bin}
 
 // TODO: ACKC13
 // How to deal with 11-127 being reserved but the rest being open for user values?
@@ -864,7 +1043,9 @@ singleformat_vec!{Agent, Ascii}
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F6, S5F8
+/// - [S5F1], S5F6, S5F8
+/// 
+/// [S5F1]: crate::messages::s5::AlarmReportSend
 #[derive(Clone, Copy, Debug)]
 pub struct AlarmCode(pub u8);
 singleformat!{AlarmCode, Bin}
@@ -902,7 +1083,9 @@ singleformat_enum!{AlarmEnableDisable, Bin}
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F3, S5F5, S5F6, S5F8
+/// - [S5F1], S5F3, S5F5, S5F6, S5F8
+/// 
+/// [S5F1]: crate::messages::s5::AlarmReportSend
 #[derive(Clone, Copy, Debug)]
 pub enum AlarmID {
   I1(i8),
@@ -924,10 +1107,13 @@ multiformat!{AlarmID, I1, I2, I4, I8, U1, U2, U4, U8}
 /// 
 /// #### Used By
 /// 
-/// - S5F1, S5F6, S5F8
+/// - [S5F1], S5F6, S5F8
+/// 
+/// [S5F1]: crate::messages::s5::AlarmReportSend
 #[derive(Clone, Debug)]
 pub struct AlarmText(Vec<Char>);
 singleformat_vec!{AlarmText, Ascii, 0..=120, Char}
+ascii_str!{AlarmText, 0..=120}
 
 /// ## ATTRDATA
 /// 
@@ -1273,13 +1459,14 @@ singleformat!{CollectionEventEnableDisable, Bool}
 /// 
 /// - [S1F23], [S1F24]
 /// - [S2F35], [S2F37]
-/// - S6F3, S6F8, S6F9, S6F11, S6F13, S6F15, S6F16, S6F17, S6F18
+/// - S6F3, S6F8, S6F9, [S6F11], S6F13, S6F15, S6F16, S6F17, S6F18
 /// - S17F5, S17F9, S17F10, S17F11, S17F12
 /// 
 /// [S1F23]: crate::messages::s1::CollectionEventNamelistRequest
 /// [S1F24]: crate::messages::s1::CollectionEventNamelist
 /// [S2F35]: crate::messages::s2::LinkEventReport
 /// [S2F37]: crate::messages::s2::EnableDisableEventReport
+/// [S6F11]: crate::messages::s6::EventReportSend
 pub enum CollectionEventID {
   Ascii(Vec<Char>),
   I1(i8),
@@ -1442,6 +1629,7 @@ singleformat_enum!{CommandAcknowledge, U1}
 #[derive(Clone, Debug)]
 pub struct CommandName(Vec<Char>);
 singleformat_vec!{CommandName, Ascii, 0..=16, Char}
+ascii_str!{CommandName, 0..=16}
 
 /// ## COLCT
 /// 
@@ -1475,6 +1663,7 @@ multiformat!{ColumnCount, U1, U2, U4, U8}
 #[derive(Clone, Debug)]
 pub struct ColumnHeader(Vec<Char>);
 singleformat_vec!{ColumnHeader, Ascii, 1..=20, Char}
+ascii_str!{ColumnHeader, 1..=20}
 
 /// ## COMMACK
 /// 
@@ -1771,7 +1960,7 @@ singleformat_enum!{DataAcknowledge, Bin}
 /// - [S2F33], [S2F35], [S2F39], [S2F45], [S2F49]
 /// - S3F15, S3F17
 /// - S4F19, S4F25
-/// - S6F3, S6F5, S6F7, S6F8, S6F9, S6F11, S6F13, S6F16, S6F18, S6F25, S6F27
+/// - S6F3, S6F5, S6F7, S6F8, S6F9, [S6F11], S6F13, S6F16, S6F18, S6F25, S6F27
 /// - S13F11, S13F13, S13F15
 /// - S14F19, S14F21, S14F23
 /// - S15F1, S15F13, S15F15, S15F21, S15F23, S15F25, S15F27, S15F29, S15F33,
@@ -1784,6 +1973,7 @@ singleformat_enum!{DataAcknowledge, Bin}
 /// [S2F39]: crate::messages::s2::MultiBlockInquire
 /// [S2F45]: crate::messages::s2::DefineVariableLimitAttributes
 /// [S2F49]: crate::messages::s2::EnhancedRemoteCommand
+/// [S6F11]: crate::messages::s6::EventReportSend
 #[derive(Clone, Debug)]
 pub enum DataID {
   Ascii(Vec<Char>),
@@ -2303,6 +2493,7 @@ singleformat_enum!{EnableDisableEventReportAcknowledgeCode, Bin}
 #[derive(Clone, Debug)]
 pub struct ErrorText(Vec<Char>);
 singleformat_vec!{ErrorText, Ascii, 0..=120, Char}
+ascii_str!{ErrorText, 0..=120}
 
 /// ## FCNID
 /// 
@@ -2387,9 +2578,10 @@ singleformat_enum!{HostCommandAcknowledgeCode, Bin}
 /// #### Used By
 /// 
 /// - [S2F1]
-/// - S7F1, S7F29
+/// - [S7F1], S7F29
 /// 
 /// [S2F1]: crate::messages::s2::ServiceProgramLoadInquire
+/// [S7F1]: crate::messages::s7::ProcessProgramLoadInquire
 #[derive(Clone, Copy, Debug)]
 pub enum Length {
   I1(i8),
@@ -2651,6 +2843,27 @@ singleformat_enum!{VariableLimitDefinitonAcknowledgeCode, Bin}
 #[derive(Clone, Debug)]
 pub struct ModelName(Vec<Char>);
 singleformat_vec!{ModelName, Ascii, 0..=20, Char}
+ascii_str!{ModelName, 0..=20}
+
+/// ## MHEAD
+/// 
+/// SECS message block header associated with a message block in error,
+/// 10 bytes.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S9F1], [S9F3], [S9F5], [S9F7], [S9F11]
+/// 
+/// [S9F1]:  crate::messages::s9::UnrecognizedDeviceID
+/// [S9F3]:  crate::messages::s9::UnrecognizedStreamType
+/// [S9F5]:  crate::messages::s9::UnrecognizedFunctionType
+/// [S9F7]:  crate::messages::s9::IllegalData
+/// [S9F11]: crate::messages::s9::DataTooLong
+#[derive(Clone, Debug)]
+pub struct MessageHeader(Vec<u8>);
+singleformat_vec!{MessageHeader, Bin, 10..=10, u8}
 
 /// ## MID
 /// 
@@ -2674,6 +2887,7 @@ singleformat_vec!{ModelName, Ascii, 0..=20, Char}
 /// [S2F27]: crate::messages::s2::InitiateProcessingRequest
 pub struct MaterialID(Vec<Char>);
 singleformat_vec!{MaterialID, Ascii, 0..=80, Char}
+ascii_str!{MaterialID, 0..=80}
 
 /// ## NULBC
 /// 
@@ -2821,6 +3035,70 @@ pub enum OnLineAcknowledge {
 }
 singleformat_enum!{OnLineAcknowledge, Bin}
 
+/// ## PPBODY
+/// 
+/// Process program body.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// The contents are equipment dependent, so the body is kept as arbitrary
+/// binary data.
+/// 
+/// TODO: Implement formats 3(), 20.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F3], [S7F6]
+/// - S7F31, S7F36
+/// 
+/// [S7F3]: crate::messages::s7::ProcessProgramSend
+/// [S7F6]: crate::messages::s7::ProcessProgramData
+#[derive(Clone, Debug)]
+pub struct ProcessProgramBody(pub Vec<u8>);
+singleformat_vec!{ProcessProgramBody, Bin}
+
+/// ## PPGNT
+/// 
+/// Process program grant status.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Format
+/// 
+/// Single-byte enumerated value.
+/// 
+/// - 0 - OK
+/// - 1 - Already have
+/// - 2 - No space
+/// - 3 - Invalid PPID
+/// - 4 - Busy, try later
+/// - 5 - Will not accept
+/// - 6-63 - Reserved
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S7F2]
+/// - S7F30
+/// 
+/// [S7F2]: crate::messages::s7::ProcessProgramLoadGrant
+#[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ProcessProgramGrant {
+  Ok = 0,
+  AlreadyHave = 1,
+  NoSpace = 2,
+  InvalidProcessProgramID = 3,
+  Busy = 4,
+  WillNotAccept = 5,
+}
+singleformat_enum!{ProcessProgramGrant, Bin}
+
 /// ## PPID
 /// 
 /// Process Program ID
@@ -2842,12 +3120,17 @@ singleformat_enum!{OnLineAcknowledge, Bin}
 /// #### Used By
 /// 
 /// - [S2F27]
-/// - S7F1, S7F3, S7F5, S7F6, S7F8, S7F10, S7F11, S7F13, S7F17, S7F20, S7F23,
-///   S7F25, S7F26, S7F27, S7F31, S7F33, S7F34, S7F36, S7F39, S7F43
+/// - [S7F1], [S7F3], [S7F5], [S7F6], S7F8, S7F10, S7F11, S7F13, S7F17, S7F20,
+///   S7F23, S7F25, S7F26, S7F27, S7F31, S7F33, S7F34, S7F36, S7F39, S7F43
 /// 
 /// [S2F27]: crate::messages::s2::InitiateProcessingRequest
+/// [S7F1]:  crate::messages::s7::ProcessProgramLoadInquire
+/// [S7F3]:  crate::messages::s7::ProcessProgramSend
+/// [S7F5]:  crate::messages::s7::ProcessProgramRequest
+/// [S7F6]:  crate::messages::s7::ProcessProgramData
 pub struct ProcessProgramID(Vec<Char>);
 singleformat_vec!{ProcessProgramID, Ascii, 0..=120, Char}
+ascii_str!{ProcessProgramID, 0..=120}
 
 /// ## RAC
 /// 
@@ -2947,11 +3230,12 @@ singleformat_enum!{ResetCode, U1}
 /// #### Used By
 /// 
 /// - [S2F33], [S2F35]
-/// - S6F11, S6F13, S6F16, S6F18, S6F19, S6F21, S6F27, S6F30
+/// - [S6F11], S6F13, S6F16, S6F18, S6F19, S6F21, S6F27, S6F30
 /// - S17F1, S17F2, S17F3, S17F4, S17F5, S17F9, S17F11, S17F12
 /// 
 /// [S2F33]: crate::messages::s2::DefineReport
 /// [S2F35]: crate::messages::s2::LinkEventReport
+/// [S6F11]: crate::messages::s6::EventReportSend
 #[derive(Clone, Debug)]
 pub enum ReportID {
   Ascii(Vec<Char>),
@@ -2966,6 +3250,55 @@ pub enum ReportID {
 }
 multiformat_ascii!{ReportID, I1, I2, I4, I8, U1, U2, U4, U8}
 
+/// ## REPORT
+/// 
+/// **Report ID and Variable Data**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Not a data item itself, but the [List] pairing an [RPTID] with the [V]s of
+/// the variables defined for it, which reports are nested in.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [RPTID]
+///    2. List - N
+///       - [V]
+/// 
+/// N is the number of variables defined for the given [RPTID].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F11]
+/// 
+/// [List]:  crate::Item::List
+/// [RPTID]: ReportID
+/// [V]:     VariableData
+/// [S6F11]: crate::messages::s6::EventReportSend
+#[derive(Clone, Debug)]
+pub struct Report {
+  pub id: ReportID,
+  pub values: Vec<VariableData>,
+}
+impl From<Report> for Item {
+  fn from(report: Report) -> Self {
+    (report.id, VecList(report.values)).into()
+  }
+}
+impl TryFrom<Item> for Report {
+  type Error = Error;
+
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    let (id, VecList(values)) = item.try_into()?;
+    Ok(Self {id, values})
+  }
+}
+
 /// ## RSPACK
 /// 
 /// **Reset Spooling Acknowledge Code**
@@ -3007,6 +3340,21 @@ singleformat_enum!{ResetSpoolingAcknowledgeCode, Bin}
 pub struct StatusFormCode(pub u8);
 singleformat!{StatusFormCode, Bin}
 
+/// ## SHEAD
+/// 
+/// Stored header related to the transaction timer, 10 bytes.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S9F9]
+/// 
+/// [S9F9]: crate::messages::s9::TransactionTimerTimeout
+#[derive(Clone, Debug)]
+pub struct StoredHeader(Vec<u8>);
+singleformat_vec!{StoredHeader, Bin, 10..=10, u8}
+
 /// ## SOFTREV
 /// 
 /// Software Revision Code, 20 bytes max.
@@ -3026,6 +3374,7 @@ singleformat!{StatusFormCode, Bin}
 #[derive(Clone, Debug)]
 pub struct SoftwareRevision(Vec<Char>);
 singleformat_vec!{SoftwareRevision, Ascii, 0..=20, Char}
+ascii_str!{SoftwareRevision, 0..=20}
 
 /// ## SPAACK
 /// 
@@ -3265,6 +3614,24 @@ pub enum TableElement {
 }
 multiformat_vec!{TableElement, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
+/// ## TEXT
+/// 
+/// A single line of characters to be displayed on, or entered at, a
+/// terminal.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S10F1], [S10F3], S10F5, S10F9
+/// 
+/// [S10F1]: crate::messages::s10::TerminalRequest
+/// [S10F3]: crate::messages::s10::TerminalDisplaySingle
+#[derive(Clone, Debug)]
+pub struct TerminalText(Vec<Char>);
+singleformat_vec!{TerminalText, Ascii, 0.., Char}
+ascii_str!{TerminalText, 0..}
+
 /// ## TIAACK
 /// 
 /// Equipment acknowledge code, 1 byte.
@@ -3313,10 +3680,32 @@ pub enum TimeAcknowledgeCode {
 }
 singleformat_enum!{TimeAcknowledgeCode, Bin}
 
+/// ## TID
+/// 
+/// Terminal number, 1 byte.
+/// 
+/// A value of 0 refers to a single terminal, or to the main terminal where
+/// there are several.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S10F1], [S10F3], S10F5, S10F7
+/// 
+/// [S10F1]: crate::messages::s10::TerminalRequest
+/// [S10F3]: crate::messages::s10::TerminalDisplaySingle
+#[derive(Clone, Copy, Debug)]
+pub struct TerminalID(pub u8);
+singleformat!{TerminalID, Bin}
+
 /// ## TIME
 /// 
 /// Time of day.
 /// 
+/// The fixed-length formats may be checked with the [Format] function and
+/// converted to and from [SystemTime], as Coordinated Universal Time.
+/// 
 /// TODO: Implement specific format restrictions.
 /// 
 /// -------------------------------------------------------------------------
@@ -3363,11 +3752,168 @@ singleformat_enum!{TimeAcknowledgeCode, Bin}
 /// 
 /// - [S2F18], [S2F31]
 /// 
-/// [S2F18]: crate::messages::s2::DateTimeData
-/// [S2F31]: crate::messages::s2::DateTimeSetRequest
+/// [S2F18]:      crate::messages::s2::DateTimeData
+/// [S2F31]:      crate::messages::s2::DateTimeSetRequest
+/// [Format]:     Time::format
+/// [SystemTime]: SystemTime
 #[derive(Clone, Debug)]
-pub struct Time(pub Vec<Char>);
-singleformat_vec!{Time, Ascii}
+pub struct Time(Vec<Char>);
+singleformat_vec!{Time, Ascii, 0..=32, Char}
+ascii_str!{Time, 0..=32}
+impl Time {
+  /// ### FORMAT
+  /// 
+  /// Reports which of the fixed-length [Time Format]s the [Time] is written
+  /// in, if any, having checked that each of its fields is in range.
+  /// 
+  /// The extended format described by SEMI E148 is not recognized.
+  /// 
+  /// [Time]:        Time
+  /// [Time Format]: TimeFormat
+  pub fn format(&self) -> Option<TimeFormat> {
+    Some(self.fields()?.0)
+  }
+
+  /// ### FROM SYSTEM TIME
+  /// 
+  /// Writes a [SystemTime] in the chosen [Time Format], as Coordinated
+  /// Universal Time.
+  /// 
+  /// Returns [None] if the [SystemTime] is before the Unix epoch, or if its
+  /// year cannot be written in the chosen [Time Format].
+  /// 
+  /// [SystemTime]:  SystemTime
+  /// [Time Format]: TimeFormat
+  pub fn from_system_time(time: SystemTime, format: TimeFormat) -> Option<Self> {
+    let duration: Duration = time.duration_since(UNIX_EPOCH).ok()?;
+    let seconds: u64 = duration.as_secs();
+    let (year, month, day) = civil_from_days(seconds / 86400);
+    let (hour, minute, second) = (seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+    let text: String = match format {
+      TimeFormat::Short => {
+        if !(1970..2070).contains(&year) {return None}
+        format!("{:02}{:02}{:02}{:02}{:02}{:02}", year % 100, month, day, hour, minute, second)
+      },
+      TimeFormat::Long => {
+        if year > 9999 {return None}
+        let centisecond: u32 = duration.subsec_millis() / 10;
+        format!("{:04}{:02}{:02}{:02}{:02}{:02}{:02}", year, month, day, hour, minute, second, centisecond)
+      },
+    };
+    Some(Self(ascii::from_str(&text)?.to_vec()))
+  }
+
+  /// ### TO SYSTEM TIME
+  /// 
+  /// Reads the [Time] as a [SystemTime], treating it as Coordinated
+  /// Universal Time.
+  /// 
+  /// A two-digit year from 70 to 99 is read as 1970 to 1999, and a two-digit
+  /// year from 00 to 69 is read as 2000 to 2069.
+  /// 
+  /// Returns [None] if the [Time] is not in a fixed-length [Time Format], or
+  /// if it is before the Unix epoch.
+  /// 
+  /// [Time]:        Time
+  /// [SystemTime]:  SystemTime
+  /// [Time Format]: TimeFormat
+  pub fn to_system_time(&self) -> Option<SystemTime> {
+    let (_, [year, month, day, hour, minute, second, centisecond]) = self.fields()?;
+    if year < 1970 {return None}
+    let days: u64 = days_from_civil(year, month, day);
+    let seconds: u64 = days * 86400 + hour * 3600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds) + Duration::from_millis(centisecond * 10))
+  }
+
+  /// ### FIELDS
+  /// 
+  /// Splits the [Time] into its year, month, day, hour, minute, second, and
+  /// centisecond, expanding a two-digit year, if each is in range.
+  /// 
+  /// [Time]: Time
+  fn fields(&self) -> Option<(TimeFormat, [u64; 7])> {
+    let bytes: &[u8] = ascii::as_bytes(&self.0);
+    if !bytes.iter().all(u8::is_ascii_digit) {return None}
+    let number = |range: std::ops::Range<usize>| -> u64 {
+      bytes[range].iter().fold(0, |n, b| n * 10 + (b - b'0') as u64)
+    };
+    let (format, fields) = match bytes.len() {
+      12 => {
+        let year: u64 = match number(0..2) {
+          year @ 70.. => 1900 + year,
+          year => 2000 + year,
+        };
+        (TimeFormat::Short, [year, number(2..4), number(4..6), number(6..8), number(8..10), number(10..12), 0])
+      },
+      16 => (TimeFormat::Long, [number(0..4), number(4..6), number(6..8), number(8..10), number(10..12), number(12..14), number(14..16)]),
+      _ => return None,
+    };
+    let [year, month, day, hour, minute, second, _] = fields;
+    if !(1..=12).contains(&month) {return None}
+    if day < 1 || day > days_in_month(year, month) {return None}
+    if hour > 23 || minute > 59 || second > 59 {return None}
+    Some((format, fields))
+  }
+}
+
+/// ## TIME FORMAT
+/// 
+/// The fixed-length formats in which a [Time] may be written.
+/// 
+/// [Time]: Time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeFormat {
+  /// ### SHORT
+  /// 
+  /// 12-byte format, YYMMDDhhmmss.
+  Short,
+
+  /// ### LONG
+  /// 
+  /// 16-byte format, YYYYMMDDhhmmsscc.
+  Long,
+}
+
+/// ## DAYS IN MONTH
+/// 
+/// The number of days in a month of the Gregorian calendar.
+fn days_in_month(year: u64, month: u64) -> u64 {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+/// ## DAYS FROM CIVIL
+/// 
+/// The number of days from the Unix epoch to a date of the Gregorian
+/// calendar no earlier than the Unix epoch.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+  let year: u64 = if month <= 2 {year - 1} else {year};
+  let era: u64 = year / 400;
+  let year_of_era: u64 = year - era * 400;
+  let day_of_year: u64 = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+  let day_of_era: u64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146097 + day_of_era - 719468
+}
+
+/// ## CIVIL FROM DAYS
+/// 
+/// The date of the Gregorian calendar a number of days after the Unix epoch.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+  let days: u64 = days + 719468;
+  let era: u64 = days / 146097;
+  let day_of_era: u64 = days - era * 146097;
+  let year_of_era: u64 = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year: u64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index: u64 = (5 * day_of_year + 2) / 153;
+  let day: u64 = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month: u64 = if month_index < 10 {month_index + 3} else {month_index - 9};
+  let year: u64 = era * 400 + year_of_era + if month <= 2 {1} else {0};
+  (year, month, day)
+}
 
 /// ## TOTSMP
 /// 
@@ -3517,6 +4063,63 @@ pub enum UpperDeadband {
 }
 multiformat_vec!{UpperDeadband, Bool, Ascii, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
 
+/// ## V
+/// 
+/// **Variable Data**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Used By
+/// 
+/// - [S6F11], S6F13, S6F16, S6F20, S6F22
+/// 
+/// [S6F11]: crate::messages::s6::EventReportSend
+#[derive(Clone, Debug)]
+pub enum VariableData {
+  List(Vec<Item>),
+  Bin(Vec<u8>),
+  Bool(Vec<bool>),
+  Ascii(Vec<Char>),
+  Jis8(String),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+multiformat_vec!{VariableData, List, Bin, Bool, Ascii, Jis8, I1, I2, I4, I8, U1, U2, U4, U8, F4, F8}
+impl From<StatusVariableValue> for VariableData {
+  /// ### SV -> V
+  /// 
+  /// The value of a status variable reported as [Variable Data].
+  /// 
+  /// [Variable Data]: VariableData
+  fn from(value: StatusVariableValue) -> Self {
+    match value {
+      StatusVariableValue::List(vec)  => Self::List(vec),
+      StatusVariableValue::Bin(vec)   => Self::Bin(vec),
+      StatusVariableValue::Bool(vec)  => Self::Bool(vec),
+      StatusVariableValue::Ascii(vec) => Self::Ascii(vec),
+      StatusVariableValue::Jis8(vec)  => Self::Jis8(vec),
+      StatusVariableValue::I1(vec)    => Self::I1(vec),
+      StatusVariableValue::I2(vec)    => Self::I2(vec),
+      StatusVariableValue::I4(vec)    => Self::I4(vec),
+      StatusVariableValue::I8(vec)    => Self::I8(vec),
+      StatusVariableValue::U1(vec)    => Self::U1(vec),
+      StatusVariableValue::U2(vec)    => Self::U2(vec),
+      StatusVariableValue::U4(vec)    => Self::U4(vec),
+      StatusVariableValue::U8(vec)    => Self::U8(vec),
+      StatusVariableValue::F4(vec)    => Self::F4(vec),
+      StatusVariableValue::F8(vec)    => Self::F8(vec),
+    }
+  }
+}
+
 /// ## VID
 /// 
 /// **Variable ID**
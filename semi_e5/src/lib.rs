@@ -48,9 +48,27 @@
 //! 
 //! ---------------------------------------------------------------------------
 //! 
+//! ## TOOLCHAIN
+//! 
+//! The crate builds with stable Rust, with a minimum supported version of
+//! 1.82. Enabling the `nightly` feature represents [ASCII] characters with
+//! the standard library's unstable type instead, which requires a nightly
+//! toolchain.
+//! 
+//! Enabling the `serde` feature implements `Serialize` and `Deserialize` for
+//! [Message]s and [Item]s, representing each [Item] by its format, named as
+//! in [SML], and its values, with binary values as base64 strings.
+//! 
+//! [ASCII]:   ascii
+//! [Message]: Message
+//! [Item]:    Item
+//! [SML]:     sml
+//! 
+//! ---------------------------------------------------------------------------
+//! 
 //! ## TO BE DONE
 //! 
-//! - Implement "Localized" strings.
+//! - Convert "Localized" strings to and from text.
 //! - Finish adding items.
 //! - Add messages to Streams 3 through 21.
 //! 
@@ -74,19 +92,26 @@
 //! 
 //! [SECS-II]:  crate
 
-#![feature(ascii_char)]
-#![feature(ascii_char_variants)]
+#![cfg_attr(feature = "nightly", feature(ascii_char))]
 #![allow(clippy::unusual_byte_groupings)]
 #![allow(clippy::collapsible_match)]
 #![allow(clippy::type_complexity)]
 
+pub mod ascii;
 pub mod format;
 pub mod items;
 pub mod messages;
+pub mod sml;
+pub mod streaming;
 pub mod units;
 
-use std::ascii::Char;
+#[cfg(feature = "serde")]
+mod serialization;
+
+use std::fmt::{self, Display, Formatter};
+use ascii::Char;
 use encoding::{all::ISO_2022_JP, Encoding};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 /// ## GENERIC MESSAGE
 /// **Based on SEMI E5§6**
@@ -96,7 +121,8 @@ use encoding::{all::ISO_2022_JP, Encoding};
 /// protocol. May contain an [Item].
 /// 
 /// [Item]: Item
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Message {
   /// ### STREAM
   /// **Based on SEMI E5§6.4.2**
@@ -141,6 +167,98 @@ pub struct Message {
   /// - [Some] - Indicates a message with contents after the header.
   pub text: Option<Item>,
 }
+impl Message {
+  /// ### HEADER ONLY MESSAGE
+  /// 
+  /// Creates a [Message] with no [Text], such as S1F1 or S1F0.
+  /// 
+  /// [Message]: Message
+  /// [Text]:    Message::text
+  pub fn header_only(stream: u8, function: u8, w: bool) -> Self {
+    Self {
+      stream,
+      function,
+      w,
+      text: None,
+    }
+  }
+
+  /// ### MESSAGE WITH ITEM
+  /// 
+  /// Creates a [Message] whose [Text] is the provided [Item].
+  /// 
+  /// [Message]: Message
+  /// [Text]:    Message::text
+  /// [Item]:    Item
+  pub fn with_item(stream: u8, function: u8, w: bool, item: Item) -> Self {
+    Self {
+      stream,
+      function,
+      w,
+      text: Some(item),
+    }
+  }
+
+  /// ### IS PRIMARY
+  /// **Based on SEMI E5§6.4.2**
+  /// 
+  /// Whether the [Message] is a primary [Message], having an odd [Function],
+  /// rather than a reply [Message], having an even [Function].
+  /// 
+  /// [Message]:  Message
+  /// [Function]: Message::function
+  pub fn is_primary(&self) -> bool {
+    self.function % 2 == 1
+  }
+
+  /// ### EXPECTS REPLY
+  /// **Based on SEMI E5§6.4.3**
+  /// 
+  /// Whether the [Message] is a primary [Message] with the [Reply Bit] set,
+  /// so that a reply [Message] is expected in return.
+  /// 
+  /// [Message]:   Message
+  /// [Reply Bit]: Message::w
+  pub fn expects_reply(&self) -> bool {
+    self.is_primary() && self.w
+  }
+
+  /// ### ENCODED LENGTH
+  /// 
+  /// The number of bytes the [Message] occupies once serialized, being the
+  /// 10 byte message header shared by SECS-I and HSMS followed by the
+  /// [Encoded Length] of its [Text], computed without serializing it.
+  /// 
+  /// Any bytes added by the message transfer protocol, such as the Message
+  /// Length of HSMS, are not included.
+  /// 
+  /// [Message]:        Message
+  /// [Text]:           Message::text
+  /// [Encoded Length]: Item::encoded_len
+  pub fn encoded_len(&self) -> usize {
+    10 + self.text.as_ref().map_or(0, Item::encoded_len)
+  }
+
+  /// ### VALIDATE
+  /// **Based on SEMI E5§6.4.3**
+  /// 
+  /// Checks that the [Message] obeys the rules of the [Reply Bit], which may
+  /// only be set on a primary [Message], having an odd [Function].
+  /// 
+  /// Fails with [Wrong Reply] if the [Reply Bit] is set on a reply
+  /// [Message], having an even [Function], including [Function] 0.
+  /// 
+  /// [Message]:     Message
+  /// [Function]:    Message::function
+  /// [Reply Bit]:   Message::w
+  /// [Wrong Reply]: Error::WrongReply
+  pub fn validate(&self) -> Result<(), Error> {
+    if self.w && !self.is_primary() {
+      return Err(Error::WrongReply)
+    }
+    Ok(())
+  }
+}
 
 /// ## DATA CONVERSION ERROR
 /// 
@@ -163,6 +281,19 @@ pub enum Error {
   /// invalid format.
   InvalidText,
 
+  /// ### UNEXPECTED END
+  /// 
+  /// Binary data was attempted to be converted into an [Item] despite ending
+  /// before the [Item] was complete, such as when a length promises more
+  /// bytes than are present.
+  /// 
+  /// Unlike [Invalid Text], the data received so far may be the start of a
+  /// valid [Item] which has been cut short.
+  /// 
+  /// [Item]:         Item
+  /// [Invalid Text]: Error::InvalidText
+  UnexpectedEnd,
+
   /// ### WRONG STREAM
   /// 
   /// A [Generic Message] was attempted to be converted into a specifc [Message]
@@ -198,11 +329,43 @@ pub enum Error {
   /// A [Generic Message] was attempted to be converted into a specifc [Message]
   /// despite containing an improperly formatted [Message Body].
   /// 
+  /// Also, binary data was attempted to be converted into an [Item] despite
+  /// the length of a multi-byte numeric [Item] not being a multiple of the
+  /// size of its values, such as a 4-byte signed integer [Item] with a
+  /// length of 9.
+  /// 
   /// [Message]:         messages
   /// [Generic Message]: Message
   /// [Message Body]:    Message::text
+  /// [Item]:            Item
   WrongFormat,
+
+  /// ### ITEM TOO LONG
+  /// 
+  /// An [Item] was attempted to be converted into binary data despite its
+  /// length exceeding the maximum of 16,777,215 which can be represented
+  /// with 3 length bytes, or the maximum of 65,535 which can be represented
+  /// when 2 length bytes are required by the [Length Bytes Mode].
+  /// 
+  /// [Item]:              Item
+  /// [Length Bytes Mode]: LengthBytesMode
+  ItemTooLong,
+}
+impl Display for Error {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::EmptyText     => write!(f, "empty text"),
+      Error::InvalidText   => write!(f, "invalid text"),
+      Error::UnexpectedEnd => write!(f, "unexpected end of text"),
+      Error::WrongStream   => write!(f, "wrong stream"),
+      Error::WrongFunction => write!(f, "wrong function"),
+      Error::WrongReply    => write!(f, "wrong reply bit"),
+      Error::WrongFormat   => write!(f, "wrong format"),
+      Error::ItemTooLong   => write!(f, "item too long"),
+    }
+  }
 }
+impl std::error::Error for Error {}
 
 /// ## GENERIC ITEM
 /// **Based on SEMI E5§9**
@@ -325,7 +488,52 @@ pub enum Item {
   /// 8-byte IEEE-754 floating point number.
   F8(Vec<f64>) = format::F8,
 }
+impl PartialEq for Item {
+  /// ### STRUCTURAL EQUALITY
+  /// 
+  /// Compares two [Item]s by [Format] and contents, recursing into [List]s.
+  /// 
+  /// Floating point values are compared by their bit patterns rather than
+  /// numerically, so that an [Item] always equals itself, including when it
+  /// contains NaN, and 0.0 and -0.0 are considered different, exactly as
+  /// they would be after serialization.
+  /// 
+  /// [Item]:   Item
+  /// [Format]: crate::format
+  /// [List]:   Item::List
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Item::List(a),       Item::List(b))      => a == b,
+      (Item::Ascii(a),      Item::Ascii(b))     => a == b,
+      (Item::Jis8(a),       Item::Jis8(b))      => a == b,
+      (Item::Local(ah, a),  Item::Local(bh, b)) => ah == bh && a == b,
+      (Item::Bin(a),        Item::Bin(b))       => a == b,
+      (Item::Bool(a),       Item::Bool(b))      => a == b,
+      (Item::I1(a),         Item::I1(b))        => a == b,
+      (Item::I2(a),         Item::I2(b))        => a == b,
+      (Item::I4(a),         Item::I4(b))        => a == b,
+      (Item::I8(a),         Item::I8(b))        => a == b,
+      (Item::U1(a),         Item::U1(b))        => a == b,
+      (Item::U2(a),         Item::U2(b))        => a == b,
+      (Item::U4(a),         Item::U4(b))        => a == b,
+      (Item::U8(a),         Item::U8(b))        => a == b,
+      (Item::F4(a),         Item::F4(b))        => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.to_bits() == b.to_bits()),
+      (Item::F8(a),         Item::F8(b))        => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.to_bits() == b.to_bits()),
+      _ => false,
+    }
+  }
+}
+impl Eq for Item {}
 impl Item {
+  /// ### MAXIMUM LIST DEPTH
+  /// 
+  /// The deepest nesting of [List]s accepted when deserializing binary data
+  /// into an [Item].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  pub const MAX_LIST_DEPTH: usize = 256;
+
   /// ### SINGLE BINARY ITEM
   /// 
   /// Constructs a [Binary] [Item] with a single member.
@@ -470,309 +678,444 @@ impl Item {
     Self::F8(vec![value])
   }
 }
-impl From<Item> for Vec<u8> {
-  /// ### ITEM -> BINARY DATA
+impl Item {
+  /// ### ENCODE
   /// 
-  /// Infallable serialization of an [Item], which can represent an entire tree
+  /// Fallable serialization of an [Item], which can represent an entire tree
   /// of [Item]s due to [List]s, into binary data.
   /// 
-  /// [Item]: Item
-  /// [List]: Item::List
-  fn from(item: Item) -> Self {
+  /// Fails with [Item Too Long] if the length of any [Item] in the tree
+  /// cannot be represented with 3 length bytes.
+  /// 
+  /// [Item]:          Item
+  /// [List]:          Item::List
+  /// [Item Too Long]: Error::ItemTooLong
+  pub fn encode(&self) -> Result<Vec<u8>, Error> {
+    self.encode_with(EncodeOptions::default())
+  }
+
+  /// ### ENCODE WITH OPTIONS
+  /// 
+  /// Fallable serialization of an [Item], which can represent an entire tree
+  /// of [Item]s due to [List]s, into binary data according to the provided
+  /// [Encode Options].
+  /// 
+  /// Fails with [Item Too Long] if the length of any [Item] in the tree
+  /// cannot be represented with the number of length bytes allowed by the
+  /// [Length Bytes Mode].
+  /// 
+  /// [Item]:              Item
+  /// [List]:              Item::List
+  /// [Encode Options]:    EncodeOptions
+  /// [Length Bytes Mode]: LengthBytesMode
+  /// [Item Too Long]:     Error::ItemTooLong
+  pub fn encode_with(&self, options: EncodeOptions) -> Result<Vec<u8>, Error> {
     let mut vec = vec![];
-    match item {
+    self.serialize_into_with(&mut vec, options)?;
+    Ok(vec)
+  }
+
+  /// ### ENCODED LENGTH
+  /// 
+  /// The number of bytes produced by the [Encode] function, computed without
+  /// serializing the [Item].
+  /// 
+  /// The result is meaningless if the [Encode] function would fail with
+  /// [Item Too Long].
+  /// 
+  /// [Item]:          Item
+  /// [Encode]:        Item::encode
+  /// [Item Too Long]: Error::ItemTooLong
+  pub fn encoded_len(&self) -> usize {
+    self.encoded_len_with(EncodeOptions::default())
+  }
+
+  /// ### ENCODED LENGTH WITH OPTIONS
+  /// 
+  /// The number of bytes produced by the [Encode With Options] function when
+  /// provided the same [Encode Options], computed without serializing the
+  /// [Item].
+  /// 
+  /// The result is meaningless if the [Encode With Options] function would
+  /// fail with [Item Too Long].
+  /// 
+  /// [Item]:                Item
+  /// [Encode With Options]: Item::encode_with
+  /// [Encode Options]:      EncodeOptions
+  /// [Item Too Long]:       Error::ItemTooLong
+  pub fn encoded_len_with(&self, options: EncodeOptions) -> usize {
+    let body: usize = match self {
+      // A List's length counts its Items rather than its bytes.
+      Item::List(vec) => {
+        let items: usize = vec.iter().map(|item| item.encoded_len_with(options)).sum();
+        return Self::header_len(vec.len(), options.length_bytes) + items
+      },
+      Item::Ascii(vec)    => vec.len(),
+      Item::Jis8(string)  => ISO_2022_JP.encode(string, encoding::EncoderTrap::Ignore).unwrap().len(),
+      // A Localized String's length counts its 2 byte header.
+      Item::Local(_, vec) => 2 + vec.len(),
+      Item::Bin(vec)      => vec.len(),
+      Item::Bool(vec)     => vec.len(),
+      Item::I1(vec)       => vec.len(),
+      Item::I2(vec)       => vec.len() * 2,
+      Item::I4(vec)       => vec.len() * 4,
+      Item::I8(vec)       => vec.len() * 8,
+      Item::U1(vec)       => vec.len(),
+      Item::U2(vec)       => vec.len() * 2,
+      Item::U4(vec)       => vec.len() * 4,
+      Item::U8(vec)       => vec.len() * 8,
+      Item::F4(vec)       => vec.len() * 4,
+      Item::F8(vec)       => vec.len() * 8,
+    };
+    Self::header_len(body, options.length_bytes) + body
+  }
+
+  /// ### HEADER LENGTH
+  /// 
+  /// The number of bytes in the header of an [Item] with the provided length,
+  /// being the format byte and the length bytes chosen by the
+  /// [Length Bytes Mode].
+  /// 
+  /// [Item]:              Item
+  /// [Length Bytes Mode]: LengthBytesMode
+  fn header_len(len: usize, mode: LengthBytesMode) -> usize {
+    1 + mode.length_bytes(len)
+  }
+
+  /// ### SERIALIZE INTO
+  /// 
+  /// Fallable serialization of an [Item], which can represent an entire tree
+  /// of [Item]s due to [List]s, appending the binary data to an existing
+  /// buffer rather than allocating a new one.
+  /// 
+  /// Fails with [Item Too Long] if the length of any [Item] in the tree
+  /// cannot be represented with 3 length bytes, in which case the contents of
+  /// the buffer are unspecified.
+  /// 
+  /// [Item]:          Item
+  /// [List]:          Item::List
+  /// [Item Too Long]: Error::ItemTooLong
+  pub fn serialize_into(&self, vec: &mut Vec<u8>) -> Result<(), Error> {
+    self.serialize_into_with(vec, EncodeOptions::default())
+  }
+
+  /// ### SERIALIZE INTO WITH OPTIONS
+  /// 
+  /// Fallable serialization of an [Item], which can represent an entire tree
+  /// of [Item]s due to [List]s, appending the binary data to an existing
+  /// buffer according to the provided [Encode Options].
+  /// 
+  /// Fails with [Item Too Long] if the length of any [Item] in the tree
+  /// cannot be represented with the number of length bytes allowed by the
+  /// [Length Bytes Mode], in which case the contents of the buffer are
+  /// unspecified.
+  /// 
+  /// [Item]:              Item
+  /// [List]:              Item::List
+  /// [Encode Options]:    EncodeOptions
+  /// [Length Bytes Mode]: LengthBytesMode
+  /// [Item Too Long]:     Error::ItemTooLong
+  pub fn serialize_into_with(&self, vec: &mut Vec<u8>, options: EncodeOptions) -> Result<(), Error> {
+    match self {
       // List
       Item::List(item_vec) => {
-        //Length
-        let len = item_vec.len();
-        if len < 256 {
-          vec.push(format::LIST | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::LIST | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::LIST | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Items
+        Self::serialize_header(vec, format::LIST, item_vec.len(), options.length_bytes)?;
         for item in item_vec {
-          vec.append(&mut item.into());
+          item.serialize_into_with(vec, options)?;
         }
       },
       // ASCII
       Item::Ascii(ascii_vec) => {
-        //Length
-        let len = ascii_vec.len();
-        if len < 256 {
-          vec.push(format::ASCII | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::ASCII | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::ASCII | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for ascii in ascii_vec {
-          vec.push(ascii as u8);
-        }
+        Self::serialize_scalar_header(vec, format::ASCII, ascii_vec.len(), options.length_bytes)?;
+        vec.extend_from_slice(ascii::as_bytes(ascii_vec));
       },
       // JIS-8
       Item::Jis8(jis8_string) => {
-        // Encode
-        let encoded = ISO_2022_JP.encode(&jis8_string, encoding::EncoderTrap::Ignore).unwrap();
-        // Item Code + Length
-        let len = encoded.len();
-        if len < 256 {
-          vec.push(format::JIS8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::JIS8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::JIS8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        // Vector
+        let encoded = ISO_2022_JP.encode(jis8_string, encoding::EncoderTrap::Ignore).unwrap();
+        Self::serialize_scalar_header(vec, format::JIS8, encoded.len(), options.length_bytes)?;
         vec.extend_from_slice(&encoded);
       },
-      // Localized String (TODO)
-      Item::Local(_widechar_format, _widechar_vec) => {
-        // 010010_00
-        todo!()
+      // Localized String
+      Item::Local(header, local_vec) => {
+        Self::serialize_scalar_header(vec, format::LOCAL, 2 + local_vec.len(), options.length_bytes)?;
+        vec.extend_from_slice(&u16::from(*header).to_be_bytes());
+        vec.extend_from_slice(local_vec);
       },
       // Binary
       Item::Bin(bin_vec) => {
-        //Length
-        let len = bin_vec.len();
-        if len < 256 {
-          vec.push(format::BIN | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::BIN | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::BIN | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for bin in bin_vec {
-          vec.push(bin);
-        }
+        Self::serialize_scalar_header(vec, format::BIN, bin_vec.len(), options.length_bytes)?;
+        vec.extend_from_slice(bin_vec);
       },
       // Boolean
       Item::Bool(bool_vec) => {
-        //Length
-        let len = bool_vec.len();
-        if len < 256 {
-          vec.push(format::BOOL | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::BOOL | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::BOOL | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for bool in bool_vec {
-          vec.push(bool as u8);
-        }
+        Self::serialize_scalar_header(vec, format::BOOL, bool_vec.len(), options.length_bytes)?;
+        vec.extend(bool_vec.iter().map(|bool| *bool as u8));
       },
       // 1-Byte Signed Integer
       Item::I1(i1_vec) => {
-        //Length
-        let len = i1_vec.len();
-        if len < 256 {
-          vec.push(format::I1 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I1 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I1 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for i1 in i1_vec {
-          vec.extend_from_slice(&i1.to_be_bytes());
-        }
+        Self::serialize_scalar_header(vec, format::I1, i1_vec.len(), options.length_bytes)?;
+        vec.extend(i1_vec.iter().map(|i1| *i1 as u8));
       },
       // 2-Byte Signed Integer
       Item::I2(i2_vec) => {
-        //Length
-        let len = i2_vec.len() * 2;
-        if len < 256 {
-          vec.push(format::I2 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I2 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I2 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::I2, i2_vec.len() * 2, options.length_bytes)?;
         for i2 in i2_vec {
           vec.extend_from_slice(&i2.to_be_bytes());
         }
       },
       // 4-Byte Signed Integer
       Item::I4(i4_vec) => {
-        //Length
-        let len = i4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::I4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::I4, i4_vec.len() * 4, options.length_bytes)?;
         for i4 in i4_vec {
           vec.extend_from_slice(&i4.to_be_bytes());
         }
       },
       // 8-Byte Signed Integer
       Item::I8(i8_vec) => {
-        //Length
-        let len = i8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::I8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::I8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::I8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::I8, i8_vec.len() * 8, options.length_bytes)?;
         for i8 in i8_vec {
           vec.extend_from_slice(&i8.to_be_bytes());
         }
       },
       // 1-Byte Unsigned Integer
       Item::U1(u1_vec) => {
-        //Length
-        let len = u1_vec.len();
-        if len < 256 {
-          vec.push(format::U1 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U1 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U1 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
-        for u1 in u1_vec {
-          vec.push(u1);
-        }
+        Self::serialize_scalar_header(vec, format::U1, u1_vec.len(), options.length_bytes)?;
+        vec.extend_from_slice(u1_vec);
       },
       // 2-Byte Unsigned Integer
       Item::U2(u2_vec) => {
-        //Length
-        let len = u2_vec.len() * 2;
-        if len < 256 {
-          vec.push(format::U2 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U2 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U2 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::U2, u2_vec.len() * 2, options.length_bytes)?;
         for u2 in u2_vec {
           vec.extend_from_slice(&u2.to_be_bytes());
         }
       },
       // 4-Byte Unsigned Integer
       Item::U4(u4_vec) => {
-        //Length
-        let len = u4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::U4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::U4, u4_vec.len() * 4, options.length_bytes)?;
         for u4 in u4_vec {
           vec.extend_from_slice(&u4.to_be_bytes());
         }
       },
       // 8-Byte Unsigned Integer
       Item::U8(u8_vec) => {
-        //Length
-        let len = u8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::U8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::U8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::U8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::U8, u8_vec.len() * 8, options.length_bytes)?;
         for u8 in u8_vec {
           vec.extend_from_slice(&u8.to_be_bytes());
         }
       },
       // 4-Byte Floating Point Number
       Item::F4(f4_vec) => {
-        //Length
-        let len = f4_vec.len() * 4;
-        if len < 256 {
-          vec.push(format::F4 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::F4 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::F4 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::F4, f4_vec.len() * 4, options.length_bytes)?;
         for f4 in f4_vec {
           vec.extend_from_slice(&f4.to_be_bytes());
         }
       },
       // 8-Byte Floating Point Number
       Item::F8(f8_vec) => {
-        //Length
-        let len = f8_vec.len() * 8;
-        if len < 256 {
-          vec.push(format::F8 | 1);
-          vec.push(len as u8);
-        } else if len < 65536 {
-          vec.push(format::F8 | 2);
-          vec.extend_from_slice(&(len as u16).to_be_bytes());
-        } else {
-          vec.push(format::F8 | 3);
-          vec.extend_from_slice(&(len as u32).to_be_bytes()[0..3]);
-        };
-        //Vector
+        Self::serialize_scalar_header(vec, format::F8, f8_vec.len() * 8, options.length_bytes)?;
         for f8 in f8_vec {
           vec.extend_from_slice(&f8.to_be_bytes());
         }
       },
     }
-    vec
+    Ok(())
+  }
+
+  /// ### SERIALIZE HEADER
+  /// 
+  /// Appends the format byte and the length bytes of an [Item] to a buffer,
+  /// using the number of length bytes chosen by the [Length Bytes Mode].
+  /// 
+  /// Only room for the header is reserved, as the length of a [List] counts
+  /// its [Item]s rather than bytes.
+  /// 
+  /// Fails with [Item Too Long] if the length cannot be represented with
+  /// the number of length bytes chosen.
+  /// 
+  /// [Item]:              Item
+  /// [List]:              Item::List
+  /// [Length Bytes Mode]: LengthBytesMode
+  /// [Item Too Long]:     Error::ItemTooLong
+  fn serialize_header(vec: &mut Vec<u8>, format: u8, len: usize, mode: LengthBytesMode) -> Result<(), Error> {
+    let length_bytes: usize = mode.length_bytes(len);
+    if len >= 1 << (length_bytes * 8) {
+      return Err(Error::ItemTooLong)
+    }
+    vec.reserve(1 + length_bytes);
+    vec.push(format | length_bytes as u8);
+    vec.extend_from_slice(&(len as u32).to_be_bytes()[4 - length_bytes..]);
+    Ok(())
+  }
+
+  /// ### SERIALIZE SCALAR HEADER
+  /// 
+  /// Appends the header of an [Item] other than a [List] as the
+  /// [Serialize Header] function does, also reserving room for the [Item]'s
+  /// body, whose length is in bytes.
+  /// 
+  /// [Item]:             Item
+  /// [List]:             Item::List
+  /// [Serialize Header]: Item::serialize_header
+  fn serialize_scalar_header(vec: &mut Vec<u8>, format: u8, len: usize, mode: LengthBytesMode) -> Result<(), Error> {
+    vec.reserve(1 + mode.length_bytes(len) + len);
+    Self::serialize_header(vec, format, len, mode)
+  }
+}
+
+/// ## ENCODE OPTIONS
+/// 
+/// Settings which alter how an [Item] is serialized into binary data, used by
+/// the [Encode With Options] function.
+/// 
+/// The default [Encode Options] produce the same binary data as the
+/// [Encode] function.
+/// 
+/// [Item]:                Item
+/// [Encode]:              Item::encode
+/// [Encode With Options]: Item::encode_with
+/// [Encode Options]:      EncodeOptions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+  /// ### LENGTH BYTES
+  /// 
+  /// The number of length bytes used in the header of each [Item].
+  /// 
+  /// [Item]: Item
+  pub length_bytes: LengthBytesMode,
+}
+
+/// ## LENGTH BYTES MODE
+/// 
+/// The number of length bytes used in the header of each [Item] when it is
+/// serialized into binary data.
+/// 
+/// -------------------------------------------------------------------------
+/// 
+/// The standard allows any of 1 to 3 length bytes to be used, but some
+/// legacy equipment only accepts a fixed number of them.
+/// 
+/// [Item]: Item
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthBytesMode {
+  /// ### MINIMAL
+  /// 
+  /// The fewest length bytes able to represent the length of the [Item] are
+  /// used.
+  /// 
+  /// [Item]: Item
+  #[default]
+  Minimal,
+
+  /// ### TWO
+  /// 
+  /// Exactly 2 length bytes are always used, so an [Item] is limited to a
+  /// length of 65535 bytes.
+  /// 
+  /// [Item]: Item
+  Two,
+
+  /// ### THREE
+  /// 
+  /// Exactly 3 length bytes are always used, regardless of the length of
+  /// the [Item].
+  /// 
+  /// [Item]: Item
+  Three,
+}
+impl LengthBytesMode {
+  /// ### LENGTH BYTES
+  /// 
+  /// The number of length bytes used in the header of an [Item] with the
+  /// provided length.
+  /// 
+  /// [Item]: Item
+  fn length_bytes(self, len: usize) -> usize {
+    match self {
+      LengthBytesMode::Minimal => {
+        if len < 256 {1}
+        else if len < 65536 {2}
+        else {3}
+      },
+      LengthBytesMode::Two   => 2,
+      LengthBytesMode::Three => 3,
+    }
+  }
+}
+impl Item {
+  /// ### DISPLAY AT DEPTH
+  /// 
+  /// Writes an [Item] as part of an indented tree, with nested [List]s
+  /// indented by two spaces per level.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn display(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+    write!(f, "{:1$}", "", depth * 2)?;
+    match self {
+      Item::List(vec) => {
+        write!(f, "L[{}]", vec.len())?;
+        for item in vec {
+          writeln!(f)?;
+          item.display(f, depth + 1)?;
+        }
+        Ok(())
+      },
+      Item::Ascii(vec) => {
+        write!(f, "A:\"")?;
+        for character in vec {
+          write!(f, "{}", character.to_u8().escape_ascii())?;
+        }
+        write!(f, "\"")
+      },
+      Item::Jis8(string)       => write!(f, "J:{:?}", string),
+      Item::Local(header, vec) => write!(f, "LOCAL:{:?}:{:02X?}", header, vec),
+      Item::Bin(vec)           => write!(f, "B:{:02X?}", vec),
+      Item::Bool(vec)          => write!(f, "BOOLEAN:{:?}", vec),
+      Item::I1(vec)            => write!(f, "I1:{:?}", vec),
+      Item::I2(vec)            => write!(f, "I2:{:?}", vec),
+      Item::I4(vec)            => write!(f, "I4:{:?}", vec),
+      Item::I8(vec)            => write!(f, "I8:{:?}", vec),
+      Item::U1(vec)            => write!(f, "U1:{:?}", vec),
+      Item::U2(vec)            => write!(f, "U2:{:?}", vec),
+      Item::U4(vec)            => write!(f, "U4:{:?}", vec),
+      Item::U8(vec)            => write!(f, "U8:{:?}", vec),
+      Item::F4(vec)            => write!(f, "F4:{:?}", vec),
+      Item::F8(vec)            => write!(f, "F8:{:?}", vec),
+    }
+  }
+}
+impl Display for Item {
+  /// ### DISPLAY ITEM
+  /// 
+  /// Renders an [Item], including any nested [Item]s, as an indented tree
+  /// intended for debugging, such as:
+  /// 
+  /// ```text
+  /// L[2]
+  ///   A:"MODEL"
+  ///   U4:[1, 2, 3]
+  /// ```
+  /// 
+  /// For a representation which can be parsed back, see [SML].
+  /// 
+  /// [Item]: Item
+  /// [SML]:  Item::to_sml
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    self.display(f, 0)
+  }
+}
+impl TryFrom<Item> for Vec<u8> {
+  type Error = Error;
+
+  /// ### ITEM -> BINARY DATA
+  /// 
+  /// Fallable serialization of an [Item], which can represent an entire tree
+  /// of [Item]s due to [List]s, into binary data.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn try_from(item: Item) -> Result<Self, Self::Error> {
+    item.encode()
   }
 }
 impl TryFrom<Vec<u8>> for Item {
@@ -783,168 +1126,199 @@ impl TryFrom<Vec<u8>> for Item {
   /// Fallable deserialization of binary data into an [Item], which can
   /// represent an entire tree of [Item]s due to [List]s.
   /// 
-  /// [Item]: Item
-  /// [List]: Item::List
+  /// Deserialization never panics, allocates only in proportion to the
+  /// binary data actually provided rather than to any length it declares,
+  /// and rejects [List]s nested more deeply than [MAX_LIST_DEPTH] so that
+  /// recursion is bounded.
+  /// 
+  /// Fails with [Empty Text] if there is no binary data, with
+  /// [Unexpected End] if the binary data ends before the [Item] is complete,
+  /// with [Wrong Format] if the length of a multi-byte numeric [Item] is not
+  /// a multiple of the size of its values, and with [Invalid Text] if the
+  /// binary data is otherwise malformed.
+  /// 
+  /// [Item]:           Item
+  /// [List]:           Item::List
+  /// [MAX_LIST_DEPTH]: Item::MAX_LIST_DEPTH
+  /// [Empty Text]:     Error::EmptyText
+  /// [Unexpected End]: Error::UnexpectedEnd
+  /// [Wrong Format]:   Error::WrongFormat
+  /// [Invalid Text]:   Error::InvalidText
   fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+    /// ## INTERNAL NEXT BYTE FUNCTION
+    /// 
+    /// Takes the next byte from an iterator, where running out of data is
+    /// reported as the text ending early.
+    fn next(data: &mut std::slice::Iter<u8>) -> Result<u8, Error> {
+      data.next().copied().ok_or(Error::UnexpectedEnd)
+    }
     /// ## INTERNAL CONVERSION FUNCTION
     /// 
     /// Converts data from an iterator into an item without final checks and
     /// using recursion in the case of List items.
-    fn convert(data: &mut std::slice::Iter<u8>) -> Option<Item> {
-      let format_byte = *data.next()?;
+    fn convert(data: &mut std::slice::Iter<u8>, depth: usize) -> Result<Item, Error> {
+      let format_byte = next(data)?;
       let item = format_byte & 0b111111_00;
       let length_length = format_byte & 0b000000_11;
-      if length_length == 0 {return None}
+      if length_length == 0 {return Err(Error::InvalidText)}
       let length: u32 = {
         let mut bytes = [0u8;4];
         for i in (4-length_length)..4 {
-          bytes[i as usize] = *data.next()?;
+          bytes[i as usize] = next(data)?;
         }
         u32::from_be_bytes(bytes)
       };
       match item {
         // List
         format::LIST => {
+          if depth >= Item::MAX_LIST_DEPTH {return Err(Error::InvalidText)}
           let mut vec: Vec<Item> = vec![];
           // Perform Recursion
-          for _ in 0..length {vec.push(convert(data)?);}
-          Some(Item::List(vec))
+          for _ in 0..length {vec.push(convert(data, depth + 1)?);}
+          Ok(Item::List(vec))
         },
         // ASCII
         format::ASCII => {
           let mut vec: Vec<Char> = vec![];
-          for _ in 0..length {vec.push(Char::from_u8(*data.next()?)?);}
-          Some(Item::Ascii(vec))
+          for _ in 0..length {vec.push(Char::from_u8(next(data)?).ok_or(Error::InvalidText)?);}
+          Ok(Item::Ascii(vec))
         },
         // JIS-8
         format::JIS8 => {
           let mut vec: Vec<u8> = vec![];
-          for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::Jis8(ISO_2022_JP.decode(&vec, encoding::types::DecoderTrap::Strict).ok()?))
+          for _ in 0..length {vec.push(next(data)?);}
+          Ok(Item::Jis8(ISO_2022_JP.decode(&vec, encoding::types::DecoderTrap::Strict).map_err(|_| Error::InvalidText)?))
+        },
+        // Localized String
+        format::LOCAL => {
+          if length < 2 {return Err(Error::InvalidText)}
+          let selector: u16 = u16::from_be_bytes([next(data)?, next(data)?]);
+          let header = LocalizedStringHeader::try_from(selector).map_err(|_| Error::InvalidText)?;
+          let mut vec: Vec<u8> = vec![];
+          for _ in 0..length-2 {vec.push(next(data)?);}
+          Ok(Item::Local(header, vec))
         },
-        // Localized String (TODO)
-        format::LOCAL => None,
         // Binary
         format::BIN => {
           let mut vec: Vec<u8> = vec![];
-          for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::Bin(vec))
+          for _ in 0..length {vec.push(next(data)?);}
+          Ok(Item::Bin(vec))
         },
         // Boolean
         format::BOOL => {
           let mut vec: Vec<bool> = vec![];
-          for _ in 0..length {vec.push(*data.next()? > 0);}
-          Some(Item::Bool(vec))
+          for _ in 0..length {vec.push(next(data)? > 0);}
+          Ok(Item::Bool(vec))
         },
         // 1-Byte Signed Integer
         format::I1 => {
           let mut vec: Vec<i8> = vec![];
-          for _ in 0..length {vec.push(*data.next()? as i8);}
-          Some(Item::I1(vec))
+          for _ in 0..length {vec.push(next(data)? as i8);}
+          Ok(Item::I1(vec))
         },
         // 2-Byte Signed Integer
         format::I2 => {
-          if length % 2 != 0 {return None}
+          if length % 2 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<i16> = vec![];
           for _ in 0..length/2 {
             let mut bytes = [0u8;2];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(i16::from_be_bytes(bytes));
           }
-          Some(Item::I2(vec))
+          Ok(Item::I2(vec))
         },
         // 4-Byte Signed Integer
         format::I4 => {
-          if length % 4 != 0 {return None}
+          if length % 4 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<i32> = vec![];
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(i32::from_be_bytes(bytes));
           }
-          Some(Item::I4(vec))
+          Ok(Item::I4(vec))
         },
         // 8-Byte Signed Integer
         format::I8 => {
-          if length % 8 != 0 {return None}
+          if length % 8 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<i64> = vec![];
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(i64::from_be_bytes(bytes));
           }
-          Some(Item::I8(vec))
+          Ok(Item::I8(vec))
         },
         // 1-Byte Unsigned Integer
         format::U1 => {
           let mut vec: Vec<u8> = vec![];
-          for _ in 0..length {vec.push(*data.next()?);}
-          Some(Item::U1(vec))
+          for _ in 0..length {vec.push(next(data)?);}
+          Ok(Item::U1(vec))
         },
         // 2-Byte Unsigned Integer
         format::U2 => {
-          if length % 2 != 0 {return None}
+          if length % 2 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<u16> = vec![];
           for _ in 0..length/2 {
             let mut bytes = [0u8;2];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(u16::from_be_bytes(bytes));
           }
-          Some(Item::U2(vec))
+          Ok(Item::U2(vec))
         },
         // 4-Byte Unsigned Integer
         format::U4 => {
-          if length % 4 != 0 {return None}
+          if length % 4 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<u32> = vec![];
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(u32::from_be_bytes(bytes));
           }
-          Some(Item::U4(vec))
+          Ok(Item::U4(vec))
         },
         // 8-Byte Unsigned Integer
         format::U8 => {
-          if length % 8 != 0 {return None}
+          if length % 8 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<u64> = vec![];
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(u64::from_be_bytes(bytes));
           }
-          Some(Item::U8(vec))
+          Ok(Item::U8(vec))
         },
         // 4-Byte Floating Point Number
         format::F4 => {
-          if length % 4 != 0 {return None}
+          if length % 4 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<f32> = vec![];
           for _ in 0..length/4 {
             let mut bytes = [0u8;4];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(f32::from_be_bytes(bytes));
           }
-          Some(Item::F4(vec))
+          Ok(Item::F4(vec))
         },
         // 8-Byte Floating Point Number
         format::F8 => {
-          if length % 8 != 0 {return None}
+          if length % 8 != 0 {return Err(Error::WrongFormat)}
           let mut vec: Vec<f64> = vec![];
           for _ in 0..length/8 {
             let mut bytes = [0u8;8];
-            for byte in &mut bytes {*byte = *data.next()?}
+            for byte in &mut bytes {*byte = next(data)?}
             vec.push(f64::from_be_bytes(bytes));
           }
-          Some(Item::F8(vec))
+          Ok(Item::F8(vec))
         },
         // Unrecognized
-        _ => None
+        _ => Err(Error::InvalidText)
       }
     }
     // Empty items are their own category of error which may be acceptable elsewhere.
     if text.is_empty() {return Err(Error::EmptyText)};
     // Convert data into an item.
     let mut data: std::slice::Iter<u8> = text.iter();
-    let result = convert(&mut data).ok_or(Error::InvalidText)?;
+    let result = convert(&mut data, 0)?;
     // Check that all text has been handled.
     if data.next().is_some() {return Err(Error::InvalidText)}
     // Finish.
@@ -954,8 +1328,17 @@ impl TryFrom<Vec<u8>> for Item {
 
 /// ## LOCALIZED STRING HEADER
 /// **Based on SEMI E5§9.4**
+/// 
+/// The 2 byte selector which begins the body of a [Localized String] and
+/// names the encoding of the characters which follow it.
+/// 
+/// Converts to and from its [u16] value, failing on any value without a
+/// named variant.
+/// 
+/// [Localized String]: Item::Local
 #[repr(u16)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocalizedStringHeader {
   //Universal
   Ucs2 = 1,
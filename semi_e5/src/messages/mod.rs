@@ -143,6 +143,62 @@ macro_rules! message_data {
   }
 }
 
+/// ## MESSAGE MACRO: DATA, OPTIONAL REPLY
+/// 
+/// To be used with particular messages that contain arbitrary data and for
+/// which a reply is optional.
+/// 
+/// The W-bit is set when converting into a [Message], requesting the reply,
+/// and may be cleared on the [Message] afterward. Either W-bit is accepted
+/// when converting from a [Message].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Arguments
+/// 
+/// - **$name**: Name of struct.
+/// - **$stream**: Stream of message.
+/// - **$function**: Function of message.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Expansion
+/// 
+/// - From\<$name\> for Message
+/// - TryFrom\<Message\> for $name
+/// 
+/// [Message]: crate::Message
+macro_rules! message_data_optional_reply {
+  (
+    $name:ident,
+    $stream:expr,
+    $function:expr
+  ) => {
+    impl From<$name> for Message {
+      fn from(value: $name) -> Self {
+        Message {
+          stream:   $stream,
+          function: $function,
+          w:        true,
+          text:     Some(value.0.into()),
+        }
+      }
+    }
+    impl TryFrom<Message> for $name {
+      type Error = Error;
+
+      fn try_from(message: Message) -> Result<Self, Self::Error> {
+        if message.stream   != $stream   {return Err(WrongStream)}
+        if message.function != $function {return Err(WrongFunction)}
+        match message.text {
+          Some(item) => {Ok(Self(item.try_into()?))},
+          None => Err(WrongFormat),
+        }
+      }
+    }
+  }
+}
+
 /// ## MESSAGE MACRO: ITEM
 /// 
 /// To be used with particular messages that contain just an Item.
@@ -233,94 +289,11 @@ pub mod s3 {}
 /// [Message]: crate::Message
 pub mod s4 {}
 
-/// # STREAM 5: EXCEPTION HANDLING
-/// **Based on SEMI E5§10.9**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with binary and analog equipment exceptions.
-/// 
-/// Exceptions are classified into two categories: Errors and Alarms
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s S5F1 through S5F8 provide basic alarm messages, which may
-/// be divided into the following categories:
-/// 
-/// - Personal Safety - Condition may be dangerous to people.
-/// - Equipment Safety - Condition may harm equipment.
-/// - Parameter Control Warning - Parameter variation outside of preset
-///   limits - may harm product.
-/// - Parameter Control Error - Parameter variation outside of reasonable
-///   control limits - may indicate an equipment malfunction.
-/// - Irrecoverable Error - Intervention required before normal use of
-///   equipment can resume.
-/// - Equipment Status Warning - An unexpected condition has occurred, but
-///   operation can continue.
-/// - Attention Flags - A signal from a process program indicating that a
-///   particular step has been reached.
-/// - Data Integrity - A condition which may cause loss of data; usually
-///   related to [Stream 6].
-/// 
-/// It will be the equipment's responsibility to categorize alarms.
-/// 
-/// Some alarm conditions may cause more than one type of alarm to be issued.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s S5F9 through S5F15 provide extended capabilities for
-/// exception handling.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-/// [Stream 6]: crate::messages::s6
-pub mod s5 {}
+pub mod s5;
 
-/// # STREAM 6: DATA COLLECTION
-/// **Based on SEMI E5§10.10**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with in-process measurement and equipment
-/// monitoring.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s6 {}
+pub mod s6;
 
-/// # STREAM 7: PROCESS PROGRAM MANAGEMENT
-/// **Based on SEMI E5§10.11**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with the management and transfer of Process Programs.
-/// 
-/// Process Programs are the equipment-specific descriptions that determine
-/// the procedure to be conducted on the material by a single piece of
-/// equipment.
-/// 
-/// Methods are provided to transfer programs as well as establish the link
-/// between the process program and the material to be processed with that
-/// program.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s7 {}
+pub mod s7;
 
 /// # STREAM 8: CONTROL PROGRAM TRANSFER
 /// **Based on SEMI E5§10.12**
@@ -340,50 +313,9 @@ pub mod s7 {}
 /// [Message]: crate::Message
 pub mod s8 {}
 
-/// # STREAM 9: SYSTEM ERRORS
-/// **Based on SEMI E5§10.13**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with informing the host of communication errors,
-/// particularly that a message block has been received which cannot be
-/// handled or that a timeout on a transaction reception timer has occurred.
-/// 
-/// The messages indicate either a Message Fault or a Communications Fault
-/// has occurred but do not indicate a Communications Failure has occurred.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s9 {}
+pub mod s9;
 
-/// # STREAM 10: TERMINAL SERVICES
-/// **Based on SEMI E5§10.14**
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// [Message]s which deal with passing textual messages between operator
-/// terminals attached to processing or testing equipment and the host.
-/// 
-/// The equipment makes no attempt to interpret the text of the message, but
-/// merely passes it from terminal keyboard to the host or from the host to
-/// the display of the terminal.
-/// 
-/// Management of human response times to information displayed on terminals
-/// is the responsibility of the host.
-/// 
-/// ---------------------------------------------------------------------------
-/// 
-/// ## TO BE DONE
-/// 
-/// - Fill out stream contents
-/// 
-/// [Message]: crate::Message
-pub mod s10 {}
+pub mod s10;
 
 /// # STREAM 11: DELETED
 /// **Based on SEMI E5§10.15**
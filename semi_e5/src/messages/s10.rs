@@ -0,0 +1,163 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 10: TERMINAL SERVICES
+//! **Based on SEMI E5§10.14**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with passing textual messages between operator
+//! terminals attached to processing or testing equipment and the host.
+//! 
+//! The equipment makes no attempt to interpret the text of the message, but
+//! merely passes it from terminal keyboard to the host or from the host to
+//! the display of the terminal.
+//! 
+//! Management of human response times to information displayed on terminals
+//! is the responsibility of the host.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Fill out remaining stream contents
+//! 
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S10F0
+/// 
+/// **Abort Transaction**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Used in lieu of an expected reply to abort a transaction.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 10, 0}
+
+/// ## S10F1
+/// 
+/// **Terminal Request (TRN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// A line of text entered by the operator at a terminal, passed on to the
+/// host.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [TID]
+///    2. [TEXT]
+/// 
+/// [TID]:  TerminalID
+/// [TEXT]: TerminalText
+pub struct TerminalRequest(pub (TerminalID, TerminalText));
+message_data!{TerminalRequest, true, 10, 1}
+
+/// ## S10F2
+/// 
+/// **Terminal Request Acknowledge (TRA)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Acknowledgement of [S10F1].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [ACKC10]
+/// 
+/// [S10F1]:  TerminalRequest
+/// [ACKC10]: AcknowledgeCode10
+pub struct TerminalRequestAcknowledge(pub AcknowledgeCode10);
+message_data!{TerminalRequestAcknowledge, false, 10, 2}
+
+/// ## S10F3
+/// 
+/// **Terminal Display, Single (VTN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// A line of text to be displayed on the specified terminal.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [TID]
+///    2. [TEXT]
+/// 
+/// [TID]:  TerminalID
+/// [TEXT]: TerminalText
+pub struct TerminalDisplaySingle(pub (TerminalID, TerminalText));
+message_data!{TerminalDisplaySingle, true, 10, 3}
+
+/// ## S10F4
+/// 
+/// **Terminal Display, Single Acknowledge (VTA)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Acknowledgement of [S10F3], stating whether the text will be displayed.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [ACKC10]
+/// 
+/// [S10F3]:  TerminalDisplaySingle
+/// [ACKC10]: AcknowledgeCode10
+pub struct TerminalDisplaySingleAcknowledge(pub AcknowledgeCode10);
+message_data!{TerminalDisplaySingleAcknowledge, false, 10, 4}
@@ -0,0 +1,147 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 5: EXCEPTION HANDLING
+//! **Based on SEMI E5§10.9**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with binary and analog equipment exceptions.
+//! 
+//! Exceptions are classified into two categories: Errors and Alarms
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s S5F1 through S5F8 provide basic alarm messages, which may
+//! be divided into the following categories:
+//! 
+//! - Personal Safety - Condition may be dangerous to people.
+//! - Equipment Safety - Condition may harm equipment.
+//! - Parameter Control Warning - Parameter variation outside of preset
+//!   limits - may harm product.
+//! - Parameter Control Error - Parameter variation outside of reasonable
+//!   control limits - may indicate an equipment malfunction.
+//! - Irrecoverable Error - Intervention required before normal use of
+//!   equipment can resume.
+//! - Equipment Status Warning - An unexpected condition has occurred, but
+//!   operation can continue.
+//! - Attention Flags - A signal from a process program indicating that a
+//!   particular step has been reached.
+//! - Data Integrity - A condition which may cause loss of data; usually
+//!   related to [Stream 6].
+//! 
+//! It will be the equipment's responsibility to categorize alarms.
+//! 
+//! Some alarm conditions may cause more than one type of alarm to be issued.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s S5F9 through S5F15 provide extended capabilities for
+//! exception handling.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Fill out remaining stream contents
+//! 
+//! [Message]:  crate::Message
+//! [Stream 6]: crate::messages::s6
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S5F0
+/// 
+/// **Abort Transaction**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Used in lieu of an expected reply to abort a transaction.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 5, 0}
+
+/// ## S5F1
+/// 
+/// **Alarm Report Send (ARS)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY OPTIONAL**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Report a change in or presence of an alarm condition.
+/// 
+/// One message will be issued when the alarm is set and one message will be
+/// issued when the alarm is cleared.
+/// 
+/// The reply is requested when converted into a [Message], and is not
+/// requested if the W-bit is cleared on the [Message] afterward. A
+/// [Message] with either W-bit is accepted.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 3
+///    1. [ALCD]
+///    2. [ALID]
+///    3. [ALTX]
+/// 
+/// [ALCD]:    AlarmCode
+/// [ALID]:    AlarmID
+/// [ALTX]:    AlarmText
+/// [Message]: crate::Message
+pub struct AlarmReportSend(pub (AlarmCode, AlarmID, AlarmText));
+message_data_optional_reply!{AlarmReportSend, 5, 1}
+
+/// ## S5F2
+/// 
+/// **Alarm Report Acknowledge (ARA)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Acknowledge or error.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [ACKC5]
+/// 
+/// [ACKC5]: AcknowledgeCode5
+pub struct AlarmReportAcknowledge(pub AcknowledgeCode5);
+message_data!{AlarmReportAcknowledge, false, 5, 2}
@@ -0,0 +1,121 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 6: DATA COLLECTION
+//! **Based on SEMI E5§10.10**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with in-process measurement and equipment
+//! monitoring.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Fill out remaining stream contents
+//! 
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S6F0
+/// 
+/// **Abort Transaction**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Used in lieu of an expected reply to abort a transaction.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 6, 0}
+
+/// ## S6F11
+/// 
+/// **Event Report Send (ERS)**
+/// 
+/// - **MULTI-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Send a defined, linked, and enabled group of reports to the host upon the
+/// occurrence of a collection event.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 3
+///    1. [DATAID]
+///    2. [CEID]
+///    3. List - A
+///       - [Report]
+///          - List - 2
+///             1. [RPTID]
+///             2. List - B
+///                - [V]
+/// 
+/// A is the number of reports linked to the given [CEID].
+/// 
+/// B is the number of [VID]s defined for the given [RPTID].
+/// 
+/// [DATAID]: DataID
+/// [CEID]:   CollectionEventID
+/// [Report]: Report
+/// [RPTID]:  ReportID
+/// [V]:      VariableData
+/// [VID]:    VariableID
+pub struct EventReportSend(pub (DataID, CollectionEventID, VecList<Report>));
+message_data!{EventReportSend, true, 6, 11}
+
+/// ## S6F12
+/// 
+/// **Event Report Acknowledge (ERA)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST -> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Acknowledge or error.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [ACKC6]
+/// 
+/// [ACKC6]: AcknowledgeCode6
+pub struct EventReportAcknowledge(pub AcknowledgeCode6);
+message_data!{EventReportAcknowledge, false, 6, 12}
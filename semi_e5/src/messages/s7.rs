@@ -0,0 +1,222 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 7: PROCESS PROGRAM MANAGEMENT
+//! **Based on SEMI E5§10.11**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with the management and transfer of Process Programs.
+//! 
+//! Process Programs are the equipment-specific descriptions that determine
+//! the procedure to be conducted on the material by a single piece of
+//! equipment.
+//! 
+//! Methods are provided to transfer programs as well as establish the link
+//! between the process program and the material to be processed with that
+//! program.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Fill out remaining stream contents
+//! 
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S7F0
+/// 
+/// **Abort Transaction**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Used in lieu of an expected reply to abort a transaction.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// Header only.
+pub struct Abort;
+message_headeronly!{Abort, false, 7, 0}
+
+/// ## S7F1
+/// 
+/// **Process Program Load Inquire (PPI)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Request for permission to send the specified process program.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [PPID]
+///    2. [LENGTH]
+/// 
+/// [PPID]:   ProcessProgramID
+/// [LENGTH]: Length
+pub struct ProcessProgramLoadInquire(pub (ProcessProgramID, Length));
+message_data!{ProcessProgramLoadInquire, true, 7, 1}
+
+/// ## S7F2
+/// 
+/// **Process Program Load Grant (PPG)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Permission, or refusal, to send the process program inquired about by
+/// [S7F1].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [PPGNT]
+/// 
+/// [S7F1]:  ProcessProgramLoadInquire
+/// [PPGNT]: ProcessProgramGrant
+pub struct ProcessProgramLoadGrant(pub ProcessProgramGrant);
+message_data!{ProcessProgramLoadGrant, false, 7, 2}
+
+/// ## S7F3
+/// 
+/// **Process Program Send (PPS)**
+/// 
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Transfer of a process program, following a granted [S7F1] if the
+/// [Message] is too long to be sent in a single block.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [PPID]
+///    2. [PPBODY]
+/// 
+/// Bodies are commonly large, so serializing with [Serialize Into] into a
+/// buffer sized by [Encoded Length] avoids reallocating as the body is
+/// copied.
+/// 
+/// [S7F1]:           ProcessProgramLoadInquire
+/// [PPID]:           ProcessProgramID
+/// [PPBODY]:         ProcessProgramBody
+/// [Message]:        crate::Message
+/// [Serialize Into]: crate::Item::serialize_into
+/// [Encoded Length]: crate::Item::encoded_len
+pub struct ProcessProgramSend(pub (ProcessProgramID, ProcessProgramBody));
+message_data!{ProcessProgramSend, true, 7, 3}
+
+/// ## S7F4
+/// 
+/// **Process Program Acknowledge (PPA)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Acknowledgement of [S7F3].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [ACKC7]
+/// 
+/// [S7F3]:  ProcessProgramSend
+/// [ACKC7]: AcknowledgeCode7
+pub struct ProcessProgramAcknowledge(pub AcknowledgeCode7);
+message_data!{ProcessProgramAcknowledge, false, 7, 4}
+
+/// ## S7F5
+/// 
+/// **Process Program Request (PPR)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY REQUIRED**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Request for the transfer of a process program.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [PPID]
+/// 
+/// [PPID]: ProcessProgramID
+pub struct ProcessProgramRequest(pub ProcessProgramID);
+message_data!{ProcessProgramRequest, true, 7, 5}
+
+/// ## S7F6
+/// 
+/// **Process Program Data (PPD)**
+/// 
+/// - **MULTI-BLOCK**
+/// - **HOST <-> EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// The process program requested by [S7F5].
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - List - 2
+///    1. [PPID]
+///    2. [PPBODY]
+/// 
+/// A zero-length list means that the request is denied.
+/// 
+/// [S7F5]:   ProcessProgramRequest
+/// [PPID]:   ProcessProgramID
+/// [PPBODY]: ProcessProgramBody
+pub struct ProcessProgramData(pub OptionItem<(ProcessProgramID, ProcessProgramBody)>);
+message_data!{ProcessProgramData, false, 7, 6}
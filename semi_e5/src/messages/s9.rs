@@ -0,0 +1,181 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAM 9: SYSTEM ERRORS
+//! **Based on SEMI E5§10.13**
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! [Message]s which deal with informing the host of communication errors,
+//! particularly that a message block has been received which cannot be
+//! handled or that a timeout on a transaction reception timer has occurred.
+//! 
+//! The messages indicate either a Message Fault or a Communications Fault
+//! has occurred but do not indicate a Communications Failure has occurred.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Fill out remaining stream contents
+//! 
+//! [Message]: crate::Message
+
+use crate::*;
+use crate::Error::*;
+use crate::items::*;
+
+/// ## S9F1
+/// 
+/// **Unrecognized Device ID (UDN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the device ID in a message block header did not
+/// correspond to any known device ID in the node detecting the error.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageHeader
+pub struct UnrecognizedDeviceID(pub MessageHeader);
+message_data!{UnrecognizedDeviceID, false, 9, 1}
+
+/// ## S9F3
+/// 
+/// **Unrecognized Stream Type (USN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the equipment does not recognize the stream type in the
+/// message block header.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageHeader
+pub struct UnrecognizedStreamType(pub MessageHeader);
+message_data!{UnrecognizedStreamType, false, 9, 3}
+
+/// ## S9F5
+/// 
+/// **Unrecognized Function Type (UFN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the function in the message block header was not
+/// recognized by the receiver, although the stream was.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageHeader
+pub struct UnrecognizedFunctionType(pub MessageHeader);
+message_data!{UnrecognizedFunctionType, false, 9, 5}
+
+/// ## S9F7
+/// 
+/// **Illegal Data (IDN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the stream and function were recognized, but the
+/// associated data format could not be interpreted.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageHeader
+pub struct IllegalData(pub MessageHeader);
+message_data!{IllegalData, false, 9, 7}
+
+/// ## S9F9
+/// 
+/// **Transaction Timer Timeout (TTN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that a transaction (receive) timer has timed out and that the
+/// corresponding transaction has been aborted.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [SHEAD]
+/// 
+/// [SHEAD]: StoredHeader
+pub struct TransactionTimerTimeout(pub StoredHeader);
+message_data!{TransactionTimerTimeout, false, 9, 9}
+
+/// ## S9F11
+/// 
+/// **Data Too Long (DLN)**
+/// 
+/// - **SINGLE-BLOCK**
+/// - **HOST <- EQUIPMENT**
+/// - **REPLY FORBIDDEN**
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// Indicates that the receiver has been sent more data than it has buffer
+/// space to receive.
+/// 
+/// ---------------------------------------------------------------------------
+/// 
+/// #### Structure
+/// 
+/// - [MHEAD]
+/// 
+/// [MHEAD]: MessageHeader
+pub struct DataTooLong(pub MessageHeader);
+message_data!{DataTooLong, false, 9, 11}
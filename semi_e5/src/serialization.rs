@@ -0,0 +1,207 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SERIALIZATION
+//! 
+//! Implements [Serialize] and [Deserialize] for [Item]s when the `serde`
+//! feature is enabled, so that [Message]s may be expressed in formats such as
+//! JSON or YAML.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! An [Item] is represented by its format, named as in [SML], and its values:
+//! 
+//! ```json
+//! {"format": "L", "values": [
+//!   {"format": "A", "values": "SEMI-RS"},
+//!   {"format": "B", "values": "AQID"},
+//!   {"format": "F4", "values": [1.5, -2.0]}
+//! ]}
+//! ```
+//! 
+//! - [ASCII] and [JIS-8] values are a single string.
+//! - [Binary] values are a single base64 string.
+//! - [Localized String] values are its header and a base64 string of its
+//!   encoded characters.
+//! - All other values are an array of numbers or booleans.
+//! 
+//! Floating point values which are not finite cannot be represented by every
+//! format.
+//! 
+//! [Serialize]:        serde::Serialize
+//! [Deserialize]:      serde::Deserialize
+//! [Message]:          crate::Message
+//! [Item]:             Item
+//! [SML]:              crate::sml
+//! [ASCII]:            Item::Ascii
+//! [JIS-8]:            Item::Jis8
+//! [Binary]:           Item::Bin
+//! [Localized String]: Item::Local
+
+use base64::{
+  engine::general_purpose::STANDARD,
+  Engine,
+};
+use serde::{
+  de::Error as _,
+  Deserialize,
+  Deserializer,
+  Serialize,
+  Serializer,
+};
+use crate::{
+  ascii,
+  Item,
+  LocalizedStringHeader,
+};
+
+/// ## ITEM REFERENCE
+/// 
+/// The representation of an [Item] used to serialize it without copying its
+/// values.
+/// 
+/// [Item]: Item
+#[derive(Serialize)]
+#[serde(tag = "format", content = "values")]
+enum ItemRef<'a> {
+  #[serde(rename = "L")]
+  List(&'a [Item]),
+  #[serde(rename = "A")]
+  Ascii(&'a str),
+  #[serde(rename = "J")]
+  Jis8(&'a str),
+  #[serde(rename = "LOCAL")]
+  Local {
+    header: LocalizedStringHeader,
+    #[serde(serialize_with = "to_base64")]
+    text: &'a [u8],
+  },
+  #[serde(rename = "B")]
+  Bin(#[serde(serialize_with = "to_base64")] &'a [u8]),
+  #[serde(rename = "BOOLEAN")]
+  Bool(&'a [bool]),
+  I1(&'a [i8]),
+  I2(&'a [i16]),
+  I4(&'a [i32]),
+  I8(&'a [i64]),
+  U1(&'a [u8]),
+  U2(&'a [u16]),
+  U4(&'a [u32]),
+  U8(&'a [u64]),
+  F4(&'a [f32]),
+  F8(&'a [f64]),
+}
+
+/// ## ITEM OWNED
+/// 
+/// The representation of an [Item] used to deserialize it.
+/// 
+/// [Item]: Item
+#[derive(Deserialize)]
+#[serde(tag = "format", content = "values")]
+enum ItemOwned {
+  #[serde(rename = "L")]
+  List(Vec<Item>),
+  #[serde(rename = "A")]
+  Ascii(String),
+  #[serde(rename = "J")]
+  Jis8(String),
+  #[serde(rename = "LOCAL")]
+  Local {
+    header: LocalizedStringHeader,
+    #[serde(deserialize_with = "from_base64")]
+    text: Vec<u8>,
+  },
+  #[serde(rename = "B")]
+  Bin(#[serde(deserialize_with = "from_base64")] Vec<u8>),
+  #[serde(rename = "BOOLEAN")]
+  Bool(Vec<bool>),
+  I1(Vec<i8>),
+  I2(Vec<i16>),
+  I4(Vec<i32>),
+  I8(Vec<i64>),
+  U1(Vec<u8>),
+  U2(Vec<u16>),
+  U4(Vec<u32>),
+  U8(Vec<u64>),
+  F4(Vec<f32>),
+  F8(Vec<f64>),
+}
+
+impl Serialize for Item {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match self {
+      Item::List(vec)          => ItemRef::List(vec),
+      Item::Ascii(vec)         => ItemRef::Ascii(ascii::as_str(vec)),
+      Item::Jis8(string)       => ItemRef::Jis8(string),
+      Item::Local(header, vec) => ItemRef::Local {header: *header, text: vec},
+      Item::Bin(vec)           => ItemRef::Bin(vec),
+      Item::Bool(vec)          => ItemRef::Bool(vec),
+      Item::I1(vec)            => ItemRef::I1(vec),
+      Item::I2(vec)            => ItemRef::I2(vec),
+      Item::I4(vec)            => ItemRef::I4(vec),
+      Item::I8(vec)            => ItemRef::I8(vec),
+      Item::U1(vec)            => ItemRef::U1(vec),
+      Item::U2(vec)            => ItemRef::U2(vec),
+      Item::U4(vec)            => ItemRef::U4(vec),
+      Item::U8(vec)            => ItemRef::U8(vec),
+      Item::F4(vec)            => ItemRef::F4(vec),
+      Item::F8(vec)            => ItemRef::F8(vec),
+    }.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for Item {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Ok(match ItemOwned::deserialize(deserializer)? {
+      ItemOwned::List(vec)            => Item::List(vec),
+      ItemOwned::Ascii(string)        => Item::Ascii(ascii::from_str(&string).ok_or_else(|| D::Error::custom("non-ASCII character in ASCII item"))?.to_vec()),
+      ItemOwned::Jis8(string)         => Item::Jis8(string),
+      ItemOwned::Local {header, text} => Item::Local(header, text),
+      ItemOwned::Bin(vec)             => Item::Bin(vec),
+      ItemOwned::Bool(vec)            => Item::Bool(vec),
+      ItemOwned::I1(vec)              => Item::I1(vec),
+      ItemOwned::I2(vec)              => Item::I2(vec),
+      ItemOwned::I4(vec)              => Item::I4(vec),
+      ItemOwned::I8(vec)              => Item::I8(vec),
+      ItemOwned::U1(vec)              => Item::U1(vec),
+      ItemOwned::U2(vec)              => Item::U2(vec),
+      ItemOwned::U4(vec)              => Item::U4(vec),
+      ItemOwned::U8(vec)              => Item::U8(vec),
+      ItemOwned::F4(vec)              => Item::F4(vec),
+      ItemOwned::F8(vec)              => Item::F8(vec),
+    })
+  }
+}
+
+/// ## TO BASE64
+/// 
+/// Serializes binary data as a base64 string.
+fn to_base64<S: Serializer>(bytes: &&[u8], serializer: S) -> Result<S::Ok, S::Error> {
+  serializer.serialize_str(&STANDARD.encode(bytes))
+}
+
+/// ## FROM BASE64
+/// 
+/// Deserializes binary data from a base64 string.
+fn from_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+  let string: String = String::deserialize(deserializer)?;
+  STANDARD.decode(string).map_err(D::Error::custom)
+}
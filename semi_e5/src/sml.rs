@@ -0,0 +1,606 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # SECS MESSAGE LANGUAGE (SML)
+//! 
+//! A plain-text notation for [Message]s, commonly used to write test cases,
+//! canned responses, and log output.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! A [Message] is written as its [Stream] and [Function], an optional `W` to
+//! indicate that a reply is requested, an optional [Item], and a terminating
+//! period, which is required.
+//! 
+//! ```text
+//! S1F13 W
+//! <L [2]
+//!   <A "SEMI-RS">
+//!   <A "010">
+//! >
+//! .
+//! ```
+//! 
+//! [Item]s are written between angle brackets as a format code, an optional
+//! length in square brackets, and the item's values:
+//! 
+//! - `<L [n] ...>` - [List] of [Item]s.
+//! - `<A "text" 0x0D>` - [ASCII], as quoted runs and character codes.
+//! - `<J "text">` - [JIS-8], as quoted runs.
+//! - `<B 0x10 0x20>` - [Binary].
+//! - `<BOOLEAN TRUE FALSE>` - [Boolean].
+//! - `<I1 -1>`, `<I2>`, `<I4>`, `<I8>` - Signed integers.
+//! - `<U1 1>`, `<U2>`, `<U4>`, `<U8>` - Unsigned integers.
+//! - `<F4 1.5>`, `<F8>` - Floating point numbers.
+//! 
+//! Integers may be written in decimal, in hexadecimal with a `0x` prefix, or
+//! in binary with a `0b` prefix. Text following `//` on a line is ignored.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! ## TO BE DONE
+//! 
+//! - Support [Localized] strings.
+//! 
+//! [Message]:   crate::Message
+//! [Stream]:    crate::Message::stream
+//! [Function]:  crate::Message::function
+//! [Item]:      crate::Item
+//! [List]:      crate::Item::List
+//! [ASCII]:     crate::Item::Ascii
+//! [JIS-8]:     crate::Item::Jis8
+//! [Binary]:    crate::Item::Bin
+//! [Boolean]:   crate::Item::Bool
+//! [Localized]: crate::Item::Local
+
+use std::{
+  fmt::{self, Display, Formatter, Write},
+  str::FromStr,
+};
+use crate::{ascii::Char, Item, Message};
+
+/// ## SML ERROR
+/// 
+/// Represents an error in parsing [SML] text into a [Message].
+/// 
+/// Each variant carries the byte offset into the text at which the error
+/// was detected.
+/// 
+/// [SML]:     crate::sml
+/// [Message]: crate::Message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmlError {
+  /// ### UNEXPECTED END
+  /// 
+  /// The text ended before the [Message] was complete.
+  /// 
+  /// [Message]: crate::Message
+  UnexpectedEnd,
+
+  /// ### UNEXPECTED CHARACTER
+  /// 
+  /// A character was found which cannot appear at this position.
+  UnexpectedCharacter(usize),
+
+  /// ### INVALID HEADER
+  /// 
+  /// The [Stream] and [Function] were not of the form `SxFy`, or were out of
+  /// range.
+  /// 
+  /// [Stream]:   crate::Message::stream
+  /// [Function]: crate::Message::function
+  InvalidHeader(usize),
+
+  /// ### UNKNOWN FORMAT
+  /// 
+  /// An [Item] used an unrecognized or unsupported format code.
+  /// 
+  /// [Item]: crate::Item
+  UnknownFormat(usize),
+
+  /// ### INVALID VALUE
+  /// 
+  /// A value could not be represented in the format of its [Item].
+  /// 
+  /// [Item]: crate::Item
+  InvalidValue(usize),
+
+  /// ### WRONG LENGTH
+  /// 
+  /// The length given in square brackets did not match the number of values
+  /// in the [Item].
+  /// 
+  /// [Item]: crate::Item
+  WrongLength(usize),
+
+  /// ### TRAILING CHARACTERS
+  /// 
+  /// Text remained after the terminating period of the [Message].
+  /// 
+  /// [Message]: crate::Message
+  TrailingCharacters(usize),
+
+  /// ### TOO DEEP
+  /// 
+  /// A [List] was nested more deeply than [MAX_LIST_DEPTH].
+  /// 
+  /// [List]:           crate::Item::List
+  /// [MAX_LIST_DEPTH]: crate::Item::MAX_LIST_DEPTH
+  TooDeep(usize),
+}
+impl Display for SmlError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      SmlError::UnexpectedEnd                 => write!(f, "unexpected end of text"),
+      SmlError::UnexpectedCharacter(position) => write!(f, "unexpected character at {}", position),
+      SmlError::InvalidHeader(position)       => write!(f, "invalid stream and function at {}", position),
+      SmlError::UnknownFormat(position)       => write!(f, "unknown item format at {}", position),
+      SmlError::InvalidValue(position)        => write!(f, "invalid item value at {}", position),
+      SmlError::WrongLength(position)         => write!(f, "item length does not match values at {}", position),
+      SmlError::TrailingCharacters(position)  => write!(f, "trailing characters at {}", position),
+      SmlError::TooDeep(position)             => write!(f, "list nested too deeply at {}", position),
+    }
+  }
+}
+impl std::error::Error for SmlError {}
+
+/// ## PARSE
+/// 
+/// Converts [SML] text into a [Message].
+/// 
+/// [SML]:     crate::sml
+/// [Message]: crate::Message
+pub fn parse(text: &str) -> Result<Message, SmlError> {
+  Parser {text, position: 0}.message()
+}
+
+impl FromStr for Message {
+  type Err = SmlError;
+
+  /// ### MESSAGE FROM SML
+  /// 
+  /// Equivalent to [parse].
+  /// 
+  /// [parse]: parse
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    parse(text)
+  }
+}
+
+impl Message {
+  /// ### MESSAGE TO SML
+  /// 
+  /// Renders the [Message] as [SML] text which can be read back by [parse].
+  /// 
+  /// [Message]: Message
+  /// [SML]:     crate::sml
+  /// [parse]:   parse
+  pub fn to_sml(&self) -> String {
+    let mut sml = format!("S{}F{}", self.stream, self.function);
+    if self.w {
+      sml.push_str(" W");
+    }
+    if let Some(item) = &self.text {
+      sml.push('\n');
+      render(&mut sml, item, 0);
+    }
+    sml.push_str("\n.");
+    sml
+  }
+}
+
+impl Item {
+  /// ### ITEM TO SML
+  /// 
+  /// Renders the [Item] as [SML] text, with nested [List]s indented by two
+  /// spaces per level.
+  /// 
+  /// [Localized] strings are not yet supported, and are rendered as their
+  /// [Binary] contents.
+  /// 
+  /// [Item]:      Item
+  /// [SML]:       crate::sml
+  /// [List]:      Item::List
+  /// [Localized]: Item::Local
+  /// [Binary]:    Item::Bin
+  pub fn to_sml(&self) -> String {
+    let mut sml = String::new();
+    render(&mut sml, self, 0);
+    sml
+  }
+}
+
+/// ### RENDER
+/// 
+/// Appends an [Item] to SML text at a particular nesting depth.
+/// 
+/// [Item]: Item
+fn render(sml: &mut String, item: &Item, depth: usize) {
+  let indent = "  ".repeat(depth);
+  sml.push_str(&indent);
+  match item {
+    Item::List(vec) => {
+      let _ = write!(sml, "<L [{}]", vec.len());
+      if !vec.is_empty() {
+        for member in vec {
+          sml.push('\n');
+          render(sml, member, depth + 1);
+        }
+        sml.push('\n');
+        sml.push_str(&indent);
+      }
+      sml.push('>');
+    },
+    Item::Ascii(vec) => {
+      sml.push_str("<A");
+      let mut quoted = false;
+      for character in vec {
+        let byte = character.to_u8();
+        if (0x20..0x7F).contains(&byte) && byte != b'"' {
+          if !quoted {
+            sml.push_str(" \"");
+            quoted = true;
+          }
+          sml.push(byte as char);
+        } else {
+          if quoted {
+            sml.push('"');
+            quoted = false;
+          }
+          let _ = write!(sml, " 0x{:02X}", byte);
+        }
+      }
+      if quoted {
+        sml.push('"');
+      }
+      sml.push('>');
+    },
+    Item::Jis8(string) => {
+      if string.is_empty() {
+        sml.push_str("<J>");
+      } else {
+        let _ = write!(sml, "<J \"{}\">", string);
+      }
+    },
+    Item::Local(_, vec) => values(sml, "B", vec.iter().map(|byte| format!("0x{:02X}", byte))),
+    Item::Bin(vec)  => values(sml, "B", vec.iter().map(|byte| format!("0x{:02X}", byte))),
+    Item::Bool(vec) => values(sml, "BOOLEAN", vec.iter().map(|value| if *value {"TRUE"} else {"FALSE"})),
+    Item::I1(vec)   => values(sml, "I1", vec.iter()),
+    Item::I2(vec)   => values(sml, "I2", vec.iter()),
+    Item::I4(vec)   => values(sml, "I4", vec.iter()),
+    Item::I8(vec)   => values(sml, "I8", vec.iter()),
+    Item::U1(vec)   => values(sml, "U1", vec.iter()),
+    Item::U2(vec)   => values(sml, "U2", vec.iter()),
+    Item::U4(vec)   => values(sml, "U4", vec.iter()),
+    Item::U8(vec)   => values(sml, "U8", vec.iter()),
+    Item::F4(vec)   => values(sml, "F4", vec.iter()),
+    Item::F8(vec)   => values(sml, "F8", vec.iter()),
+  }
+}
+
+/// ### RENDER VALUES
+/// 
+/// Appends a format code and space separated values to SML text.
+fn values<T: Display>(sml: &mut String, format: &str, values: impl Iterator<Item = T>) {
+  sml.push('<');
+  sml.push_str(format);
+  for value in values {
+    let _ = write!(sml, " {}", value);
+  }
+  sml.push('>');
+}
+
+/// ### PARSER
+/// 
+/// Tracks the position within SML text during parsing.
+struct Parser<'a> {
+  text: &'a str,
+  position: usize,
+}
+impl Parser<'_> {
+  /// ### PEEK
+  /// 
+  /// Returns the next character without consuming it.
+  fn peek(&self) -> Option<char> {
+    self.text[self.position..].chars().next()
+  }
+
+  /// ### SKIP WHITESPACE
+  /// 
+  /// Consumes whitespace and `//` comments.
+  fn skip(&mut self) {
+    loop {
+      let rest = &self.text[self.position..];
+      if rest.starts_with("//") {
+        self.position += rest.find('\n').unwrap_or(rest.len());
+      } else if let Some(character) = rest.chars().next().filter(|c| c.is_whitespace()) {
+        self.position += character.len_utf8();
+      } else {
+        break
+      }
+    }
+  }
+
+  /// ### EXPECT
+  /// 
+  /// Consumes a particular character, after any whitespace.
+  fn expect(&mut self, expected: char) -> Result<(), SmlError> {
+    self.skip();
+    match self.peek() {
+      Some(character) if character == expected => {
+        self.position += character.len_utf8();
+        Ok(())
+      },
+      Some(_) => Err(SmlError::UnexpectedCharacter(self.position)),
+      None => Err(SmlError::UnexpectedEnd),
+    }
+  }
+
+  /// ### WORD
+  /// 
+  /// Consumes a run of characters which may form a format code, header, or
+  /// value, after any whitespace.
+  /// 
+  /// A period only continues a word when followed by a digit, so that the
+  /// terminating period of a [Message] is not consumed.
+  /// 
+  /// [Message]: Message
+  fn word(&mut self) -> (usize, &str) {
+    self.skip();
+    let start = self.position;
+    let bytes = self.text.as_bytes();
+    while let Some(&byte) = bytes.get(self.position) {
+      let continues = match byte {
+        b'.' => bytes.get(self.position + 1).is_some_and(u8::is_ascii_digit),
+        _ => byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'+' || byte == b'_',
+      };
+      if !continues {break}
+      self.position += 1;
+    }
+    (start, &self.text[start..self.position])
+  }
+
+  /// ### MESSAGE
+  /// 
+  /// Parses a complete [Message], including its terminating period, which
+  /// must make up the entire text.
+  /// 
+  /// [Message]: Message
+  fn message(&mut self) -> Result<Message, SmlError> {
+    // Stream and Function
+    let (start, header) = self.word();
+    if header.is_empty() {
+      return Err(self.end_or(SmlError::UnexpectedCharacter(start)))
+    }
+    let upper = header.to_ascii_uppercase();
+    let (stream, function) = upper
+      .strip_prefix('S')
+      .and_then(|rest| rest.split_once('F'))
+      .and_then(|(stream, function)| Some((stream.parse::<u8>().ok()?, function.parse::<u8>().ok()?)))
+      .filter(|(stream, _)| *stream < 128)
+      .ok_or(SmlError::InvalidHeader(start))?;
+    // Reply Bit
+    self.skip();
+    let w = if self.peek().is_some_and(|c| c == 'W' || c == 'w') {
+      let (start, word) = self.word();
+      if !word.eq_ignore_ascii_case("W") {
+        return Err(SmlError::UnexpectedCharacter(start))
+      }
+      true
+    } else {
+      false
+    };
+    // Text
+    self.skip();
+    let text = if self.peek() == Some('<') {
+      Some(self.item(0)?)
+    } else {
+      None
+    };
+    // Termination
+    self.expect('.')?;
+    self.skip();
+    if self.position != self.text.len() {
+      return Err(SmlError::TrailingCharacters(self.position))
+    }
+    Ok(Message {stream, function, w, text})
+  }
+
+  /// ### ITEM
+  /// 
+  /// Parses a single [Item], including any nested [Item]s, at a particular
+  /// nesting depth.
+  /// 
+  /// [List]s nested more deeply than [MAX_LIST_DEPTH] are rejected, so that
+  /// hostile text cannot exhaust the stack.
+  /// 
+  /// [Item]:           Item
+  /// [List]:           Item::List
+  /// [MAX_LIST_DEPTH]: Item::MAX_LIST_DEPTH
+  fn item(&mut self, depth: usize) -> Result<Item, SmlError> {
+    self.expect('<')?;
+    let (start, format) = self.word();
+    let format = format.to_ascii_uppercase();
+    // Length
+    self.skip();
+    let length = if self.peek() == Some('[') {
+      self.position += 1;
+      let (start, word) = self.word();
+      let length = word.parse::<usize>().map_err(|_| SmlError::InvalidValue(start))?;
+      self.expect(']')?;
+      Some(length)
+    } else {
+      None
+    };
+    // Values
+    let item = match format.as_str() {
+      "L" => {
+        if depth >= Item::MAX_LIST_DEPTH {return Err(SmlError::TooDeep(start))}
+        let mut vec = vec![];
+        loop {
+          self.skip();
+          match self.peek() {
+            Some('>') => break,
+            Some(_) => vec.push(self.item(depth + 1)?),
+            None => return Err(SmlError::UnexpectedEnd),
+          }
+        }
+        Item::List(vec)
+      },
+      "A" => Item::Ascii(self.text_values(true)?.into_iter().map(|(position, c)| {
+        u8::try_from(c).ok().and_then(Char::from_u8).ok_or(SmlError::InvalidValue(position))
+      }).collect::<Result<Vec<Char>, SmlError>>()?),
+      "J" => Item::Jis8(self.text_values(false)?.into_iter().map(|(_, c)| c).collect()),
+      "B"       => Item::Bin(self.values(integer)?),
+      "BOOLEAN" => Item::Bool(self.values(boolean)?),
+      "I1"      => Item::I1(self.values(integer)?),
+      "I2"      => Item::I2(self.values(integer)?),
+      "I4"      => Item::I4(self.values(integer)?),
+      "I8"      => Item::I8(self.values(integer)?),
+      "U1"      => Item::U1(self.values(integer)?),
+      "U2"      => Item::U2(self.values(integer)?),
+      "U4"      => Item::U4(self.values(integer)?),
+      "U8"      => Item::U8(self.values(integer)?),
+      "F4"      => Item::F4(self.values(|word| word.parse().ok())?),
+      "F8"      => Item::F8(self.values(|word| word.parse().ok())?),
+      _ => return Err(SmlError::UnknownFormat(start)),
+    };
+    // Length Check
+    if let Some(length) = length {
+      let actual = match &item {
+        Item::List(vec)     => vec.len(),
+        Item::Ascii(vec)    => vec.len(),
+        Item::Jis8(string)  => string.chars().count(),
+        Item::Local(_, vec) => vec.len(),
+        Item::Bin(vec)      => vec.len(),
+        Item::Bool(vec)     => vec.len(),
+        Item::I1(vec)       => vec.len(),
+        Item::I2(vec)       => vec.len(),
+        Item::I4(vec)       => vec.len(),
+        Item::I8(vec)       => vec.len(),
+        Item::U1(vec)       => vec.len(),
+        Item::U2(vec)       => vec.len(),
+        Item::U4(vec)       => vec.len(),
+        Item::U8(vec)       => vec.len(),
+        Item::F4(vec)       => vec.len(),
+        Item::F8(vec)       => vec.len(),
+      };
+      if actual != length {
+        return Err(SmlError::WrongLength(start))
+      }
+    }
+    self.expect('>')?;
+    Ok(item)
+  }
+
+  /// ### VALUES
+  /// 
+  /// Parses space separated values up to the closing angle bracket.
+  fn values<T>(&mut self, convert: fn(&str) -> Option<T>) -> Result<Vec<T>, SmlError> {
+    let mut vec = vec![];
+    loop {
+      self.skip();
+      match self.peek() {
+        Some('>') => break,
+        Some(_) => {
+          let (start, word) = self.word();
+          if word.is_empty() {
+            return Err(SmlError::UnexpectedCharacter(start))
+          }
+          vec.push(convert(word).ok_or(SmlError::InvalidValue(start))?);
+        },
+        None => return Err(SmlError::UnexpectedEnd),
+      }
+    }
+    Ok(vec)
+  }
+
+  /// ### TEXT VALUES
+  /// 
+  /// Parses quoted runs of characters up to the closing angle bracket,
+  /// optionally interleaved with character codes, along with the position of
+  /// each character.
+  fn text_values(&mut self, codes: bool) -> Result<Vec<(usize, char)>, SmlError> {
+    let mut vec = vec![];
+    loop {
+      self.skip();
+      match self.peek() {
+        Some('>') => break,
+        Some('"') => {
+          self.position += 1;
+          let length = self.text[self.position..].find('"').ok_or(SmlError::UnexpectedEnd)?;
+          for (offset, character) in self.text[self.position..self.position + length].char_indices() {
+            vec.push((self.position + offset, character));
+          }
+          self.position += length + 1;
+        },
+        Some(_) if codes => {
+          let (start, word) = self.word();
+          if word.is_empty() {
+            return Err(SmlError::UnexpectedCharacter(start))
+          }
+          let code: u8 = integer(word).ok_or(SmlError::InvalidValue(start))?;
+          vec.push((start, code as char));
+        },
+        Some(_) => return Err(SmlError::UnexpectedCharacter(self.position)),
+        None => return Err(SmlError::UnexpectedEnd),
+      }
+    }
+    Ok(vec)
+  }
+
+  /// ### END OR
+  /// 
+  /// Distinguishes running out of text from encountering a bad character.
+  fn end_or(&self, error: SmlError) -> SmlError {
+    if self.position == self.text.len() {SmlError::UnexpectedEnd} else {error}
+  }
+}
+
+/// ### INTEGER
+/// 
+/// Converts a decimal, `0x` hexadecimal, or `0b` binary word into an integer
+/// of any width, failing if the value does not fit.
+fn integer<T: TryFrom<i128>>(word: &str) -> Option<T> {
+  let (negative, digits) = match word.strip_prefix('-') {
+    Some(digits) => (true, digits),
+    None => (false, word.strip_prefix('+').unwrap_or(word)),
+  };
+  let lower = digits.to_ascii_lowercase();
+  let magnitude = if let Some(hex) = lower.strip_prefix("0x") {
+    i128::from_str_radix(hex, 16).ok()?
+  } else if let Some(bin) = lower.strip_prefix("0b") {
+    i128::from_str_radix(bin, 2).ok()?
+  } else {
+    lower.parse::<i128>().ok()?
+  };
+  T::try_from(if negative {-magnitude} else {magnitude}).ok()
+}
+
+/// ### BOOLEAN
+/// 
+/// Converts `TRUE`/`FALSE`, `T`/`F`, or `1`/`0` into a boolean.
+fn boolean(word: &str) -> Option<bool> {
+  match word.to_ascii_uppercase().as_str() {
+    "TRUE"  | "T" | "1" => Some(true),
+    "FALSE" | "F" | "0" => Some(false),
+    _ => None,
+  }
+}
@@ -0,0 +1,347 @@
+// Copyright © 2024 Nathaniel Hardesty
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the “Software”), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+//! # STREAMING ITEMS
+//! 
+//! Event-based reading and writing of [Item]s, allowing very large trees,
+//! such as process programs and wafer maps, to be processed incrementally
+//! without the entire tree being built in memory.
+//! 
+//! ---------------------------------------------------------------------------
+//! 
+//! An [Item] is represented as a sequence of [Event]s:
+//! 
+//! - A [List Start] carrying the number of [Item]s in the [List], followed
+//!   by the [Event]s of each of those [Item]s, followed by a [List End].
+//! - A [Scalar] carrying any [Item] other than a [List].
+//! 
+//! The [Item Reader] produces these [Event]s from binary data, and the
+//! [Item Writer] consumes them to produce binary data.
+//! 
+//! [Item]:        crate::Item
+//! [List]:        crate::Item::List
+//! [Event]:       Event
+//! [List Start]:  Event::ListStart
+//! [List End]:    Event::ListEnd
+//! [Scalar]:      Event::Scalar
+//! [Item Reader]: ItemReader
+//! [Item Writer]: ItemWriter
+
+use std::io::{
+  self,
+  ErrorKind,
+  Read,
+  Write,
+};
+use crate::{
+  format,
+  EncodeOptions,
+  Error,
+  Item,
+};
+
+/// ## EVENT
+/// 
+/// A single step in the traversal of an [Item] tree.
+/// 
+/// [Item]: Item
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+  /// ### LIST START
+  /// 
+  /// The beginning of a [List], carrying the number of [Item]s it contains.
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  ListStart(usize),
+
+  /// ### LIST END
+  /// 
+  /// The end of the most recently started [List].
+  /// 
+  /// [List]: Item::List
+  ListEnd,
+
+  /// ### SCALAR
+  /// 
+  /// A complete [Item] which is not a [List].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  Scalar(Item),
+}
+
+/// ## ITEM READER
+/// 
+/// Produces the [Event]s of a single [Item] tree from binary data provided
+/// by any [Reader], including a byte slice.
+/// 
+/// Only the body of one [Scalar] is held in memory at a time. Reading stops
+/// after the [Item] tree is complete, so any bytes following it are left
+/// unread.
+/// 
+/// After an error is produced, no further [Event]s are produced. Errors are
+/// [Empty Text] if no data is available at all, [Unexpected End] if the
+/// data ends before the [Item] tree is complete, [Wrong Format] if the
+/// length of a numeric [Scalar] is not a multiple of the size of its values,
+/// and [Invalid Text] if the data is malformed or cannot be read. As with
+/// the conversion of binary data into an [Item], [List]s nested more deeply
+/// than [MAX_LIST_DEPTH] are rejected.
+/// 
+/// [Item]:           Item
+/// [List]:           Item::List
+/// [Event]:          Event
+/// [Scalar]:         Event::Scalar
+/// [Reader]:         Read
+/// [Empty Text]:     Error::EmptyText
+/// [Invalid Text]:   Error::InvalidText
+/// [Wrong Format]:   Error::WrongFormat
+/// [Unexpected End]: Error::UnexpectedEnd
+/// [MAX_LIST_DEPTH]: Item::MAX_LIST_DEPTH
+pub struct ItemReader<R: Read> {
+  reader: R,
+  open: Vec<usize>,
+  started: bool,
+  finished: bool,
+}
+impl<R: Read> ItemReader<R> {
+  /// ### NEW ITEM READER
+  /// 
+  /// Creates an [Item Reader] over binary data provided by a [Reader].
+  /// 
+  /// [Item Reader]: ItemReader
+  /// [Reader]:      Read
+  pub fn new(reader: R) -> Self {
+    Self {
+      reader,
+      open: vec![],
+      started: false,
+      finished: false,
+    }
+  }
+
+  /// ### INTO INNER
+  /// 
+  /// Consumes the [Item Reader], returning the underlying [Reader].
+  /// 
+  /// [Item Reader]: ItemReader
+  /// [Reader]:      Read
+  pub fn into_inner(self) -> R {
+    self.reader
+  }
+
+  /// ### READ EVENT
+  /// 
+  /// Reads the header of the next [Item], and its body if it is not a
+  /// [List].
+  /// 
+  /// [Item]: Item
+  /// [List]: Item::List
+  fn read_event(&mut self) -> Result<Event, Error> {
+    // Format Byte
+    let mut format_byte = [0u8; 1];
+    match self.reader.read_exact(&mut format_byte) {
+      Ok(()) => {},
+      Err(error) => {
+        if error.kind() != ErrorKind::UnexpectedEof {return Err(Error::InvalidText)}
+        if !self.started {return Err(Error::EmptyText)}
+        return Err(Error::UnexpectedEnd)
+      },
+    }
+    self.started = true;
+    let length_length = (format_byte[0] & 0b000000_11) as usize;
+    if length_length == 0 {return Err(Error::InvalidText)}
+    // Length Bytes
+    let mut length_bytes = [0u8; 4];
+    self.reader.read_exact(&mut length_bytes[4 - length_length..]).map_err(|error| match error.kind() {
+      ErrorKind::UnexpectedEof => Error::UnexpectedEnd,
+      _ => Error::InvalidText,
+    })?;
+    let length = u32::from_be_bytes(length_bytes) as usize;
+    // List
+    if format_byte[0] & 0b111111_00 == format::LIST {
+      if self.open.len() >= Item::MAX_LIST_DEPTH {return Err(Error::InvalidText)}
+      self.open.push(length);
+      return Ok(Event::ListStart(length))
+    }
+    // Scalar
+    let mut text: Vec<u8> = vec![];
+    text.push(format_byte[0]);
+    text.extend_from_slice(&length_bytes[4 - length_length..]);
+    let read = (&mut self.reader).take(length as u64).read_to_end(&mut text).map_err(|_| Error::InvalidText)?;
+    if read != length {return Err(Error::UnexpectedEnd)}
+    Ok(Event::Scalar(Item::try_from(text)?))
+  }
+}
+impl<R: Read> Iterator for ItemReader<R> {
+  type Item = Result<Event, Error>;
+
+  /// ### NEXT EVENT
+  /// 
+  /// Produces the next [Event], or [None] once the [Item] tree is complete.
+  /// 
+  /// [Item]:  Item
+  /// [Event]: Event
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.finished {return None}
+    // Close List
+    if self.open.last() == Some(&0) {
+      self.open.pop();
+      return Some(Ok(Event::ListEnd))
+    }
+    // Complete Tree
+    if self.started && self.open.is_empty() {
+      self.finished = true;
+      return None
+    }
+    // Next Item
+    if let Some(remaining) = self.open.last_mut() {
+      *remaining -= 1;
+    }
+    let result = self.read_event();
+    if result.is_err() {
+      self.finished = true;
+    }
+    Some(result)
+  }
+}
+
+/// ## ITEM WRITER
+/// 
+/// Consumes the [Event]s of an [Item] tree, producing binary data into any
+/// [Writer].
+/// 
+/// Only the [Item] carried by one [Scalar] is held in memory at a time. The
+/// structure of the [Event]s is verified as they are written, failing with
+/// [Invalid Input] if a [List] receives more or fewer [Item]s than it
+/// declared, or if [Event]s follow a complete tree.
+/// 
+/// [Item]:          Item
+/// [List]:          Item::List
+/// [Scalar]:        Event::Scalar
+/// [Writer]:        Write
+/// [Invalid Input]: ErrorKind::InvalidInput
+pub struct ItemWriter<W: Write> {
+  writer: W,
+  options: EncodeOptions,
+  open: Vec<usize>,
+  started: bool,
+}
+impl<W: Write> ItemWriter<W> {
+  /// ### NEW ITEM WRITER
+  /// 
+  /// Creates an [Item Writer] producing binary data into a [Writer].
+  /// 
+  /// [Item Writer]: ItemWriter
+  /// [Writer]:      Write
+  pub fn new(writer: W) -> Self {
+    Self::with_options(writer, EncodeOptions::default())
+  }
+
+  /// ### NEW ITEM WRITER WITH OPTIONS
+  /// 
+  /// Creates an [Item Writer] producing binary data into a [Writer]
+  /// according to the provided [Encode Options].
+  /// 
+  /// [Item Writer]:    ItemWriter
+  /// [Writer]:         Write
+  /// [Encode Options]: EncodeOptions
+  pub fn with_options(writer: W, options: EncodeOptions) -> Self {
+    Self {
+      writer,
+      options,
+      open: vec![],
+      started: false,
+    }
+  }
+
+  /// ### WRITE EVENT
+  /// 
+  /// Writes the binary data corresponding to an [Event].
+  /// 
+  /// A [Scalar] may also carry a [List], in which case the entire [List] is
+  /// written as a single [Item].
+  /// 
+  /// [Item]:   Item
+  /// [List]:   Item::List
+  /// [Event]:  Event
+  /// [Scalar]: Event::Scalar
+  pub fn write(&mut self, event: &Event) -> io::Result<()> {
+    // List End
+    if let Event::ListEnd = event {
+      return match self.open.last() {
+        Some(0) => {
+          self.open.pop();
+          Ok(())
+        },
+        Some(_) => Err(io::Error::new(ErrorKind::InvalidInput, "list ended before all items were written")),
+        None => Err(io::Error::new(ErrorKind::InvalidInput, "list ended without being started")),
+      }
+    }
+    // Next Item
+    match self.open.last_mut() {
+      Some(0) => return Err(io::Error::new(ErrorKind::InvalidInput, "list contains more items than declared")),
+      Some(remaining) => *remaining -= 1,
+      None => if self.started {return Err(io::Error::new(ErrorKind::InvalidInput, "item tree is already complete"))},
+    }
+    self.started = true;
+    let mut vec: Vec<u8> = vec![];
+    match event {
+      Event::ListStart(length) => {
+        Item::serialize_header(&mut vec, format::LIST, *length, self.options.length_bytes).map_err(Self::item_error)?;
+        self.open.push(*length);
+      },
+      Event::Scalar(item) => item.serialize_into_with(&mut vec, self.options).map_err(Self::item_error)?,
+      Event::ListEnd => unreachable!(),
+    }
+    self.writer.write_all(&vec)
+  }
+
+  /// ### IS COMPLETE
+  /// 
+  /// Reports whether a complete [Item] tree has been written.
+  /// 
+  /// [Item]: Item
+  pub fn is_complete(&self) -> bool {
+    self.started && self.open.is_empty()
+  }
+
+  /// ### INTO INNER
+  /// 
+  /// Consumes the [Item Writer], returning the underlying [Writer].
+  /// 
+  /// [Item Writer]: ItemWriter
+  /// [Writer]:      Write
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+
+  /// ### ITEM ERROR
+  /// 
+  /// Converts an [Error] produced while serializing an [Item] into an
+  /// [Invalid Input] error.
+  /// 
+  /// [Item]:          Item
+  /// [Error]:         Error
+  /// [Invalid Input]: ErrorKind::InvalidInput
+  fn item_error(error: Error) -> io::Error {
+    io::Error::new(ErrorKind::InvalidInput, format!("{:?}", error))
+  }
+}
@@ -0,0 +1,40 @@
+//! # ITEM
+//! 
+//! Converts binary data into numeric Items, including data whose length is
+//! not a whole number of values.
+
+use semi_e5::{
+  format,
+  Error,
+  Item,
+};
+
+#[test]
+fn signed4_decodes_big_endian() {
+  let item = Item::try_from(vec![format::I4 | 1, 4, 0x01, 0x02, 0x03, 0x04]);
+  assert_eq!(item, Ok(Item::I4(vec![0x01020304])));
+}
+
+#[test]
+fn partial_value_is_wrong_format() {
+  let mut text = vec![format::I4 | 1, 9];
+  text.extend_from_slice(&[0; 9]);
+  assert_eq!(Item::try_from(text), Err(Error::WrongFormat));
+  assert_eq!(Item::try_from(vec![format::U2 | 1, 3, 0, 0, 0]), Err(Error::WrongFormat));
+  assert_eq!(Item::try_from(vec![format::F8 | 1, 7, 0, 0, 0, 0, 0, 0, 0]), Err(Error::WrongFormat));
+}
+
+#[test]
+fn negative_values_round_trip() {
+  let items = [
+    Item::I1(vec![i8::MIN, -1]),
+    Item::I2(vec![i16::MIN, -1]),
+    Item::I4(vec![i32::MIN, -1, -2]),
+    Item::I8(vec![i64::MIN, -1]),
+  ];
+  for item in items {
+    let text = item.encode().unwrap();
+    assert_eq!(Item::try_from(text), Ok(item));
+  }
+  assert_eq!(Item::I4(vec![-2]).encode(), Ok(vec![format::I4 | 1, 4, 0xFF, 0xFF, 0xFF, 0xFE]));
+}
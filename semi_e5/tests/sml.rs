@@ -0,0 +1,43 @@
+//! # SML
+//! 
+//! Parses SML text, including text which must be rejected.
+
+use semi_e5::{
+  sml::{
+    parse,
+    SmlError,
+  },
+  Item,
+  Message,
+};
+
+#[test]
+fn round_trip() {
+  let message = Message {
+    stream: 1,
+    function: 13,
+    w: true,
+    text: Some(Item::List(vec![
+      Item::I4(vec![-1, 0, 1]),
+      Item::List(vec![Item::Bool(vec![true])]),
+    ])),
+  };
+  assert_eq!(parse(&message.to_sml()), Ok(message));
+}
+
+#[test]
+fn requires_terminating_period() {
+  assert_eq!(parse("S1F1"), Err(SmlError::UnexpectedEnd));
+  assert_eq!(parse("S1F1 W <L> >"), Err(SmlError::UnexpectedCharacter(11)));
+  assert!(parse("S1F1 W.").is_ok());
+  assert!(parse("S1F1 W <L> .").is_ok());
+}
+
+#[test]
+fn rejects_deep_nesting() {
+  let deep = format!("S1F1 {}{}.", "<L ".repeat(100_000), ">".repeat(100_000));
+  assert!(matches!(parse(&deep), Err(SmlError::TooDeep(_))));
+  let limit = Item::MAX_LIST_DEPTH;
+  let shallow = format!("S1F1 {}{}.", "<L ".repeat(limit), ">".repeat(limit));
+  assert!(parse(&shallow).is_ok());
+}